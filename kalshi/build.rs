@@ -18,6 +18,7 @@ fn main() {
     // assertion fires. We normalise: for each operation, keep only the FIRST
     // 2xx response and drop the rest, then verify only one 2xx remains.
     preprocess_spec(&mut spec_value);
+    hoist_lenient_enums(&mut spec_value);
 
     let spec: openapiv3::OpenAPI = serde_json::from_value(spec_value)
         .unwrap_or_else(|e| panic!("Failed to interpret OpenAPI spec: {e}"));
@@ -32,6 +33,15 @@ fn main() {
             "Orderbook",
             progenitor::TypePatch::default().with_rename("GeneratedOrderbook"),
         );
+    // Response enums are replaced by hand-written types with an `Unknown`
+    // fallback (see src/enums.rs) so new API values don't break deserialization.
+    for (_, _, name) in LENIENT_ENUMS {
+        settings.with_replacement(
+            name,
+            format!("crate::enums::{name}"),
+            [progenitor::TypeImpl::Display, progenitor::TypeImpl::FromStr].into_iter(),
+        );
+    }
 
     let tokens = progenitor::Generator::new(&settings)
         .generate_tokens(&spec)
@@ -47,6 +57,63 @@ fn main() {
         .unwrap_or_else(|e| panic!("Failed to write codegen to {}: {e}", out_file.display()));
 }
 
+/// Enums appearing in API responses, as `(schema, property, type name)`. Inline
+/// enums are hoisted into named component schemas so progenitor's type
+/// replacement (which only matches named schemas) can pick them up; entries
+/// with an empty property are already component schemas.
+const LENIENT_ENUMS: &[(&str, &str, &str)] = &[
+    ("Announcement", "type", "AnnouncementType"),
+    ("Announcement", "status", "AnnouncementStatus"),
+    ("ExchangeInstance", "", "ExchangeInstance"),
+    ("Fill", "side", "FillSide"),
+    ("Fill", "action", "FillAction"),
+    ("IncentiveProgram", "incentive_type", "IncentiveProgramIncentiveType"),
+    ("Market", "status", "MarketStatus"),
+    ("Market", "result", "MarketResult"),
+    ("Market", "market_type", "MarketMarketType"),
+    ("Market", "strike_type", "MarketStrikeType"),
+    ("Market", "response_price_units", "MarketResponsePriceUnits"),
+    ("Order", "side", "OrderSide"),
+    ("Order", "action", "OrderAction"),
+    ("Order", "type", "OrderType"),
+    ("OrderStatus", "", "OrderStatus"),
+    ("Quote", "status", "QuoteStatus"),
+    ("Quote", "accepted_side", "QuoteAcceptedSide"),
+    ("RFQ", "status", "RfqStatus"),
+    ("SelfTradePreventionType", "", "SelfTradePreventionType"),
+    ("Series", "fee_type", "SeriesFeeType"),
+    ("SeriesFeeChange", "fee_type", "SeriesFeeChangeFeeType"),
+    ("Settlement", "market_result", "SettlementMarketResult"),
+    ("Trade", "taker_side", "TradeTakerSide"),
+];
+
+/// Moves each inline enum listed in [`LENIENT_ENUMS`] into its own component
+/// schema and points the owning property at it with a `$ref`.
+fn hoist_lenient_enums(spec: &mut serde_json::Value) {
+    let schemas = spec
+        .pointer_mut("/components/schemas")
+        .and_then(|s| s.as_object_mut())
+        .expect("spec has no components/schemas");
+
+    for (schema, property, name) in LENIENT_ENUMS {
+        if property.is_empty() {
+            assert!(schemas.contains_key(*name), "build.rs: missing enum schema {name}");
+            continue;
+        }
+        let prop = schemas
+            .get_mut(*schema)
+            .and_then(|s| s.get_mut("properties"))
+            .and_then(|p| p.get_mut(*property))
+            .unwrap_or_else(|| panic!("build.rs: missing property {schema}.{property}"));
+        let inline = std::mem::replace(
+            prop,
+            serde_json::json!({ "$ref": format!("#/components/schemas/{name}") }),
+        );
+        assert!(inline.get("enum").is_some(), "build.rs: {schema}.{property} is not an enum");
+        schemas.insert(name.to_string(), inline);
+    }
+}
+
 /// Normalise the spec so each operation has at most one 2xx response with a
 /// body. This works around progenitor's `assert!(response_types.len() <= 1)`.
 ///
@@ -0,0 +1,257 @@
+//! analytics.rs – pure trading math that doesn't touch the network
+use crate::{Candle, Market};
+
+/// A pricing model that estimates the fair probability of a market resolving yes.
+///
+/// Implement this for your own model and compare it to the market with
+/// [`Market::edge_vs`].
+pub trait FairValueModel {
+    /// The model's probability (`0.0..=1.0`) that `market` resolves yes, or `None` if
+    /// the model has no opinion on it.
+    fn fair_yes_prob(&self, market: &Market) -> Option<f64>;
+}
+
+/// Kelly-optimal fraction of bankroll to stake on buying one side of a binary contract.
+///
+/// A contract bought at `market_price_cents` pays 100¢ if it resolves in your favour and
+/// nothing otherwise, so with `p` the price in dollars and `q` your probability of winning
+/// the formula reduces to `f* = (q - p) / (1 - p)`.
+///
+/// The result is clamped to `[0, 1]`: it is `0` whenever there is no edge (`q <= p`), and
+/// also when the price is outside `1..=99` or `edge_prob` is not a finite probability.
+/// For the `no` side, pass your probability of `no` and the `no` ask.
+///
+/// # Example
+///
+/// ```
+/// // You think "yes" is 60% likely and it is offered at 50¢: stake 20% of bankroll.
+/// let f = kalshi::kelly_fraction(0.60, 50);
+/// assert!((f - 0.2).abs() < 1e-9);
+/// ```
+///
+pub fn kelly_fraction(edge_prob: f64, market_price_cents: i32) -> f64 {
+    if !(1..=99).contains(&market_price_cents) || !(0.0..=1.0).contains(&edge_prob) {
+        return 0.0;
+    }
+    let price = market_price_cents as f64 / 100.0;
+    ((edge_prob - price) / (1.0 - price)).clamp(0.0, 1.0)
+}
+
+/// Rolling volume-weighted average of the traded yes close over `window` candles.
+///
+/// Entry `i` of the result is `Σ close * volume / Σ volume` over candles
+/// `i + 1 - window ..= i` (fewer at the start of the series), with prices in dollars.
+/// Candles with no volume, or without a traded close because nothing traded in the
+/// period, carry no weight. If no candle in the window traded, the previous value is
+/// carried forward, and entries before the first trade are `NaN`. A `window` of `0` is
+/// treated as `1`.
+///
+/// # Example
+///
+/// ```
+/// let candles = kalshi_instance
+///     .get_market_candlesticks("SOME-MARKET-2024", "SOME-SERIES", None, None, Some(60))
+///     .await
+///     .unwrap();
+/// let vwap = kalshi::rolling_vwap(&candles, 24);
+/// ```
+///
+pub fn rolling_vwap(candles: &[Candle], window: usize) -> Vec<f64> {
+    let window = window.max(1);
+    let weighted: Vec<(f64, f64)> = candles.iter().map(weighted_close).collect();
+
+    let mut vwap = Vec::with_capacity(candles.len());
+    let mut last = f64::NAN;
+    for end in 0..weighted.len() {
+        let start = (end + 1).saturating_sub(window);
+        let (notional, volume) = weighted[start..=end]
+            .iter()
+            .fold((0.0, 0.0), |(n, v), (wn, wv)| (n + wn, v + wv));
+        if volume > 0.0 {
+            last = notional / volume;
+        }
+        vwap.push(last);
+    }
+    vwap
+}
+
+/// Volume-weighted average of the traded yes close across all of `candles`, in dollars.
+///
+/// `Σ close * volume / Σ volume`, the whole-series counterpart of [`rolling_vwap`].
+/// Candles with no volume or no traded close are skipped, and the result is `None` when
+/// none are left, rather than dividing by zero.
+///
+/// # Example
+///
+/// ```
+/// let candles = kalshi_instance
+///     .get_market_candlesticks("SOME-MARKET-2024", "SOME-SERIES", None, None, Some(60))
+///     .await
+///     .unwrap();
+/// if let Some(vwap) = kalshi::candles_vwap(&candles) {
+///     println!("VWAP: ${:.4}", vwap);
+/// }
+/// ```
+///
+pub fn candles_vwap(candles: &[Candle]) -> Option<f64> {
+    let (notional, volume) = candles
+        .iter()
+        .map(weighted_close)
+        .fold((0.0, 0.0), |(n, v), (wn, wv)| (n + wn, v + wv));
+    (volume > 0.0).then(|| notional / volume)
+}
+
+/// A candle's `(close * volume, volume)`, or zeros if it has no volume or traded close.
+fn weighted_close(candle: &Candle) -> (f64, f64) {
+    let close = candle.price.close_dollars.as_ref().and_then(|p| p.to_f64());
+    let volume = candle.volume_fp.to_f64().filter(|v| *v > 0.0);
+    match (close, volume) {
+        (Some(close), Some(volume)) => (close * volume, volume),
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Annualized Sharpe ratio of a series of per-period P&L in cents.
+///
+/// Computed as `mean / stdev * sqrt(periods_per_year)` with the sample standard deviation
+/// and a risk-free rate of zero, which is the usual convention for a book of prediction
+/// market positions that isn't funded by borrowing. Every entry is one period of equal
+/// length, so bucket settlements or fills by day (say) first and pass `365.0`, including
+/// zero entries for days with no P&L; skipping them overstates the ratio.
+///
+/// Returns `0.0` when the ratio isn't defined: fewer than two periods, no variation in
+/// P&L, or a `periods_per_year` that isn't a positive finite number.
+///
+/// # Example
+///
+/// ```
+/// // Daily P&L in cents.
+/// let sharpe = kalshi::sharpe_ratio(&[100, -50, 100, -50], 365.0);
+/// ```
+///
+pub fn sharpe_ratio(pnl_series: &[i64], periods_per_year: f64) -> f64 {
+    if pnl_series.len() < 2 || !periods_per_year.is_finite() || periods_per_year <= 0.0 {
+        return 0.0;
+    }
+    let n = pnl_series.len() as f64;
+    let mean = pnl_series.iter().map(|p| *p as f64).sum::<f64>() / n;
+    let variance = pnl_series.iter().map(|p| (*p as f64 - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    if variance == 0.0 {
+        return 0.0;
+    }
+    mean / variance.sqrt() * periods_per_year.sqrt()
+}
+
+/// Largest peak-to-trough decline of an equity curve in cents, as a positive number.
+///
+/// `equity_curve` is the account value (or cumulative P&L) after each period. The
+/// drawdown is measured from the highest value seen so far, so a decline from the very
+/// first entry counts, and a curve that never falls returns `0`.
+///
+/// # Example
+///
+/// ```
+/// let drawdown = kalshi::max_drawdown(&[1_000, 1_500, 900, 1_200, 600, 2_000]);
+/// assert_eq!(drawdown, 900);
+/// ```
+///
+pub fn max_drawdown(equity_curve: &[i64]) -> i64 {
+    let mut peak = i64::MIN;
+    let mut drawdown = 0;
+    for &equity in equity_curve {
+        peak = peak.max(equity);
+        drawdown = drawdown.max(peak.saturating_sub(equity));
+    }
+    drawdown
+}
+
+#[cfg(test)]
+mod test {
+    use super::{candles_vwap, kelly_fraction, max_drawdown, rolling_vwap, sharpe_ratio};
+    use crate::Candle;
+
+    #[test]
+    fn test_kelly_fraction() {
+        assert!((kelly_fraction(0.60, 50) - 0.2).abs() < 1e-9);
+        assert!((kelly_fraction(0.90, 80) - 0.5).abs() < 1e-9);
+        assert!((kelly_fraction(1.0, 30) - 1.0).abs() < 1e-9);
+        assert_eq!(kelly_fraction(0.40, 50), 0.0);
+        assert_eq!(kelly_fraction(0.50, 50), 0.0);
+        assert_eq!(kelly_fraction(0.60, 0), 0.0);
+        assert_eq!(kelly_fraction(0.60, 100), 0.0);
+        assert_eq!(kelly_fraction(f64::NAN, 50), 0.0);
+        assert_eq!(kelly_fraction(1.5, 50), 0.0);
+    }
+
+    fn candle(close: Option<&str>, volume: &str) -> Candle {
+        let bid_ask = serde_json::json!({
+            "open_dollars": "0.5000", "high_dollars": "0.5000", "low_dollars": "0.5000", "close_dollars": "0.5000",
+        });
+        serde_json::from_value(serde_json::json!({
+            "end_period_ts": 60,
+            "open_interest_fp": "10.00",
+            "price": { "close_dollars": close },
+            "volume_fp": volume,
+            "yes_ask": bid_ask,
+            "yes_bid": bid_ask,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rolling_vwap() {
+        let candles = vec![
+            candle(None, "0.00"),
+            candle(Some("0.4000"), "10.00"),
+            candle(Some("0.6000"), "30.00"),
+            candle(Some("0.9900"), "0.00"),
+            candle(None, "0.00"),
+            candle(None, "0.00"),
+        ];
+        let vwap = rolling_vwap(&candles, 2);
+        assert_eq!(vwap.len(), candles.len());
+        assert!(vwap[0].is_nan());
+        assert!((vwap[1] - 0.40).abs() < 1e-9);
+        assert!((vwap[2] - 0.55).abs() < 1e-9);
+        assert!((vwap[3] - 0.60).abs() < 1e-9);
+        // Nothing traded in the window: the last value carries.
+        assert!((vwap[4] - 0.60).abs() < 1e-9);
+        assert!((vwap[5] - 0.60).abs() < 1e-9);
+        assert_eq!(rolling_vwap(&candles, 0)[2], 0.6);
+        assert!(rolling_vwap(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_candles_vwap() {
+        let candles = vec![
+            candle(Some("0.4000"), "10.00"),
+            candle(Some("0.9900"), "0.00"),
+            candle(None, "5.00"),
+            candle(Some("0.6000"), "30.00"),
+        ];
+        assert!((candles_vwap(&candles).unwrap() - 0.55).abs() < 1e-9);
+        assert_eq!(candles_vwap(&candles[1..3]), None);
+        assert_eq!(candles_vwap(&[]), None);
+    }
+
+    #[test]
+    fn test_sharpe_ratio() {
+        // Mean 25, sample stdev 50 * sqrt(3): per period 1 / (2 * sqrt(3)), doubled over 4 periods.
+        let sharpe = sharpe_ratio(&[100, -50, 100, -50], 4.0);
+        assert!((sharpe - 1.0 / 3f64.sqrt()).abs() < 1e-9);
+        assert!(sharpe_ratio(&[-100, 50, -100, 50], 4.0) < 0.0);
+        assert_eq!(sharpe_ratio(&[100], 365.0), 0.0);
+        assert_eq!(sharpe_ratio(&[100, 100, 100], 365.0), 0.0);
+        assert_eq!(sharpe_ratio(&[100, -50], 0.0), 0.0);
+        assert_eq!(sharpe_ratio(&[100, -50], f64::NAN), 0.0);
+    }
+
+    #[test]
+    fn test_max_drawdown() {
+        assert_eq!(max_drawdown(&[1_000, 1_500, 900, 1_200, 600, 2_000, 1_800]), 900);
+        assert_eq!(max_drawdown(&[500, 400, 300]), 200);
+        assert_eq!(max_drawdown(&[-100, -300, 200]), 200);
+        assert_eq!(max_drawdown(&[1, 2, 3]), 0);
+        assert_eq!(max_drawdown(&[]), 0);
+    }
+}
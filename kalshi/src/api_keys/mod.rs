@@ -114,8 +114,70 @@ impl Kalshi {
 
     /// Retrieves the API rate-limit tier for the authenticated user.
     pub async fn get_account_api_limits(&self) -> Result<AccountApiLimits, KalshiError> {
-        self.signed_get("/account/api_limits").await
+        self.signed_get("/account/limits").await
     }
+
+    /// Describes the account and API key the client is authenticated as.
+    ///
+    /// Useful in multi-account setups to confirm the right key is loaded, and as a cheap
+    /// check that the credentials work at all. The API does not expose member ids or email
+    /// addresses, so the account is identified by the loaded key and its usage tier.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(AccountInfo)`: The key's name and scopes plus the account's API tier.
+    /// - `Err(KalshiError)`: `AuthFailed` if the exchange rejects the credentials (HTTP 401),
+    ///   or another error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let me = kalshi_instance.whoami().await.unwrap();
+    /// println!("key {} ({:?}) on tier {}", me.key_id, me.key_name, me.usage_tier);
+    /// ```
+    ///
+    pub async fn whoami(&self) -> Result<AccountInfo, KalshiError> {
+        let (limits, keys) = futures_util::future::try_join(self.get_account_api_limits(), self.get_api_keys())
+            .await
+            .map_err(|e| self.unauthorized_as_auth_failed(e))?;
+        let key = keys.into_iter().find(|k| k.api_key_id == self.key_id);
+        Ok(AccountInfo {
+            key_id: self.key_id.clone(),
+            key_name: key.as_ref().map(|k| k.name.clone()),
+            scopes: key.map(|k| k.scopes).unwrap_or_default(),
+            usage_tier: limits.usage_tier,
+            read_limit: limits.read_limit,
+            write_limit: limits.write_limit,
+        })
+    }
+
+    /// Turns a `401 Unauthorized` response into `AuthFailed` for the loaded key.
+    fn unauthorized_as_auth_failed(&self, err: KalshiError) -> KalshiError {
+        match err.status() {
+            Some(401) => KalshiError::AuthFailed { key_id: self.key_id.clone() },
+            _ => err,
+        }
+    }
+}
+
+// -------- Public models --------
+
+/// Result of [`Kalshi::whoami`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInfo {
+    /// The API key ID the client signs requests with.
+    pub key_id: String,
+    /// The name the key was created with, `None` if it isn't listed for the account.
+    pub key_name: Option<String>,
+    /// Scopes granted to the key, e.g. `["read", "write"]`.
+    pub scopes: Vec<String>,
+    /// The account's API usage tier.
+    pub usage_tier: String,
+    /// Maximum read requests per second.
+    pub read_limit: i64,
+    /// Maximum write requests per second.
+    pub write_limit: i64,
 }
 
 // -------- Request bodies --------
@@ -130,3 +192,30 @@ struct CreateApiKeyRequest {
 #[derive(Debug, Deserialize)]
 struct DeleteApiKeyResponse {}
 
+
+#[cfg(test)]
+mod test {
+    use crate::auth::test::offline_client;
+    use crate::KalshiError;
+
+    #[test]
+    fn test_unauthorized_is_auth_failed() {
+        let client = offline_client();
+        let status_error = |status: u16| -> KalshiError {
+            let mut resp = http::Response::new(Vec::<u8>::new());
+            *resp.status_mut() = http::StatusCode::from_u16(status).unwrap();
+            reqwest::Response::from(resp).error_for_status().unwrap_err().into()
+        };
+
+        match client.unauthorized_as_auth_failed(status_error(401)) {
+            KalshiError::AuthFailed { key_id } => assert_eq!(key_id, "test-key"),
+            other => panic!("expected AuthFailed, got {:?}", other),
+        }
+        let api = KalshiError::Api { status: 401, code: "unauthorized".to_string(), message: String::new() };
+        assert!(matches!(client.unauthorized_as_auth_failed(api), KalshiError::AuthFailed { .. }));
+        assert!(matches!(
+            client.unauthorized_as_auth_failed(status_error(403)),
+            KalshiError::RequestError(_)
+        ));
+    }
+}
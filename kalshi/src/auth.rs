@@ -4,10 +4,11 @@ use openssl::{
     rsa::Padding,
     sign::{RsaPssSaltlen, Signer},
 };
-use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use base64::Engine;
 
 use crate::kalshi_error::KalshiError;
+use crate::rate_limit::Priority;
 use crate::Kalshi; // struct defined in lib.rs
 
 impl Kalshi {
@@ -61,53 +62,600 @@ impl Kalshi {
         self.signed_request("PUT", path, body).await
     }
 
+    /// Like [`signed_get`](Kalshi::signed_get) for a paginated endpoint. When `cursor` is
+    /// set and the API rejects it, the error is reported as `InvalidCursor`; see
+    /// [`cursor_rejection`].
+    pub(crate) async fn signed_get_with_cursor<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        cursor: Option<&str>,
+    ) -> Result<T, KalshiError> {
+        self.signed_get(path).await.map_err(|e| cursor_rejection(e, cursor))
+    }
+
     async fn signed_request<B: serde::Serialize, T: serde::de::DeserializeOwned>(
         &self,
         method: &str,
         path: &str,
         body: Option<&B>,
     ) -> Result<T, KalshiError> {
-        let key_id = &self.key_id;
-        let pkey = &self.private_key;
+        observed(
+            async {
+                let builder = self.signed_builder(method, path)?;
+                let builder = match body {
+                    Some(b) => builder.json(b),
+                    None => builder,
+                };
+                let resp = self.check_status(self.send(builder).await?).await?;
+                Ok(self.buffered(resp).await?.json::<T>().await?)
+            }
+            .await,
+        )
+    }
+
+    /// Sends `builder`, recording request metrics when the `metrics` feature is enabled.
+    ///
+    /// Every request the client makes goes through here.
+    pub(crate) async fn send(&self, builder: reqwest::RequestBuilder) -> Result<reqwest::Response, KalshiError> {
+        self.execute(builder.build()?).await
+    }
+
+    /// Like [`send`](Kalshi::send) for an already built request.
+    pub(crate) async fn execute(&self, mut request: reqwest::Request) -> Result<reqwest::Response, KalshiError> {
+        self.apply_default_headers(&mut request);
+        self.execute_with_retries(request).await
+    }
+
+    /// Sends a single attempt of `request`, after waiting on the rate limiter.
+    pub(crate) async fn execute_once(&self, request: reqwest::Request) -> Result<reqwest::Response, KalshiError> {
+        if let Some(limiter) = &self.rate_limiter {
+            let priority = self.priority.unwrap_or_else(|| Priority::for_method(request.method()));
+            limiter.acquire(request.method(), priority).await;
+        }
+        #[cfg(feature = "metrics")]
+        let (method, started) = (request.method().clone(), std::time::Instant::now());
+        let result = self.client.execute(request).await;
+        #[cfg(feature = "metrics")]
+        crate::telemetry::record_response(&method, &result, started.elapsed());
+        Ok(result?)
+    }
+
+    /// Adds the headers from [`set_default_headers`](Kalshi::set_default_headers) that
+    /// `request` doesn't already have.
+    ///
+    /// Only requests to the origin of `base_url` get them: the defaults may hold gateway
+    /// tokens, which must not reach third-party hosts such as a contract document's.
+    fn apply_default_headers(&self, request: &mut reqwest::Request) {
+        let same_origin = reqwest::Url::parse(&self.base_url)
+            .is_ok_and(|base| base.origin() == request.url().origin());
+        if !same_origin {
+            return;
+        }
+        let headers = request.headers_mut();
+        for name in self.default_headers.keys() {
+            if !headers.contains_key(name) {
+                for value in self.default_headers.get_all(name) {
+                    headers.append(name, value.clone());
+                }
+            }
+        }
+    }
+
+    /// Sends `builder` and deserializes the JSON body, enforcing `max_response_bytes`.
+    pub(crate) async fn send_json<T: serde::de::DeserializeOwned>(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<T, KalshiError> {
+        observed(
+            async {
+                let resp = self.send(builder).await?;
+                self.json_or_invalid_cursor(resp, None).await
+            }
+            .await,
+        )
+    }
+
+    /// Like [`send_json`](Kalshi::send_json) for a paginated endpoint. When `cursor` is set
+    /// and the API rejects it, the error is reported as `InvalidCursor`; see
+    /// [`cursor_rejection`].
+    pub(crate) async fn send_json_with_cursor<T: serde::de::DeserializeOwned>(
+        &self,
+        builder: reqwest::RequestBuilder,
+        cursor: Option<&str>,
+    ) -> Result<T, KalshiError> {
+        observed(
+            async {
+                let resp = self.send(builder).await?;
+                self.json_or_invalid_cursor(resp, cursor).await
+            }
+            .await,
+        )
+    }
+
+    /// Deserializes `resp`, checking its status before its content type so that, for
+    /// example, a `429` served as an HTML page is still reported as `RateLimited`.
+    async fn json_or_invalid_cursor<T: serde::de::DeserializeOwned>(
+        &self,
+        resp: reqwest::Response,
+        cursor: Option<&str>,
+    ) -> Result<T, KalshiError> {
+        let resp = self.check_status(resp).await.map_err(|e| cursor_rejection(e, cursor))?;
+        Ok(self.buffered(resp).await?.json::<T>().await?)
+    }
+
+    /// Passes `resp` through if its status is a success, and turns it into an error otherwise.
+    ///
+    /// A body carrying Kalshi's error envelope, `{"error": {"code", "message"}}` or the
+    /// bare `{"code", "message"}`, becomes [`KalshiError::Api`]. An HTML body, such as a
+    /// gateway's maintenance page, becomes [`KalshiError::UnexpectedContentType`].
+    /// Anything else becomes the same status error `error_for_status` would give. A `429`
+    /// is always [`KalshiError::RateLimited`], with the wait from its `Retry-After` header.
+    pub(crate) async fn check_status(&self, resp: reqwest::Response) -> Result<reqwest::Response, KalshiError> {
+        let Err(status_error) = resp.error_for_status_ref() else {
+            return Ok(resp);
+        };
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(KalshiError::RateLimited { retry_after: crate::retry::retry_after(&resp) });
+        }
+        let status = resp.status().as_u16();
+        let content_type = content_type(&resp);
+        let body = self.read_body(resp).await.unwrap_or_default();
+        Err(api_error(status, &body)
+            .or_else(|| html_error(content_type.as_deref(), &body))
+            .unwrap_or_else(|| status_error.into()))
+    }
+
+    /// Reads the whole body of `resp`, failing with `ResponseTooLarge` as soon as it
+    /// exceeds `max_response_bytes`.
+    pub(crate) async fn read_body(&self, mut resp: reqwest::Response) -> Result<Vec<u8>, KalshiError> {
+        let limit = self.max_response_bytes;
+        if resp.content_length().is_some_and(|len| len > limit as u64) {
+            return Err(KalshiError::ResponseTooLarge { limit });
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = resp.chunk().await? {
+            if body.len() + chunk.len() > limit {
+                return Err(KalshiError::ResponseTooLarge { limit });
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+
+    /// Buffers `resp` through [`read_body`](Kalshi::read_body) and returns an equivalent
+    /// in-memory response, so callers keep using reqwest's `json()` and error types.
+    ///
+    /// HTML bodies (e.g. a CDN maintenance page) are rejected as `UnexpectedContentType`
+    /// here, since every caller expects JSON.
+    pub(crate) async fn buffered(&self, resp: reqwest::Response) -> Result<reqwest::Response, KalshiError> {
+        let mut rebuilt = http::Response::builder()
+            .status(resp.status())
+            .version(resp.version());
+        if let Some(headers) = rebuilt.headers_mut() {
+            headers.extend(resp.headers().clone());
+        }
+        let content_type = content_type(&resp);
+        let body = self.read_body(resp).await?;
+        if let Some(e) = html_error(content_type.as_deref(), &body) {
+            return Err(e);
+        }
+        let rebuilt = rebuilt
+            .body(body)
+            .map_err(|e| KalshiError::InternalError(format!("rebuilding buffered response: {}", e)))?;
+        Ok(reqwest::Response::from(rebuilt))
+    }
+
+    /// Builds a request for `path` carrying the key-based signature headers.
+    pub(crate) fn signed_builder(
+        &self,
+        method: &str,
+        path: &str,
+    ) -> Result<reqwest::RequestBuilder, KalshiError> {
+        if self.read_only && method != "GET" {
+            return Err(KalshiError::ReadOnly {
+                method: method.to_string(),
+                path: path.to_string(),
+            });
+        }
 
-        let ts_ms = Utc::now().timestamp_millis();
-        
         // Remove query parameters from path (like Python code does)
         let path_without_query = path.split('?').next().unwrap_or(path);
-        let message = format!("{ts_ms}{method}/trade-api/v2{path_without_query}");
+        let headers = self.auth_headers(method, &format!("/trade-api/v2{path_without_query}"))?;
+
+        // --- build request --------------------------------------------------
+        let url = format!("{}{}", self.base_url, path);
+        let builder = match method {
+            "GET" => self.client.get(&url),
+            "POST" => self.client.post(&url),
+            "PUT" => self.client.put(&url),
+            "DELETE" => self.client.delete(&url),
+            other => self.client.request(other.parse()?, &url),
+        }
+        .headers(headers);
+
+        Ok(builder)
+    }
+
+    /// The `KALSHI-ACCESS-*` headers authenticating a `method` request to `signed_path`,
+    /// the full path on the host (e.g. `/trade-api/v2/portfolio/balance`).
+    pub(crate) fn auth_headers(&self, method: &str, signed_path: &str) -> Result<HeaderMap, KalshiError> {
+        let ts_ms = Utc::now().timestamp_millis();
+        let message = format!("{ts_ms}{method}{signed_path}");
 
         // --- RSA-PSS / SHA-256 signature -----------------------------------
-        let mut signer = Signer::new(MessageDigest::sha256(), pkey)?;
+        let mut signer = Signer::new(MessageDigest::sha256(), &self.private_key)?;
         signer.set_rsa_padding(Padding::PKCS1_PSS)?;
         signer.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)?;
         signer.update(message.as_bytes())?;
         let sig_raw = signer.sign_to_vec()?;
         let sig_b64 = base64::engine::general_purpose::STANDARD.encode(sig_raw);
 
-        // --- build request --------------------------------------------------
-        let url = format!("{}{}", self.base_url, path);
         let mut headers = HeaderMap::with_capacity(3);
-        headers.insert("KALSHI-ACCESS-KEY", HeaderValue::from_str(key_id)?);
+        headers.insert("KALSHI-ACCESS-KEY", HeaderValue::from_str(&self.key_id)?);
         headers.insert("KALSHI-ACCESS-TIMESTAMP", HeaderValue::from(ts_ms));
         headers.insert("KALSHI-ACCESS-SIGNATURE", HeaderValue::from_str(&sig_b64)?);
+        Ok(headers)
+    }
+}
 
-        let builder = match method {
-            "GET" => self.client.get(&url),
-            "POST" => self.client.post(&url),
-            "PUT" => self.client.put(&url),
-            "DELETE" => self.client.delete(&url),
-            other => self.client.request(other.parse()?, &url),
+/// Counts `result`'s error by variant when the `metrics` feature is enabled, then returns it.
+pub(crate) fn observed<T>(result: Result<T, KalshiError>) -> Result<T, KalshiError> {
+    #[cfg(feature = "metrics")]
+    if let Err(e) = &result {
+        crate::telemetry::record_error(e);
+    }
+    result
+}
+
+/// Kalshi's error body, either wrapped in `error` or at the top level.
+#[derive(serde::Deserialize)]
+struct ErrorEnvelope {
+    error: Option<ErrorBody>,
+    #[serde(flatten)]
+    bare: ErrorBody,
+}
+
+#[derive(serde::Deserialize)]
+struct ErrorBody {
+    code: Option<String>,
+    message: Option<String>,
+    details: Option<String>,
+}
+
+/// The [`KalshiError::Api`] described by an error `body`, or `None` if it isn't Kalshi's
+/// error envelope.
+fn api_error(status: u16, body: &[u8]) -> Option<KalshiError> {
+    let envelope: ErrorEnvelope = serde_json::from_slice(body).ok()?;
+    let error = envelope.error.unwrap_or(envelope.bare);
+    if error.code.is_none() && error.message.is_none() {
+        return None;
+    }
+    let mut message = error.message.unwrap_or_default();
+    if let Some(details) = error.details.filter(|d| !d.is_empty()) {
+        message = if message.is_empty() { details } else { format!("{} ({})", message, details) };
+    }
+    Some(KalshiError::Api { status, code: error.code.unwrap_or_default(), message })
+}
+
+/// Turns `err` into `InvalidCursor` if it is the API rejecting `cursor`.
+///
+/// Only a `400` counts. When the body carried Kalshi's error envelope, its code or
+/// message must mention the cursor, so other bad parameters still surface as
+/// [`KalshiError::Api`]; a bare `400` without an envelope is blamed on the cursor.
+fn cursor_rejection(err: KalshiError, cursor: Option<&str>) -> KalshiError {
+    let Some(cursor) = cursor else {
+        return err;
+    };
+    let about_cursor = match &err {
+        KalshiError::Api { status: 400, code, message } => {
+            code.to_lowercase().contains("cursor") || message.to_lowercase().contains("cursor")
         }
-        .headers(headers);
+        KalshiError::Api { .. } => false,
+        other => other.status() == Some(400),
+    };
+    if about_cursor {
+        KalshiError::InvalidCursor { cursor: cursor.to_string() }
+    } else {
+        err
+    }
+}
+
+/// The `Content-Type` header of `resp`, if it is valid text.
+fn content_type(resp: &reqwest::Response) -> Option<String> {
+    resp.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok()).map(str::to_string)
+}
+
+/// `UnexpectedContentType` if `content_type` says `body` is an HTML page rather than JSON.
+fn html_error(content_type: Option<&str>, body: &[u8]) -> Option<KalshiError> {
+    let got = content_type.filter(|ct| ct.trim_start().starts_with("text/html"))?;
+    Some(KalshiError::UnexpectedContentType {
+        expected: "application/json".to_string(),
+        got: got.to_string(),
+        snippet: body_snippet(body),
+    })
+}
+
+/// Number of characters of an unexpected body kept in `UnexpectedContentType`.
+const SNIPPET_CHARS: usize = 200;
+
+/// The start of `body` as text with whitespace runs collapsed, for error messages.
+fn body_snippet(body: &[u8]) -> String {
+    let text = String::from_utf8_lossy(body);
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    collapsed.chars().take(SNIPPET_CHARS).collect()
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use crate::kalshi_error::RequestError;
+    use crate::{Kalshi, KalshiError};
+    use openssl::{pkey::PKey, rsa::Rsa};
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    /// A client with a throwaway key, for tests that never reach the network.
+    pub(crate) fn offline_client() -> Kalshi {
+        Kalshi {
+            base_url: "https://demo-api.kalshi.co/trade-api/v2".to_string(),
+            key_id: "test-key".to_string(),
+            private_key: PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap(),
+            client: reqwest::Client::new(),
+            https_client: reqwest::Client::builder().https_only(true).build().unwrap(),
+            #[cfg(feature = "cache")]
+            etag_cache: Default::default(),
+            read_only: false,
+            max_response_bytes: crate::DEFAULT_MAX_RESPONSE_BYTES,
+            default_headers: HeaderMap::new(),
+            dry_run: false,
+            market_cache: None,
+            rate_limiter: None,
+            retry_policy: None,
+            priority: None,
+        }
+    }
+
+    /// A client pointed at a local server that answers successive requests with
+    /// `responses`, as `(status, JSON body)`, one connection each. Bodies starting with
+    /// `<` are served as `text/html` instead.
+    pub(crate) async fn mock_client(responses: Vec<(u16, String)>) -> Kalshi {
+        recording_mock_client(responses).await.0
+    }
+
+    /// Like [`mock_client`], but also yields the request line (e.g.
+    /// `GET /trade-api/v2/markets?limit=100 HTTP/1.1`) of every request served.
+    pub(crate) async fn recording_mock_client(
+        responses: Vec<(u16, String)>,
+    ) -> (Kalshi, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests, received) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let _ = requests.send(request.lines().next().unwrap_or_default().to_string());
+                let content_type = if body.starts_with('<') { "text/html; charset=utf-8" } else { "application/json" };
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    content_type,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        let mut client = offline_client();
+        client.base_url = format!("http://{}", addr);
+        client.client = reqwest::Client::builder().no_proxy().build().unwrap();
+        (client, received)
+    }
 
-        let resp = if let Some(b) = body {
-            builder.json(b).send().await?
-        } else {
-            builder.send().await?
+    #[test]
+    fn test_read_only_refuses_writes() {
+        let client = offline_client();
+        assert!(client.signed_builder("POST", "/portfolio/orders").is_ok());
+
+        let read_only = client.read_only();
+        assert!(read_only.is_read_only());
+        assert!(!client.is_read_only());
+        assert!(read_only.signed_builder("GET", "/portfolio/balance").is_ok());
+        for method in ["POST", "PUT", "DELETE"] {
+            match read_only.signed_builder(method, "/portfolio/orders") {
+                Err(KalshiError::ReadOnly { method: m, path }) => {
+                    assert_eq!(m, method);
+                    assert_eq!(path, "/portfolio/orders");
+                }
+                other => panic!("expected ReadOnly, got {:?}", other.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_headers_keep_signature() {
+        let mut client = offline_client();
+        let mut defaults = HeaderMap::new();
+        defaults.insert("x-trace-id", HeaderValue::from_static("abc"));
+        defaults.append("x-gateway", HeaderValue::from_static("one"));
+        defaults.append("x-gateway", HeaderValue::from_static("two"));
+        defaults.insert("kalshi-access-key", HeaderValue::from_static("override"));
+        client.set_default_headers(defaults);
+
+        let mut request = client.signed_builder("GET", "/portfolio/balance").unwrap().build().unwrap();
+        client.apply_default_headers(&mut request);
+        let headers = request.headers();
+        assert_eq!(headers["x-trace-id"], "abc");
+        assert_eq!(headers.get_all("x-gateway").iter().collect::<Vec<_>>(), ["one", "two"]);
+        assert_eq!(headers["kalshi-access-key"], "test-key");
+    }
+
+    #[test]
+    fn test_default_headers_stay_on_api_host() {
+        let mut client = offline_client();
+        let mut defaults = HeaderMap::new();
+        defaults.insert("x-gateway-token", HeaderValue::from_static("secret"));
+        client.set_default_headers(defaults);
+
+        for url in [
+            "https://kalshi-public-docs.s3.amazonaws.com/contract.pdf",
+            "http://demo-api.kalshi.co/trade-api/v2/series/KXHIGHNY",
+            "https://demo-api.kalshi.co:8443/trade-api/v2/series/KXHIGHNY",
+        ] {
+            let mut request = client.client.get(url).build().unwrap();
+            client.apply_default_headers(&mut request);
+            assert!(request.headers().is_empty(), "{} got {:?}", url, request.headers());
+        }
+
+        let mut request = client.client.get("https://demo-api.kalshi.co/trade-api/v2/series/KXHIGHNY").build().unwrap();
+        client.apply_default_headers(&mut request);
+        assert_eq!(request.headers()["x-gateway-token"], "secret");
+    }
+
+    #[tokio::test]
+    async fn test_max_response_bytes() {
+        let mut client = offline_client();
+        client.set_max_response_bytes(16);
+        let small = reqwest::Response::from(http::Response::new(b"{\"ok\":true}".to_vec()));
+        let value: serde_json::Value = client.buffered(small).await.unwrap().json().await.unwrap();
+        assert_eq!(value, serde_json::json!({ "ok": true }));
+
+        let large = reqwest::Response::from(http::Response::new(vec![b' '; 17]));
+        match client.read_body(large).await {
+            Err(KalshiError::ResponseTooLarge { limit }) => assert_eq!(limit, 16),
+            other => panic!("expected ResponseTooLarge, got {:?}", other.map(|b| b.len())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejected_cursor_is_invalid_cursor() {
+        let client = offline_client();
+        let bad_request_with = |body: &'static str| {
+            let mut resp = http::Response::new(body.as_bytes().to_vec());
+            *resp.status_mut() = http::StatusCode::BAD_REQUEST;
+            reqwest::Response::from(resp)
+        };
+        let bad_request = || bad_request_with("{}");
+
+        let res = client
+            .json_or_invalid_cursor::<serde_json::Value>(bad_request(), Some("stale"))
+            .await;
+        match res {
+            Err(KalshiError::InvalidCursor { cursor }) => assert_eq!(cursor, "stale"),
+            other => panic!("expected InvalidCursor, got {:?}", other),
         }
-        .error_for_status()?;
+        let res = client.json_or_invalid_cursor::<serde_json::Value>(bad_request(), None).await;
+        assert!(!matches!(res, Err(KalshiError::InvalidCursor { .. })));
 
-        Ok(resp.json::<T>().await?)
+        let expired = bad_request_with(r#"{"error":{"code":"invalid_parameters","message":"Cursor has expired"}}"#);
+        let res = client.json_or_invalid_cursor::<serde_json::Value>(expired, Some("stale")).await;
+        assert!(matches!(res, Err(KalshiError::InvalidCursor { .. })));
+
+        // A 400 about another parameter keeps the API's own error.
+        let other = bad_request_with(r#"{"error":{"code":"invalid_parameters","message":"limit must be at most 1000"}}"#);
+        match client.json_or_invalid_cursor::<serde_json::Value>(other, Some("stale")).await {
+            Err(KalshiError::Api { status, message, .. }) => {
+                assert_eq!((status, message.as_str()), (400, "limit must be at most 1000"))
+            }
+            other => panic!("expected Api, got {:?}", other),
+        }
     }
 
+    #[tokio::test]
+    async fn test_error_body_is_api_error() {
+        let client = mock_client(vec![
+            (400, r#"{"error":{"code":"insufficient_balance","message":"Insufficient balance"}}"#.to_string()),
+            (404, r#"{"code":"not_found","message":"Market not found","details":"BAD-MARKET"}"#.to_string()),
+            (500, "{}".to_string()),
+        ])
+        .await;
+
+        match client.signed_get::<serde_json::Value>("/portfolio/balance").await {
+            Err(KalshiError::Api { status, code, message }) => {
+                assert_eq!((status, code.as_str(), message.as_str()), (400, "insufficient_balance", "Insufficient balance"));
+            }
+            other => panic!("expected Api, got {:?}", other),
+        }
+        match client.get_exchange_status().await {
+            Err(KalshiError::Api { status, code, message }) => {
+                assert_eq!((status, code.as_str(), message.as_str()), (404, "not_found", "Market not found (BAD-MARKET)"));
+            }
+            other => panic!("expected Api, got {:?}", other),
+        }
+        let err = client.signed_get::<serde_json::Value>("/portfolio/balance").await.unwrap_err();
+        assert!(matches!(err, KalshiError::RequestError(RequestError::ServerError(_))));
+        assert_eq!(err.status(), Some(500));
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_is_rate_limited() {
+        let client = offline_client();
+        let resp = |retry_after: Option<&str>| {
+            let mut builder = http::Response::builder().status(429);
+            if let Some(value) = retry_after {
+                builder = builder.header(reqwest::header::RETRY_AFTER, value);
+            }
+            reqwest::Response::from(builder.body(r#"{"code":"too_many_requests"}"#).unwrap())
+        };
+
+        match client.check_status(resp(Some("3"))).await {
+            Err(KalshiError::RateLimited { retry_after }) => {
+                assert_eq!(retry_after, Some(std::time::Duration::from_secs(3)))
+            }
+            other => panic!("expected RateLimited, got {:?}", other.map(|r| r.status())),
+        }
+        let err = client.check_status(resp(None)).await.unwrap_err();
+        assert!(matches!(err, KalshiError::RateLimited { retry_after: None }));
+        assert!(err.is_retryable());
+        assert_eq!(err.status(), Some(429));
+    }
+
+    #[tokio::test]
+    async fn test_html_body_is_unexpected_content_type() {
+        let client = mock_client(vec![
+            (503, "<html>\n  <body>Down for   maintenance</body>\n</html>".to_string()),
+            (200, "<html><body>Sign in</body></html>".to_string()),
+            (200, "{}".to_string()),
+        ])
+        .await;
+        let get = || client.send_json::<serde_json::Value>(client.signed_builder("GET", "/exchange/status").unwrap());
+
+        match get().await {
+            Err(KalshiError::UnexpectedContentType { expected, got, snippet }) => {
+                assert_eq!(expected, "application/json");
+                assert_eq!(got, "text/html; charset=utf-8");
+                assert_eq!(snippet, "<html> <body>Down for maintenance</body> </html>");
+            }
+            other => panic!("expected UnexpectedContentType, got {:?}", other),
+        }
+        assert!(matches!(get().await, Err(KalshiError::UnexpectedContentType { .. })));
+        assert!(get().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_html_429_is_rate_limited() {
+        let client = offline_client();
+        let page = http::Response::builder()
+            .status(429)
+            .header("content-type", "text/html")
+            .header("retry-after", "2")
+            .body(b"<html><body>Slow down</body></html>".to_vec())
+            .unwrap();
+        match client.json_or_invalid_cursor::<serde_json::Value>(reqwest::Response::from(page), None).await {
+            Err(KalshiError::RateLimited { retry_after }) => {
+                assert_eq!(retry_after, Some(std::time::Duration::from_secs(2)))
+            }
+            other => panic!("expected RateLimited, got {:?}", other),
+        }
+    }
 }
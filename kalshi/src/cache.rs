@@ -0,0 +1,72 @@
+//! cache.rs – ETag-based conditional requests (enabled with the `cache` feature)
+use reqwest::header::{HeaderValue, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::auth::observed;
+use crate::kalshi_error::KalshiError;
+use crate::Kalshi;
+
+/// Cached bodies keyed by request URL, alongside the `ETag` they were served with.
+pub(crate) type EtagCache = Arc<Mutex<HashMap<String, (String, serde_json::Value)>>>;
+
+impl Kalshi {
+    /// Drops every response cached through `ETag` revalidation.
+    ///
+    /// Only available with the `cache` feature.
+    pub fn clear_response_cache(&self) {
+        self.etag_cache.lock().unwrap().clear();
+    }
+
+    /// Sends `builder`, revalidating against a previously cached response.
+    ///
+    /// If an earlier response for the same URL carried an `ETag`, it is sent back as
+    /// `If-None-Match`; a `304 Not Modified` then returns the cached body without
+    /// re-downloading it. Responses without an `ETag` are never cached, so endpoints
+    /// that don't support conditional requests simply behave as uncached.
+    pub(crate) async fn etag_get<T: serde::de::DeserializeOwned>(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<T, KalshiError> {
+        let mut request = builder.build()?;
+        let key = request.url().to_string();
+
+        let cached = self.etag_cache.lock().unwrap().get(&key).cloned();
+        if let Some((etag, _)) = &cached {
+            request
+                .headers_mut()
+                .insert(IF_NONE_MATCH, HeaderValue::from_str(etag)?);
+        }
+
+        observed(
+            async {
+                let resp = self.execute(request).await?;
+                let body = match (resp.status(), cached) {
+                    (StatusCode::NOT_MODIFIED, Some((_, body))) => body,
+                    _ => {
+                        let resp = self.buffered(self.check_status(resp).await?).await?;
+                        let etag = resp
+                            .headers()
+                            .get(ETAG)
+                            .and_then(|v| v.to_str().ok())
+                            .map(str::to_string);
+                        let body: serde_json::Value = resp.json().await?;
+                        if let Some(etag) = etag {
+                            self.etag_cache
+                                .lock()
+                                .unwrap()
+                                .insert(key, (etag, body.clone()));
+                        }
+                        body
+                    }
+                };
+
+                serde_json::from_value(body).map_err(|e| {
+                    KalshiError::InternalError(format!("cached response deserialization: {}", e))
+                })
+            }
+            .await,
+        )
+    }
+}
@@ -357,7 +357,7 @@ struct DeleteQuoteResponse {}
 // -------- Public models --------
 
 /// Represents a communication message or thread.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Communication {
     /// The communication ID.
     pub id: String,
@@ -374,7 +374,7 @@ pub struct Communication {
 }
 
 /// Represents an RFQ (Request for Quote).
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Rfq {
     /// The RFQ ID.
     pub id: String,
@@ -395,7 +395,7 @@ pub struct Rfq {
 }
 
 /// Represents a quote offer.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Quote {
     /// The quote ID.
     pub id: String,
@@ -414,7 +414,7 @@ pub struct Quote {
 }
 
 /// Represents an accepted quote.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct QuoteAccepted {
     /// The quote ID.
     pub quote_id: String,
@@ -425,7 +425,7 @@ pub struct QuoteAccepted {
 }
 
 /// Represents a confirmed quote.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct QuoteConfirmed {
     /// The quote ID.
     pub quote_id: String,
@@ -0,0 +1,335 @@
+//! enums.rs – string enums returned by the API, with an `Unknown` fallback
+//!
+//! Progenitor generates closed enums for the inline `enum:` lists in the spec,
+//! so a single value the exchange adds later fails deserialization of the whole
+//! response. `build.rs` swaps the response-side enums for the types below, which
+//! keep any unrecognised value in an `Unknown(String)` variant instead.
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::deserialize_enum_or_unknown;
+
+macro_rules! lenient_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// A value not known to this version of the crate, kept verbatim.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The string the API uses for this value.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Unknown(raw) => raw,
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(match value {
+                    $($value => Self::$variant,)+
+                    other => Self::Unknown(other.to_string()),
+                })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize_enum_or_unknown(deserializer)
+            }
+        }
+    };
+}
+
+lenient_enum! {
+    /// Side of a binary contract: an order, a fill, a trade's taker or an accepted quote.
+    ///
+    /// The API names this type per field; [`OrderSide`], [`FillSide`], [`TradeTakerSide`]
+    /// and [`QuoteAcceptedSide`] are all aliases of it.
+    Side {
+        Yes => "yes",
+        No => "no",
+    }
+}
+
+impl Side {
+    /// The other side of the same contract. An unknown side is returned unchanged.
+    pub fn opposite(&self) -> Side {
+        match self {
+            Side::Yes => Side::No,
+            Side::No => Side::Yes,
+            Side::Unknown(raw) => Side::Unknown(raw.clone()),
+        }
+    }
+}
+
+lenient_enum! {
+    /// Whether contracts are bought or sold, by an order or a fill.
+    ///
+    /// [`OrderAction`] and [`FillAction`] are aliases of it.
+    Action {
+        Buy => "buy",
+        Sell => "sell",
+    }
+}
+
+lenient_enum! {
+    /// Kind of exchange announcement.
+    AnnouncementType {
+        Info => "info",
+        Warning => "warning",
+        Error => "error",
+    }
+}
+
+lenient_enum! {
+    /// Whether an exchange announcement is currently shown.
+    AnnouncementStatus {
+        Active => "active",
+        Inactive => "inactive",
+    }
+}
+
+lenient_enum! {
+    /// Exchange instance a position or balance belongs to.
+    ExchangeInstance {
+        EventContract => "event_contract",
+        Margined => "margined",
+    }
+}
+
+/// Side taken by a fill.
+pub type FillSide = Side;
+
+/// Whether a fill bought or sold contracts.
+pub type FillAction = Action;
+
+lenient_enum! {
+    /// Reward type of an incentive program.
+    IncentiveProgramIncentiveType {
+        Liquidity => "liquidity",
+        Volume => "volume",
+    }
+}
+
+lenient_enum! {
+    /// Lifecycle state of a market.
+    MarketStatus {
+        Initialized => "initialized",
+        Inactive => "inactive",
+        Active => "active",
+        Closed => "closed",
+        Determined => "determined",
+        Disputed => "disputed",
+        Amended => "amended",
+        Finalized => "finalized",
+    }
+}
+
+lenient_enum! {
+    /// Outcome a market resolved to.
+    MarketResult {
+        Yes => "yes",
+        No => "no",
+        Scalar => "scalar",
+        /// The market has not resolved yet (sent as an empty string).
+        Undetermined => "",
+    }
+}
+
+lenient_enum! {
+    /// Payout structure of a market.
+    MarketMarketType {
+        Binary => "binary",
+        Scalar => "scalar",
+    }
+}
+
+lenient_enum! {
+    /// How a market's strike values should be interpreted.
+    MarketStrikeType {
+        Greater => "greater",
+        GreaterOrEqual => "greater_or_equal",
+        Less => "less",
+        LessOrEqual => "less_or_equal",
+        Between => "between",
+        Functional => "functional",
+        Custom => "custom",
+        Structured => "structured",
+    }
+}
+
+lenient_enum! {
+    /// Deprecated price unit of a market; always `usd_cent` today.
+    MarketResponsePriceUnits {
+        UsdCent => "usd_cent",
+    }
+}
+
+/// Side of an order.
+pub type OrderSide = Side;
+
+/// Whether an order buys or sells contracts.
+pub type OrderAction = Action;
+
+lenient_enum! {
+    /// Order type.
+    OrderType {
+        Limit => "limit",
+        Market => "market",
+    }
+}
+
+lenient_enum! {
+    /// Lifecycle state of an order.
+    OrderStatus {
+        Resting => "resting",
+        Canceled => "canceled",
+        Executed => "executed",
+    }
+}
+
+lenient_enum! {
+    /// Lifecycle state of a quote.
+    QuoteStatus {
+        Open => "open",
+        Accepted => "accepted",
+        Confirmed => "confirmed",
+        Executed => "executed",
+        Cancelled => "cancelled",
+    }
+}
+
+/// Side a quote was accepted on.
+pub type QuoteAcceptedSide = Side;
+
+lenient_enum! {
+    /// Lifecycle state of a request for quote.
+    RfqStatus {
+        Open => "open",
+        Closed => "closed",
+    }
+}
+
+lenient_enum! {
+    /// How self-trades are prevented for an order.
+    SelfTradePreventionType {
+        TakerAtCross => "taker_at_cross",
+        Maker => "maker",
+    }
+}
+
+lenient_enum! {
+    /// Fee schedule applied to a series.
+    SeriesFeeType {
+        Quadratic => "quadratic",
+        QuadraticWithMakerFees => "quadratic_with_maker_fees",
+        Flat => "flat",
+    }
+}
+
+lenient_enum! {
+    /// Fee schedule a scheduled series fee change moves to.
+    SeriesFeeChangeFeeType {
+        Quadratic => "quadratic",
+        QuadraticWithMakerFees => "quadratic_with_maker_fees",
+        Flat => "flat",
+    }
+}
+
+lenient_enum! {
+    /// Outcome a settled position was paid out on.
+    SettlementMarketResult {
+        Yes => "yes",
+        No => "no",
+        Scalar => "scalar",
+        Void => "void",
+    }
+}
+
+/// Side of the taker in a public trade.
+pub type TradeTakerSide = Side;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::de::DeserializeOwned;
+
+    fn assert_unknown<T>(expected: fn(String) -> T)
+    where
+        T: DeserializeOwned + Serialize + PartialEq + fmt::Debug,
+    {
+        let value: T = serde_json::from_str("\"brand_new_value\"").unwrap();
+        assert_eq!(value, expected("brand_new_value".to_string()));
+        assert_eq!(serde_json::to_string(&value).unwrap(), "\"brand_new_value\"");
+    }
+
+    #[test]
+    fn test_unknown_values_fall_back() {
+        assert_unknown(Side::Unknown);
+        assert_unknown(Action::Unknown);
+        assert_unknown(AnnouncementType::Unknown);
+        assert_unknown(AnnouncementStatus::Unknown);
+        assert_unknown(ExchangeInstance::Unknown);
+        assert_unknown(IncentiveProgramIncentiveType::Unknown);
+        assert_unknown(MarketStatus::Unknown);
+        assert_unknown(MarketResult::Unknown);
+        assert_unknown(MarketMarketType::Unknown);
+        assert_unknown(MarketStrikeType::Unknown);
+        assert_unknown(MarketResponsePriceUnits::Unknown);
+        assert_unknown(OrderType::Unknown);
+        assert_unknown(OrderStatus::Unknown);
+        assert_unknown(QuoteStatus::Unknown);
+        assert_unknown(RfqStatus::Unknown);
+        assert_unknown(SelfTradePreventionType::Unknown);
+        assert_unknown(SeriesFeeType::Unknown);
+        assert_unknown(SeriesFeeChangeFeeType::Unknown);
+        assert_unknown(SettlementMarketResult::Unknown);
+    }
+
+    #[test]
+    fn test_known_values_round_trip() {
+        let status: MarketStatus = serde_json::from_str("\"finalized\"").unwrap();
+        assert_eq!(status, MarketStatus::Finalized);
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"finalized\"");
+
+        let result: MarketResult = serde_json::from_str("\"\"").unwrap();
+        assert_eq!(result, MarketResult::Undetermined);
+        assert_eq!("void".parse::<SettlementMarketResult>(), Ok(SettlementMarketResult::Void));
+    }
+
+    #[test]
+    fn test_side_opposite() {
+        assert_eq!(Side::Yes.opposite(), Side::No);
+        assert_eq!(Side::No.opposite(), Side::Yes);
+        assert_eq!(Side::Unknown("maybe".into()).opposite(), Side::Unknown("maybe".into()));
+        assert_eq!(Side::No.to_string(), "no");
+        assert_eq!(Action::Sell.to_string(), "sell");
+    }
+}
@@ -1,12 +1,146 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
-use serde::{Deserialize, Serialize};
+use crate::utils::{next_cursor, with_query};
+use crate::MarketFilter;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Deserializer, Serialize};
 
 pub use crate::generated::types::{EventData as Event, MarketCandlestick};
+use crate::generated::types::{FixedPointCount, Market};
+
+/// Maximum number of concurrent `/markets` lookups in [`Kalshi::get_events_with_markets`].
+const NESTED_MARKETS_FALLBACK_CONCURRENCY: usize = 4;
+
+/// Page size requested by [`Kalshi::events_stream`].
+const EVENTS_STREAM_PAGE_SIZE: i64 = 100;
+
+/// Largest page size `/events` accepts.
+const EVENTS_PAGE_LIMIT_MAX: i64 = 200;
+
+/// One page request for [`Kalshi::events`], built with chained setters.
+///
+/// Unset parameters are left out of the request, so the server defaults apply.
+///
+/// # Example
+///
+/// ```
+/// let request = GetEventsRequest::new().status("open").with_nested_markets(true).limit(10);
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetEventsRequest {
+    /// Page size, from 1 to 200.
+    pub limit: Option<i64>,
+    /// Pagination cursor from a previous page.
+    pub cursor: Option<String>,
+    /// Only events with this status (e.g. "open", "closed", "settled").
+    pub status: Option<String>,
+    /// Only events belonging to this series.
+    pub series_ticker: Option<String>,
+    /// Whether to nest each event's markets in [`Event::markets`].
+    pub with_nested_markets: Option<bool>,
+    /// Whether to include related milestones.
+    pub with_milestones: Option<bool>,
+    /// Only events with at least one market closing after this Unix timestamp.
+    pub min_close_ts: Option<i64>,
+}
+
+impl GetEventsRequest {
+    /// A request with no parameters: the first page of all events.
+    pub fn new() -> GetEventsRequest {
+        GetEventsRequest::default()
+    }
+
+    /// Sets the page size, from 1 to 200.
+    pub fn limit(mut self, limit: i64) -> GetEventsRequest {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the cursor of the page to fetch.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> GetEventsRequest {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Only events with this status.
+    pub fn status(mut self, status: impl Into<String>) -> GetEventsRequest {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Only events belonging to this series.
+    pub fn series_ticker(mut self, series_ticker: impl Into<String>) -> GetEventsRequest {
+        self.series_ticker = Some(series_ticker.into());
+        self
+    }
+
+    /// Whether to nest each event's markets in [`Event::markets`].
+    pub fn with_nested_markets(mut self, with_nested_markets: bool) -> GetEventsRequest {
+        self.with_nested_markets = Some(with_nested_markets);
+        self
+    }
+
+    /// Whether to include related milestones.
+    pub fn with_milestones(mut self, with_milestones: bool) -> GetEventsRequest {
+        self.with_milestones = Some(with_milestones);
+        self
+    }
+
+    /// Only events with at least one market closing after this Unix timestamp.
+    pub fn min_close_ts(mut self, min_close_ts: i64) -> GetEventsRequest {
+        self.min_close_ts = Some(min_close_ts);
+        self
+    }
+}
 
 impl Kalshi {
+    /// Retrieves one page of events described by a [`GetEventsRequest`].
+    ///
+    /// The recommended way to query `/events`: the same request as
+    /// [`get_events`](Kalshi::get_events), without a long list of positional `None`s.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((Option<String>, Vec<Event>))`: The cursor of the next page, if any, and the
+    ///   events on this one.
+    /// - `Err(KalshiError::UserInputError)`: If `limit` is outside `1..=200`; no request is
+    ///   sent.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let request = GetEventsRequest::new().status("open").with_nested_markets(true).limit(10);
+    /// let (cursor, events) = kalshi_instance.events(request).await.unwrap();
+    /// ```
+    ///
+    pub async fn events(&self, request: GetEventsRequest) -> Result<(Option<String>, Vec<Event>), KalshiError> {
+        if let Some(limit) = request.limit.filter(|l| !(1..=EVENTS_PAGE_LIMIT_MAX).contains(l)) {
+            return Err(KalshiError::UserInputError(format!(
+                "events limit must be between 1 and {}, got {}",
+                EVENTS_PAGE_LIMIT_MAX, limit
+            )));
+        }
+        let GetEventsRequest {
+            limit,
+            cursor,
+            status,
+            series_ticker,
+            with_nested_markets,
+            with_milestones,
+            min_close_ts,
+        } = request;
+        self.get_events(limit, cursor, status, series_ticker, with_nested_markets, with_milestones, min_close_ts)
+            .await
+    }
+
     /// Retrieves a list of events from the Kalshi exchange based on specified criteria.
     ///
+    /// Prefer [`events`](Kalshi::events), which takes the same parameters as a
+    /// [`GetEventsRequest`]; this positional form is kept for compatibility.
+    ///
     /// This method fetches multiple events, allowing for filtering by status, series ticker,
     /// and pagination. The events represent prediction markets that users can trade on.
     ///
@@ -24,7 +158,8 @@ impl Kalshi {
     ///
     /// - `Ok((Option<String>, Vec<Event>))`: A tuple containing an optional pagination cursor
     ///   and a vector of `Event` objects on successful retrieval.
-    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    /// - `Err(KalshiError)`: `InvalidCursor` if the API rejects `cursor`, or an error if
+    ///   there is an issue with the request.
     ///
     /// # Example
     ///
@@ -35,6 +170,7 @@ impl Kalshi {
     /// ).await.unwrap();
     /// ```
     ///
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_events(
         &self,
         limit: Option<i64>,
@@ -57,32 +193,137 @@ impl Kalshi {
 
         let url = format!("{}{}", self.base_url, path);
         let final_url = reqwest::Url::parse_with_params(&url, &params)?;
-        let res: EventListResponse = self.client.get(final_url).send().await?.json().await?;
+        let res: EventListResponse = self.send_json_with_cursor(self.client.get(final_url), cursor.as_deref()).await?;
         Ok((res.cursor, res.events))
     }
 
-    /// Retrieves detailed information about a specific event from the Kalshi exchange.
+    /// Streams every event matching the filters, following pagination cursors.
     ///
-    /// This method fetches data for a single event identified by its event ticker.
-    /// The event represents a prediction market with associated markets that users can trade on.
+    /// Pages of 100 events are requested from `/events` one at a time, as the stream is
+    /// polled, until the API returns an empty cursor. If a request fails, or the API
+    /// hands back the cursor it was just sent, the error is yielded and the stream ends.
     ///
     /// # Arguments
     ///
-    /// * `event_ticker` - A string slice referencing the event's unique ticker identifier.
+    /// * `status` - An optional string to filter events by their status.
+    /// * `series_ticker` - An optional string to filter events by series ticker.
+    /// * `with_nested_markets` - An optional boolean to include nested markets.
     ///
     /// # Returns
     ///
-    /// - `Ok(Event)`: Detailed information about the specified event on successful retrieval.
-    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    /// A stream of `Result<Event, KalshiError>` that borrows this client.
     ///
     /// # Example
     ///
     /// ```
     /// // Assuming `kalshi_instance` is an instance of `Kalshi`
-    /// let event_ticker = "SOME-EVENT-2024";
-    /// let event = kalshi_instance.get_event(event_ticker).await.unwrap();
+    /// use futures_util::StreamExt;
+    ///
+    /// let events = kalshi_instance.events_stream(Some("open".to_string()), None, None);
+    /// futures_util::pin_mut!(events);
+    /// while let Some(event) = events.next().await {
+    ///     println!("{}", event.unwrap().event_ticker);
+    /// }
     /// ```
     ///
+    pub fn events_stream(
+        &self,
+        status: Option<String>,
+        series_ticker: Option<String>,
+        with_nested_markets: Option<bool>,
+    ) -> impl Stream<Item = Result<Event, KalshiError>> + '_ {
+        // `None` once the last page has been fetched; `Some(cursor)` otherwise.
+        stream::unfold(Some(None::<String>), move |state| {
+            let (status, series_ticker) = (status.clone(), series_ticker.clone());
+            async move {
+                let cursor = state?;
+                let page = self
+                    .get_events(
+                        Some(EVENTS_STREAM_PAGE_SIZE),
+                        cursor.clone(),
+                        status,
+                        series_ticker,
+                        with_nested_markets,
+                        None,
+                        None,
+                    )
+                    .await
+                    .and_then(|(next, events)| Ok((next_cursor(cursor.as_deref(), next)?, events)));
+                Some(match page {
+                    Ok((next, events)) => (events.into_iter().map(Ok).collect(), next.map(Some)),
+                    Err(e) => (vec![Err(e)], None),
+                })
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Retrieves events together with all of their markets.
+    ///
+    /// Requests `/events` with `with_nested_markets=true`. Some API versions ignore that
+    /// flag and return no markets (or `markets: null`), so every event that comes back
+    /// without markets is filled in from `/markets?event_ticker=...`, following every page.
+    /// At most four of those fallback lookups run at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - An optional integer to limit the number of events returned.
+    /// * `cursor` - An optional string for pagination cursor.
+    /// * `status` - An optional string to filter events by their status.
+    /// * `series_ticker` - An optional string to filter events by series ticker.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((Option<String>, Vec<Event>))`: The pagination cursor and the events, each
+    ///   with `markets` populated.
+    /// - `Err(KalshiError)`: An error if the events request or any fallback request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let (cursor, events) = kalshi_instance
+    ///     .get_events_with_markets(Some(50), None, Some("open".to_string()), None)
+    ///     .await
+    ///     .unwrap();
+    /// ```
+    ///
+    pub async fn get_events_with_markets(
+        &self,
+        limit: Option<i64>,
+        cursor: Option<String>,
+        status: Option<String>,
+        series_ticker: Option<String>,
+    ) -> Result<(Option<String>, Vec<Event>), KalshiError> {
+        let mut params = vec![];
+        add_param!(params, "limit", limit);
+        add_param!(params, "cursor", cursor);
+        add_param!(params, "status", status);
+        add_param!(params, "series_ticker", series_ticker);
+        params.push(("with_nested_markets", "true".to_string()));
+
+        let url = format!("{}/events", self.base_url);
+        let final_url = reqwest::Url::parse_with_params(&url, &params)?;
+        let res: NestedEventListResponse =
+            self.send_json_with_cursor(self.client.get(final_url), cursor.as_deref()).await?;
+
+        let events = stream::iter(res.events)
+            .map(|mut event| async move {
+                if event.markets.is_empty() {
+                    let filter = MarketFilter {
+                        event_ticker: Some(event.event_ticker.clone()),
+                        ..Default::default()
+                    };
+                    event.markets = self.collect_markets(&filter).await?;
+                }
+                Ok::<_, KalshiError>(event)
+            })
+            .buffered(NESTED_MARKETS_FALLBACK_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok((res.cursor, events))
+    }
+
     /// Retrieves multivariate (combo) events, optionally filtered by series or collection.
     pub async fn get_multivariate_events(
         &self,
@@ -110,9 +351,74 @@ impl Kalshi {
         Ok((res.cursor, res.events))
     }
 
+    /// Retrieves detailed information about a specific event from the Kalshi exchange.
+    ///
+    /// This method fetches data for a single event identified by its event ticker.
+    /// The event represents a prediction market with associated markets that users can trade on.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_ticker` - A string slice referencing the event's unique ticker identifier.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Event)`: Detailed information about the specified event on successful retrieval.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let event_ticker = "SOME-EVENT-2024";
+    /// let event = kalshi_instance.get_event(event_ticker).await.unwrap();
+    /// ```
+    ///
+    /// With the `cache` feature enabled, repeated calls revalidate with `If-None-Match`
+    /// and reuse the cached event when the server answers `304 Not Modified`.
+    ///
     pub async fn get_event(&self, event_ticker: &str) -> Result<Event, KalshiError> {
-        let path = format!("/events/{}", event_ticker);
-        self.signed_get(&path).await
+        Ok(self.fetch_event(event_ticker, None).await?.event)
+    }
+
+    /// Retrieves a single event with its markets in [`Event::markets`].
+    ///
+    /// Like [`get_event`](Kalshi::get_event), but requests `with_nested_markets=true`.
+    /// The endpoint also returns the markets in a deprecated top-level `markets` field;
+    /// if the event itself comes back without markets, they are taken from there.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let event = kalshi_instance.get_event_with_markets("SOME-EVENT-2024").await.unwrap();
+    /// for market in &event.markets {
+    ///     println!("{}: {}", market.ticker, market.yes_bid_dollars.0);
+    /// }
+    /// ```
+    ///
+    pub async fn get_event_with_markets(&self, event_ticker: &str) -> Result<Event, KalshiError> {
+        let res = self.fetch_event(event_ticker, Some(true)).await?;
+        let mut event = res.event;
+        if event.markets.is_empty() {
+            event.markets = res.markets;
+        }
+        Ok(event)
+    }
+
+    /// Fetches `/events/{event_ticker}`, shared by the single-event getters.
+    async fn fetch_event(
+        &self,
+        event_ticker: &str,
+        with_nested_markets: Option<bool>,
+    ) -> Result<SingleEventResponse, KalshiError> {
+        let mut params = vec![];
+        add_param!(params, "with_nested_markets", with_nested_markets);
+        let path = with_query(format!("/events/{}", event_ticker), &params)?;
+        #[cfg(feature = "cache")]
+        let res = self.etag_get(self.signed_builder("GET", &path)?).await?;
+        #[cfg(not(feature = "cache"))]
+        let res = self.signed_get(&path).await?;
+        Ok(res)
     }
 
     /// Retrieves candlestick data aggregated across all markets in an event.
@@ -158,7 +464,7 @@ impl Kalshi {
 
         let url = format!("{}{}", self.base_url, path);
         let final_url = reqwest::Url::parse_with_params(&url, &params)?;
-        let res: CandlestickResponse = self.client.get(final_url).send().await?.json().await?;
+        let res: CandlestickResponse = self.send_json(self.client.get(final_url)).await?;
         Ok(res.candlesticks)
     }
 
@@ -226,6 +532,27 @@ struct EventListResponse {
     events: Vec<Event>,
 }
 
+/// Like [`EventListResponse`], but tolerates `markets: null` on the nested events.
+#[derive(Debug, Deserialize)]
+struct NestedEventListResponse {
+    cursor: Option<String>,
+    #[serde(deserialize_with = "deserialize_events_null_markets")]
+    events: Vec<Event>,
+}
+
+fn deserialize_events_null_markets<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Event>, D::Error> {
+    let events = Vec::<serde_json::Value>::deserialize(deserializer)?;
+    events
+        .into_iter()
+        .map(|mut event| {
+            if let Some(markets) = event.get_mut("markets").filter(|m| m.is_null()) {
+                *markets = serde_json::Value::Array(Vec::new());
+            }
+            serde_json::from_value(event).map_err(serde::de::Error::custom)
+        })
+        .collect()
+}
+
 #[derive(Debug, Deserialize)]
 struct MultivariateEventListResponse {
     cursor: Option<String>,
@@ -235,6 +562,9 @@ struct MultivariateEventListResponse {
 #[derive(Debug, Deserialize)]
 struct SingleEventResponse {
     event: Event,
+    /// Deprecated duplicate of the event's markets, sent whether or not they are nested.
+    #[serde(default)]
+    markets: Vec<Market>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -242,25 +572,258 @@ struct CandlestickResponse {
     candlesticks: Vec<MarketCandlestick>,
 }
 
+// -------- Event helpers --------
+
+impl Event {
+    /// Fetches the event with `event_ticker`; shorthand for [`Kalshi::get_event`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let event = Event::load(&kalshi_instance, "SOME-EVENT").await.unwrap();
+    /// ```
+    ///
+    pub async fn load(kalshi: &Kalshi, event_ticker: &str) -> Result<Event, KalshiError> {
+        kalshi.get_event(event_ticker).await
+    }
+
+    /// Total contracts traded across the event's nested markets.
+    ///
+    /// Markets are only nested when the event was fetched with `with_nested_markets`;
+    /// otherwise this is `0`.
+    pub fn total_volume(&self) -> i64 {
+        sum_counts(self.markets.iter().map(|m| &m.volume_fp))
+    }
+
+    /// Total open interest across the event's nested markets, or `0` if none are nested.
+    pub fn total_open_interest(&self) -> i64 {
+        sum_counts(self.markets.iter().map(|m| &m.open_interest_fp))
+    }
+
+    /// The nested market with the most 24h volume, ties broken by open interest.
+    ///
+    /// Kalshi no longer populates `liquidity_dollars`, so recent activity stands in
+    /// for liquidity. Returns `None` if no markets are nested.
+    pub fn most_liquid_market(&self) -> Option<&Market> {
+        let key = |m: &Market| {
+            (
+                m.volume_24h_fp.to_f64().unwrap_or(0.0),
+                m.open_interest_fp.to_f64().unwrap_or(0.0),
+            )
+        };
+        self.markets
+            .iter()
+            .max_by(|a, b| key(a).partial_cmp(&key(b)).unwrap_or(std::cmp::Ordering::Equal))
+    }
+}
+
+/// Sums fixed-point counts, skipping unparseable values, rounded to whole contracts.
+fn sum_counts<'a>(counts: impl Iterator<Item = &'a FixedPointCount>) -> i64 {
+    counts.filter_map(FixedPointCount::to_f64).sum::<f64>().round() as i64
+}
+
 // -------- Public models --------
 
 /// Represents additional metadata for an event.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EventMetadata {
     #[serde(flatten)]
     pub fields: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Represents forecast percentile history for an event.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ForecastPercentileHistory {
     pub history: Vec<ForecastDataPoint>,
 }
 
 /// Represents a single forecast data point.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ForecastDataPoint {
     pub ts: String,
     pub percentiles: std::collections::HashMap<String, f64>,
 }
 
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn market(ticker: &str, volume: &str, volume_24h: &str, open_interest: &str) -> serde_json::Value {
+        serde_json::json!({
+            "can_close_early": true,
+            "close_time": "2030-01-01T00:00:00Z",
+            "created_time": "2024-01-01T00:00:00Z",
+            "event_ticker": "EVENT",
+            "expiration_value": "",
+            "fractional_trading_enabled": false,
+            "last_price_dollars": "0.5000",
+            "latest_expiration_time": "2030-01-02T00:00:00Z",
+            "liquidity_dollars": "0.0000",
+            "market_type": "binary",
+            "no_ask_dollars": "0.5200",
+            "no_bid_dollars": "0.4600",
+            "no_sub_title": "",
+            "notional_value_dollars": "1.0000",
+            "open_interest_fp": open_interest,
+            "open_time": "2024-01-01T00:00:00Z",
+            "previous_price_dollars": "0.5000",
+            "previous_yes_ask_dollars": "0.5400",
+            "previous_yes_bid_dollars": "0.4800",
+            "price_level_structure": "linear_cent",
+            "price_ranges": [],
+            "result": "",
+            "rules_primary": "",
+            "rules_secondary": "",
+            "settlement_timer_seconds": 0,
+            "status": "active",
+            "ticker": ticker,
+            "updated_time": "2024-01-01T00:00:00Z",
+            "volume_24h_fp": volume_24h,
+            "volume_fp": volume,
+            "yes_ask_dollars": "0.5400",
+            "yes_ask_size_fp": "5.00",
+            "yes_bid_dollars": "0.4800",
+            "yes_bid_size_fp": "7.00",
+            "yes_sub_title": ""
+        })
+    }
+
+    fn event(markets: Vec<serde_json::Value>) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "available_on_brokers": false,
+            "collateral_return_type": "binary",
+            "event_ticker": "EVENT",
+            "markets": markets,
+            "mutually_exclusive": true,
+            "product_metadata": {},
+            "series_ticker": "SERIES",
+            "sub_title": "",
+            "title": "Event"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_event_aggregates() {
+        let empty = event(vec![]);
+        assert_eq!(empty.total_volume(), 0);
+        assert_eq!(empty.total_open_interest(), 0);
+        assert!(empty.most_liquid_market().is_none());
+
+        let ev = event(vec![
+            market("EVENT-A", "100.00", "5.00", "40.00"),
+            market("EVENT-B", "250.50", "20.00", "10.00"),
+            market("EVENT-C", "10.00", "20.00", "60.00"),
+        ]);
+        assert_eq!(ev.total_volume(), 361);
+        assert_eq!(ev.total_open_interest(), 110);
+        assert_eq!(ev.most_liquid_market().unwrap().ticker, "EVENT-C");
+    }
+
+    #[tokio::test]
+    async fn test_events_with_markets_fallback() {
+        let mut unnested = serde_json::to_value(event(vec![])).unwrap();
+        unnested["event_ticker"] = "UNNESTED".into();
+        unnested["markets"] = serde_json::Value::Null;
+        let nested = event(vec![market("EVENT-A", "1.00", "1.00", "1.00")]);
+        let mut fallback = market("UNNESTED-A", "1.00", "1.00", "1.00");
+        fallback["event_ticker"] = "UNNESTED".into();
+
+        let (client, mut requests) = crate::auth::test::recording_mock_client(vec![
+            (200, serde_json::json!({ "cursor": "next", "events": [nested, unnested] }).to_string()),
+            (200, serde_json::json!({ "cursor": "", "markets": [fallback] }).to_string()),
+        ])
+        .await;
+        let (cursor, events) = client.get_events_with_markets(None, None, None, None).await.unwrap();
+        requests.recv().await.unwrap();
+        // Only the unnested event's markets are requested.
+        assert!(requests.recv().await.unwrap().contains("event_ticker=UNNESTED"));
+        assert_eq!(cursor.as_deref(), Some("next"));
+        let tickers: Vec<Vec<&str>> = events
+            .iter()
+            .map(|e| e.markets.iter().map(|m| m.ticker.as_str()).collect())
+            .collect();
+        assert_eq!(tickers, vec![vec!["EVENT-A"], vec!["UNNESTED-A"]]);
+    }
+
+    #[tokio::test]
+    async fn test_events_request_builder() {
+        let request = GetEventsRequest::new().status("open").series_ticker("SERIES").limit(10);
+        assert_eq!(request.status.as_deref(), Some("open"));
+        assert_eq!(request.series_ticker.as_deref(), Some("SERIES"));
+        assert_eq!((request.limit, request.with_nested_markets), (Some(10), None));
+
+        let page = serde_json::json!({ "cursor": "", "events": [event(vec![])] });
+        let client = crate::auth::test::mock_client(vec![(200, page.to_string())]).await;
+        let (_, events) = client.events(request).await.unwrap();
+        assert_eq!(events[0].event_ticker, "EVENT");
+
+        for limit in [0, 201] {
+            assert!(matches!(
+                client.events(GetEventsRequest::new().limit(limit)).await,
+                Err(KalshiError::UserInputError(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_event_with_markets() {
+        let nested = event(vec![market("EVENT-A", "1.00", "1.00", "1.00")]);
+        let unnested = serde_json::to_value(event(vec![])).unwrap();
+        let top_level = vec![market("EVENT-B", "1.00", "1.00", "1.00")];
+
+        let client = crate::auth::test::mock_client(vec![
+            (200, serde_json::json!({ "event": nested, "markets": top_level }).to_string()),
+            (200, serde_json::json!({ "event": unnested, "markets": top_level }).to_string()),
+            (200, serde_json::json!({ "event": unnested, "markets": top_level }).to_string()),
+        ])
+        .await;
+        let tickers = |event: Event| event.markets.into_iter().map(|m| m.ticker).collect::<Vec<_>>();
+        assert_eq!(tickers(client.get_event_with_markets("EVENT").await.unwrap()), ["EVENT-A"]);
+        assert_eq!(tickers(client.get_event_with_markets("EVENT").await.unwrap()), ["EVENT-B"]);
+        assert!(client.get_event("EVENT").await.unwrap().markets.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_events_stream_follows_cursor() {
+        let page = |cursor: &str, tickers: &[&str]| {
+            let events: Vec<_> = tickers
+                .iter()
+                .map(|t| {
+                    let mut ev = serde_json::to_value(event(vec![])).unwrap();
+                    ev["event_ticker"] = (*t).into();
+                    ev
+                })
+                .collect();
+            (200, serde_json::json!({ "cursor": cursor, "events": events }).to_string())
+        };
+        let client = crate::auth::test::mock_client(vec![
+            page("c1", &["A", "B"]),
+            page("c2", &[]),
+            page("", &["C"]),
+        ])
+        .await;
+        let tickers: Vec<String> = client
+            .events_stream(None, None, None)
+            .map(|e| e.unwrap().event_ticker)
+            .collect()
+            .await;
+        assert_eq!(tickers, ["A", "B", "C"]);
+
+        let client = crate::auth::test::mock_client(vec![page("c1", &["A"]), page("c1", &["B"])]).await;
+        let items: Vec<_> = client.events_stream(None, None, None).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(matches!(&items[1], Err(KalshiError::InvalidCursor { cursor }) if cursor == "c1"));
+
+        // A cursor the API rejects ends the stream the same way.
+        let client = crate::auth::test::mock_client(vec![
+            page("c1", &["A"]),
+            (400, r#"{"error":{"code":"bad_request","message":"invalid cursor"}}"#.to_string()),
+        ])
+        .await;
+        let items: Vec<_> = client.events_stream(None, None, None).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(matches!(&items[1], Err(KalshiError::InvalidCursor { cursor }) if cursor == "c1"));
+    }
+}
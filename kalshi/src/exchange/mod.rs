@@ -1,6 +1,9 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
+use futures_util::stream::{self, Stream};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 impl Kalshi {
     /// Retrieves the current status of the Kalshi exchange and trading engine.
@@ -25,7 +28,62 @@ impl Kalshi {
     ///
     pub async fn get_exchange_status(&self) -> Result<ExchangeStatus, KalshiError> {
         let url = format!("{}/exchange/status", self.base_url);
-        Ok(self.client.get(&url).send().await?.json().await?)
+        self.send_json(self.client.get(&url)).await
+    }
+
+    /// Polls the exchange status and yields it only when it changes.
+    ///
+    /// The first successful poll is always yielded. After that, a status is only
+    /// emitted when `trading_active` or `exchange_active` differs from the last
+    /// one emitted, which gives a clean "exchange just halted/resumed" signal
+    /// without repeating unchanged statuses. The stream waits `poll` between
+    /// requests and never ends on its own.
+    ///
+    /// Failed polls are yielded as `Err` and polling continues, so a transient
+    /// network error does not end the stream. The next successful status is
+    /// still compared against the last one emitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `poll` - How long to wait between status requests.
+    ///
+    /// # Returns
+    ///
+    /// A stream of `Result<ExchangeStatus, KalshiError>` that borrows this client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let statuses = kalshi_instance.exchange_status_stream(std::time::Duration::from_secs(30));
+    /// futures_util::pin_mut!(statuses);
+    /// while let Some(status) = statuses.next().await {
+    ///     let status = status.unwrap();
+    ///     if !status.trading_active {
+    ///         println!("Trading halted");
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn exchange_status_stream(
+        &self,
+        poll: Duration,
+    ) -> impl Stream<Item = Result<ExchangeStatus, KalshiError>> + '_ {
+        stream::unfold((None, true), move |(last, first)| async move {
+            if !first {
+                tokio::time::sleep(poll).await;
+            }
+            loop {
+                match self.get_exchange_status().await {
+                    Ok(status) if last.as_ref() == Some(&status) => {}
+                    Ok(status) => return Some((Ok(status.clone()), (Some(status), false))),
+                    Err(e) => return Some((Err(e), (last, false))),
+                }
+                tokio::time::sleep(poll).await;
+            }
+        })
     }
 
     /// Retrieves the exchange schedule including trading hours and maintenance windows.
@@ -49,10 +107,39 @@ impl Kalshi {
     ///
     pub async fn get_exchange_schedule(&self) -> Result<ExchangeSchedule, KalshiError> {
         let url = format!("{}/exchange/schedule", self.base_url);
-        let res: ExchangeScheduleResponse = self.client.get(&url).send().await?.json().await?;
+        let res: ExchangeScheduleResponse = self.send_json(self.client.get(&url)).await?;
         Ok(res.schedule)
     }
 
+    /// Retrieves the exchange status and schedule together and works out whether
+    /// trading is possible right now.
+    ///
+    /// Both endpoints are requested concurrently. `is_open_now` is true when the
+    /// exchange and trading engine both report active and the schedule has the
+    /// exchange open right now (see [`ExchangeSchedule::is_open_at`]).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(ExchangeInfo)`: The status, the schedule and the combined open flag.
+    /// - `Err(KalshiError)`: An error if either request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let info = kalshi_instance.get_exchange_info().await.unwrap();
+    /// if !info.is_open_now {
+    ///     println!("Closed; maintenance windows: {:?}", info.schedule.maintenance_windows);
+    /// }
+    /// ```
+    ///
+    pub async fn get_exchange_info(&self) -> Result<ExchangeInfo, KalshiError> {
+        let (status, schedule) =
+            futures_util::future::try_join(self.get_exchange_status(), self.get_exchange_schedule()).await?;
+        let is_open_now = is_open_at(&status, &schedule, Utc::now());
+        Ok(ExchangeInfo { status, schedule, is_open_now })
+    }
+
     /// Retrieves exchange announcements including active and historical messages.
     ///
     /// This method fetches all exchange-wide announcements, both active and expired,
@@ -94,7 +181,7 @@ impl Kalshi {
         add_param!(params, "cursor", cursor);
 
         let final_url = reqwest::Url::parse_with_params(&url, &params)?;
-        let res: ExchangeAnnouncementsResponse = self.client.get(final_url).send().await?.json().await?;
+        let res: ExchangeAnnouncementsResponse = self.send_json(self.client.get(final_url)).await?;
         Ok((res.cursor, res.announcements))
     }
 
@@ -118,7 +205,7 @@ impl Kalshi {
     ///
     pub async fn get_user_data_timestamp(&self) -> Result<UserDataTimestamp, KalshiError> {
         let url = format!("{}/exchange/user_data_timestamp", self.base_url);
-        Ok(self.client.get(&url).send().await?.json().await?)
+        self.send_json(self.client.get(&url)).await
     }
 
     /// Checks if the exchange is active with exponential backoff retry logic.
@@ -255,7 +342,7 @@ impl Kalshi {
 
         let url = format!("{}{}", self.base_url, path);
         let final_url = reqwest::Url::parse_with_params(&url, &params)?;
-        let res: SeriesFeeChangesResponse = self.client.get(final_url).send().await?.json().await?;
+        let res: SeriesFeeChangesResponse = self.send_json(self.client.get(final_url)).await?;
         Ok(res.fee_changes)
     }
 }
@@ -266,7 +353,7 @@ impl Kalshi {
 ///
 /// This struct provides simple boolean flags indicating whether the exchange
 /// platform and trading engine are currently active and operational.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ExchangeStatus {
     /// Indicates whether the trading engine is currently active and accepting orders.
     pub trading_active: bool,
@@ -274,11 +361,22 @@ pub struct ExchangeStatus {
     pub exchange_active: bool,
 }
 
+/// The exchange status and schedule, returned by [`Kalshi::get_exchange_info`].
+#[derive(Debug, Clone)]
+pub struct ExchangeInfo {
+    /// Whether the exchange and trading engine are active.
+    pub status: ExchangeStatus,
+    /// Standard trading hours and scheduled maintenance windows.
+    pub schedule: ExchangeSchedule,
+    /// Whether orders can be placed at the time the info was fetched.
+    pub is_open_now: bool,
+}
+
 /// Represents the trading schedule and maintenance windows for the Kalshi exchange.
 ///
 /// This struct contains the standard trading hours for each day of the week
 /// and any scheduled maintenance windows when the exchange may be unavailable.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ExchangeSchedule {
     /// The standard trading hours for each day of the week.
     pub standard_hours: Vec<StandardHours>,
@@ -291,7 +389,7 @@ pub struct ExchangeSchedule {
 /// Announcements provide important information about exchange updates,
 /// maintenance schedules, new features, or other relevant information
 /// that users need to be aware of.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ExchangeAnnouncement {
     /// The announcement message content.
     pub message: String,
@@ -305,7 +403,7 @@ pub struct ExchangeAnnouncement {
 ///
 /// This struct provides information about when user portfolio data
 /// was last updated, allowing users to determine data freshness.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UserDataTimestamp {
     /// The timestamp of the last portfolio data validation/refresh.
     pub last_validated_ts: String,
@@ -315,7 +413,7 @@ pub struct UserDataTimestamp {
 ///
 /// Maintenance windows indicate periods when the exchange may be unavailable
 /// for trading or other operations due to scheduled maintenance.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MaintenanceWindow {
     /// The start datetime of the maintenance window.
     pub start_datetime: String,
@@ -327,7 +425,7 @@ pub struct MaintenanceWindow {
 ///
 /// This struct defines the opening and closing times for trading
 /// on a particular day of the week.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DaySchedule {
     /// The time when trading opens for this day.
     pub open_time: String,
@@ -339,7 +437,7 @@ pub struct DaySchedule {
 ///
 /// This struct defines the trading schedule for each day of the week,
 /// including multiple time slots per day if applicable.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StandardHours {
     /// The start time for the trading period.
     pub start_time: String,
@@ -361,6 +459,172 @@ pub struct StandardHours {
     #[serde(default)] pub sunday: Vec<DaySchedule>,
 }
 
+impl ExchangeSchedule {
+    /// Whether the schedule has the exchange open for trading at `when`.
+    ///
+    /// The [`StandardHours`] period whose `start_time`..`end_time` contains `when` is
+    /// looked up, and `when` must fall in one of its sessions for that weekday. Session
+    /// times are wall-clock times in US Eastern time, so `when` is converted to Eastern
+    /// first, using the US daylight saving rules in force since 2007 (UTC-4 from the
+    /// second Sunday in March to the first Sunday in November, UTC-5 otherwise).
+    ///
+    /// Session times are read as `HH:MM` or `HH:MM:SS`, with `24:00` meaning the end of
+    /// the day. A session that closes at or before it opens runs past midnight into the
+    /// next day. Sessions whose times don't parse are skipped, as are period or window
+    /// bounds that aren't RFC 3339.
+    ///
+    /// Unknown hours count as closed: if no period covers `when`, including when
+    /// `standard_hours` is empty, the exchange hasn't published hours for that time and
+    /// `false` is returned. `when` is never open while inside a maintenance window.
+    ///
+    /// This only reads the schedule; [`Kalshi::get_exchange_info`] also checks that
+    /// trading hasn't been halted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let schedule = kalshi_instance.get_exchange_schedule().await.unwrap();
+    /// if schedule.is_open_at(chrono::Utc::now()) {
+    ///     println!("Within trading hours");
+    /// }
+    /// ```
+    ///
+    pub fn is_open_at(&self, when: DateTime<Utc>) -> bool {
+        if self.maintenance_windows.iter().any(|window| window.contains(when)) {
+            return false;
+        }
+        let Some(hours) = self.standard_hours.iter().find(|hours| hours.covers(when)) else {
+            return false;
+        };
+        let local = to_eastern(when);
+        let time = local.num_seconds_from_midnight();
+        let day = local.weekday();
+        let today = hours.sessions(day).iter().filter_map(DaySchedule::bounds).any(|(open, close)| {
+            if open < close {
+                open <= time && time < close
+            } else {
+                open <= time
+            }
+        });
+        let from_yesterday = hours
+            .sessions(day.pred())
+            .iter()
+            .filter_map(DaySchedule::bounds)
+            .any(|(open, close)| close <= open && time < close);
+        today || from_yesterday
+    }
+
+    /// The first maintenance window starting strictly after `after`.
+    ///
+    /// A window already underway at `after` is not returned; use
+    /// [`current_maintenance_window`](ExchangeSchedule::current_maintenance_window) for
+    /// that. Windows whose datetimes don't parse as RFC 3339 are skipped. Returns `None`
+    /// if no window is left.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let schedule = kalshi_instance.get_exchange_schedule().await.unwrap();
+    /// if let Some(window) = schedule.next_maintenance_window(chrono::Utc::now()) {
+    ///     println!("Pausing for maintenance from {} to {}", window.start_datetime, window.end_datetime);
+    /// }
+    /// ```
+    ///
+    pub fn next_maintenance_window(&self, after: DateTime<Utc>) -> Option<&MaintenanceWindow> {
+        self.maintenance_windows
+            .iter()
+            .filter_map(|window| {
+                let start = DateTime::parse_from_rfc3339(&window.start_datetime).ok()?;
+                DateTime::parse_from_rfc3339(&window.end_datetime).ok()?;
+                (start > after).then_some((start, window))
+            })
+            .min_by_key(|(start, _)| *start)
+            .map(|(_, window)| window)
+    }
+
+    /// The maintenance window in progress at `at`: started at or before it and not yet
+    /// ended. Windows whose datetimes don't parse as RFC 3339 are skipped.
+    pub fn current_maintenance_window(&self, at: DateTime<Utc>) -> Option<&MaintenanceWindow> {
+        self.maintenance_windows.iter().find(|window| {
+            let start = DateTime::parse_from_rfc3339(&window.start_datetime);
+            let end = DateTime::parse_from_rfc3339(&window.end_datetime);
+            matches!((start, end), (Ok(start), Ok(end)) if start <= at && at < end)
+        })
+    }
+}
+
+impl StandardHours {
+    /// Whether `when` falls in this period; a bound that doesn't parse is left open.
+    fn covers(&self, when: DateTime<Utc>) -> bool {
+        let after_start = DateTime::parse_from_rfc3339(&self.start_time).map_or(true, |start| start <= when);
+        let before_end = DateTime::parse_from_rfc3339(&self.end_time).map_or(true, |end| when < end);
+        after_start && before_end
+    }
+
+    /// The sessions for `day`.
+    fn sessions(&self, day: Weekday) -> &[DaySchedule] {
+        match day {
+            Weekday::Mon => &self.monday,
+            Weekday::Tue => &self.tuesday,
+            Weekday::Wed => &self.wednesday,
+            Weekday::Thu => &self.thursday,
+            Weekday::Fri => &self.friday,
+            Weekday::Sat => &self.saturday,
+            Weekday::Sun => &self.sunday,
+        }
+    }
+}
+
+impl DaySchedule {
+    /// Open and close as seconds after midnight, or `None` if either doesn't parse.
+    fn bounds(&self) -> Option<(u32, u32)> {
+        Some((clock_seconds(&self.open_time)?, clock_seconds(&self.close_time)?))
+    }
+}
+
+impl MaintenanceWindow {
+    /// Whether `when` is inside the window; never true if a bound isn't RFC 3339.
+    fn contains(&self, when: DateTime<Utc>) -> bool {
+        let start = DateTime::parse_from_rfc3339(&self.start_datetime);
+        let end = DateTime::parse_from_rfc3339(&self.end_datetime);
+        matches!((start, end), (Ok(start), Ok(end)) if start <= when && when < end)
+    }
+}
+
+/// Seconds after midnight of an `HH:MM` or `HH:MM:SS` time; `24:00` is the end of the day.
+fn clock_seconds(time: &str) -> Option<u32> {
+    let mut parts = time.trim().split(':');
+    let hours: u32 = parts.next()?.trim().parse().ok()?;
+    let minutes: u32 = parts.next()?.trim().parse().ok()?;
+    let seconds: u32 = parts.next().map_or(Some(0), |s| s.trim().parse().ok())?;
+    if parts.next().is_some() || minutes > 59 || seconds > 59 {
+        return None;
+    }
+    let total = hours * 3600 + minutes * 60 + seconds;
+    (total <= 24 * 3600).then_some(total)
+}
+
+/// `when` as US Eastern wall-clock time.
+///
+/// Daylight saving runs from 2:00 local on the second Sunday in March to 2:00 local on
+/// the first Sunday in November.
+fn to_eastern(when: DateTime<Utc>) -> NaiveDateTime {
+    let utc = when.naive_utc();
+    let sunday = |month, n| NaiveDate::from_weekday_of_month_opt(utc.year(), month, Weekday::Sun, n);
+    // 2:00 EST and 2:00 EDT in UTC.
+    let dst_start = sunday(3, 2).and_then(|d| d.and_hms_opt(7, 0, 0));
+    let dst_end = sunday(11, 1).and_then(|d| d.and_hms_opt(6, 0, 0));
+    let daylight = matches!((dst_start, dst_end), (Some(start), Some(end)) if start <= utc && utc < end);
+    utc - chrono::Duration::hours(if daylight { 4 } else { 5 })
+}
+
+/// Whether `status` reports trading as active and `schedule` has the exchange open at `when`.
+fn is_open_at(status: &ExchangeStatus, schedule: &ExchangeSchedule, when: DateTime<Utc>) -> bool {
+    status.exchange_active && status.trading_active && schedule.is_open_at(when)
+}
+
 // -------- response wrappers --------
 
 #[derive(Debug, Deserialize)]
@@ -380,7 +644,7 @@ struct SeriesFeeChangesResponse {
 }
 
 /// Represents a fee change for a series.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SeriesFeeChange {
     /// The series ticker.
     pub series_ticker: String,
@@ -391,3 +655,124 @@ pub struct SeriesFeeChange {
     /// The effective date of the fee change.
     pub effective_date: String,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_open_at() {
+        let active = ExchangeStatus { trading_active: true, exchange_active: true };
+        let all_day = || vec![DaySchedule { open_time: "00:00".to_string(), close_time: "24:00".to_string() }];
+        let schedule = ExchangeSchedule {
+            standard_hours: vec![StandardHours {
+                start_time: "2024-01-01T00:00:00Z".to_string(),
+                end_time: "2025-01-01T00:00:00Z".to_string(),
+                monday: all_day(),
+                tuesday: all_day(),
+                wednesday: all_day(),
+                thursday: all_day(),
+                friday: all_day(),
+                saturday: all_day(),
+                sunday: all_day(),
+            }],
+            maintenance_windows: vec![MaintenanceWindow {
+                start_datetime: "2024-06-01T04:00:00Z".to_string(),
+                end_datetime: "2024-06-01T06:00:00Z".to_string(),
+            }],
+        };
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+        assert!(is_open_at(&active, &schedule, at("2024-06-01T03:59:59Z")));
+        assert!(!is_open_at(&active, &schedule, at("2024-06-01T05:00:00Z")));
+        assert!(is_open_at(&active, &schedule, at("2024-06-01T06:00:00Z")));
+
+        let halted = ExchangeStatus { trading_active: false, exchange_active: true };
+        assert!(!is_open_at(&halted, &schedule, at("2024-06-01T03:00:00Z")));
+    }
+
+    #[test]
+    fn test_schedule_is_open_at() {
+        let session = |open: &str, close: &str| DaySchedule { open_time: open.to_string(), close_time: close.to_string() };
+        let schedule = ExchangeSchedule {
+            standard_hours: vec![StandardHours {
+                start_time: "2024-01-01T00:00:00Z".to_string(),
+                end_time: "2025-01-01T00:00:00Z".to_string(),
+                monday: vec![session("09:30", "16:00"), session("bad", "17:00")],
+                tuesday: vec![],
+                wednesday: vec![],
+                thursday: vec![],
+                friday: vec![session("20:00", "02:00")],
+                saturday: vec![],
+                sunday: vec![session("00:00", "24:00")],
+            }],
+            maintenance_windows: vec![MaintenanceWindow {
+                start_datetime: "2024-06-03T17:00:00Z".to_string(),
+                end_datetime: "2024-06-03T18:00:00Z".to_string(),
+            }],
+        };
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+
+        // Monday 2024-06-03 in EDT (UTC-4).
+        assert!(!schedule.is_open_at(at("2024-06-03T13:29:59Z")));
+        assert!(schedule.is_open_at(at("2024-06-03T13:30:00Z")));
+        assert!(!schedule.is_open_at(at("2024-06-03T17:30:00Z")));
+        assert!(!schedule.is_open_at(at("2024-06-03T20:00:00Z")));
+        // Monday 2024-01-08 in EST (UTC-5).
+        assert!(!schedule.is_open_at(at("2024-01-08T14:00:00Z")));
+        assert!(schedule.is_open_at(at("2024-01-08T14:30:00Z")));
+        // Friday's evening session runs into Saturday.
+        assert!(schedule.is_open_at(at("2024-06-08T01:00:00Z")));
+        assert!(schedule.is_open_at(at("2024-06-08T05:59:59Z")));
+        assert!(!schedule.is_open_at(at("2024-06-08T06:00:00Z")));
+        assert!(schedule.is_open_at(at("2024-06-09T12:00:00Z")));
+        // No hours published for 2025, or at all: unknown hours are closed.
+        assert!(!schedule.is_open_at(at("2025-06-03T20:00:00Z")));
+        assert!(!schedule.is_open_at(at("2023-06-05T14:00:00Z")));
+        let unpublished = ExchangeSchedule { standard_hours: vec![], maintenance_windows: vec![] };
+        assert!(!unpublished.is_open_at(at("2024-06-03T14:00:00Z")));
+    }
+
+    #[test]
+    fn test_next_maintenance_window() {
+        let window = |start: &str, end: &str| MaintenanceWindow {
+            start_datetime: start.to_string(),
+            end_datetime: end.to_string(),
+        };
+        let schedule = ExchangeSchedule {
+            standard_hours: vec![],
+            maintenance_windows: vec![
+                window("2024-06-08T04:00:00Z", "2024-06-08T06:00:00Z"),
+                window("2024-06-01T04:00:00Z", "2024-06-01T06:00:00Z"),
+                window("not a date", "2024-06-02T06:00:00Z"),
+            ],
+        };
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        let next = |s: &str| schedule.next_maintenance_window(at(s)).map(|w| w.start_datetime.as_str());
+
+        let current = |s: &str| schedule.current_maintenance_window(at(s)).map(|w| w.start_datetime.as_str());
+
+        assert_eq!(next("2024-05-31T00:00:00Z"), Some("2024-06-01T04:00:00Z"));
+        // A window in progress is current, not next.
+        assert_eq!(next("2024-06-01T05:00:00Z"), Some("2024-06-08T04:00:00Z"));
+        assert_eq!(current("2024-06-01T05:00:00Z"), Some("2024-06-01T04:00:00Z"));
+        assert_eq!(next("2024-06-01T04:00:00Z"), Some("2024-06-08T04:00:00Z"));
+        assert_eq!(current("2024-06-01T06:00:00Z"), None);
+        assert_eq!(next("2024-06-08T06:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_to_eastern_follows_daylight_saving() {
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc);
+        let local = |s: &str| to_eastern(at(s)).format("%Y-%m-%d %H:%M").to_string();
+
+        assert_eq!(local("2024-03-10T06:59:00Z"), "2024-03-10 01:59");
+        assert_eq!(local("2024-03-10T07:00:00Z"), "2024-03-10 03:00");
+        assert_eq!(local("2024-11-03T05:59:00Z"), "2024-11-03 01:59");
+        assert_eq!(local("2024-11-03T06:00:00Z"), "2024-11-03 01:00");
+        assert_eq!(clock_seconds(" 9:30 "), Some(34_200));
+        assert_eq!(clock_seconds("24:00"), Some(86_400));
+        assert_eq!(clock_seconds("24:01"), None);
+        assert_eq!(clock_seconds("9"), None);
+    }
+}
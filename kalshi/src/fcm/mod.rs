@@ -76,7 +76,7 @@ struct FcmPositionsResponse {
 use serde::Serialize;
 
 /// Represents an FCM position (simplified version for FCM interface).
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FcmPosition {
     /// The market ticker for this position.
     pub ticker: String,
@@ -0,0 +1,72 @@
+//! format.rs – consistent display of probabilities
+
+/// How [`format_probability_with`] renders a probability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbabilityFormat {
+    /// Digits after the decimal point.
+    pub decimals: usize,
+    /// Render as a percentage (`37%`) instead of a fraction (`0.37`).
+    pub as_percent: bool,
+}
+
+impl Default for ProbabilityFormat {
+    /// Two decimals as a fraction, e.g. `0.37`.
+    fn default() -> Self {
+        ProbabilityFormat { decimals: 2, as_percent: false }
+    }
+}
+
+/// Formats `p` with [`ProbabilityFormat::default`].
+pub fn format_probability(p: f64) -> String {
+    format_probability_with(p, &ProbabilityFormat::default())
+}
+
+/// Formats a probability for display.
+///
+/// Values outside `[0, 1]` are clamped first, so rounding noise such as `1.0000001` or
+/// `-0.0` never shows up as `100.00001%` or `-0%`. `NaN` is rendered as `"n/a"`.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::{format_probability_with, ProbabilityFormat};
+///
+/// let pct = ProbabilityFormat { decimals: 1, as_percent: true };
+/// assert_eq!(format_probability_with(0.3721, &pct), "37.2%");
+/// ```
+///
+pub fn format_probability_with(p: f64, fmt: &ProbabilityFormat) -> String {
+    if p.is_nan() {
+        return "n/a".to_string();
+    }
+    // Adding 0.0 turns a clamped -0.0 into 0.0.
+    let p = p.clamp(0.0, 1.0) + 0.0;
+    if fmt.as_percent {
+        format!("{:.*}%", fmt.decimals, p * 100.0)
+    } else {
+        format!("{:.*}", fmt.decimals, p)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_probability_with() {
+        let fraction = ProbabilityFormat::default();
+        let whole_percent = ProbabilityFormat { decimals: 0, as_percent: true };
+        let percent = ProbabilityFormat { decimals: 1, as_percent: true };
+
+        assert_eq!(format_probability(0.3721), "0.37");
+        assert_eq!(format_probability_with(0.3721, &fraction), "0.37");
+        assert_eq!(format_probability_with(0.3721, &whole_percent), "37%");
+        assert_eq!(format_probability_with(0.3721, &percent), "37.2%");
+
+        assert_eq!(format_probability_with(1.0000001, &percent), "100.0%");
+        assert_eq!(format_probability_with(-0.2, &fraction), "0.00");
+        assert_eq!(format_probability_with(-0.0, &whole_percent), "0%");
+        assert_eq!(format_probability_with(f64::INFINITY, &whole_percent), "100%");
+        assert_eq!(format_probability_with(f64::NAN, &percent), "n/a");
+    }
+}
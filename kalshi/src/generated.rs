@@ -7,7 +7,7 @@
 //   - `pub mod types { … }` — all request / response structs
 //   - `pub struct Client { … }` + `impl Client { … }` — the generated HTTP client
 //     (available for direct use; the `Kalshi` wrapper uses it for type names only)
-#[allow(
+#![allow(
     dead_code,
     unused_imports,
     unused_variables,
@@ -40,7 +40,7 @@ struct IncentiveProgramsResponse {
 // -------- Public models --------
 
 /// Represents a volume incentive program.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct VolumeIncentive {
     /// The name of the incentive program.
     pub program_name: String,
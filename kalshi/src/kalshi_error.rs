@@ -1,5 +1,6 @@
 use core::fmt;
 use std::error::Error;
+use std::time::Duration;
 // CUSTOM ERROR STRUCTS + ENUMS
 // -----------------------------------------------
 
@@ -20,16 +21,260 @@ pub enum KalshiError {
     InternalError(String),
     /// Authentication errors, such as missing credentials or invalid keys.
     Auth(String),
+    /// A market ticker was paired with a series it does not belong to.
+    TickerSeriesMismatch {
+        /// The market ticker that was requested.
+        ticker: String,
+        /// The series ticker it was (incorrectly) paired with.
+        series: String,
+    },
+    /// A write was attempted through a client created with [`Kalshi::read_only`](crate::Kalshi::read_only).
+    ReadOnly {
+        /// The HTTP method of the refused request.
+        method: String,
+        /// The API path of the refused request.
+        path: String,
+    },
+    /// A response body exceeded the limit set with
+    /// [`Kalshi::set_max_response_bytes`](crate::Kalshi::set_max_response_bytes).
+    ResponseTooLarge {
+        /// The limit in bytes that was exceeded.
+        limit: usize,
+    },
+    /// The API rejected a pagination cursor, typically because it expired or was corrupted.
+    /// Start a fresh scan without a cursor to recover.
+    InvalidCursor {
+        /// The cursor that was rejected.
+        cursor: String,
+    },
+    /// The server answered with a body that isn't JSON, such as an HTML maintenance page
+    /// served by the CDN during an outage.
+    UnexpectedContentType {
+        /// The content type the client expected.
+        expected: String,
+        /// The `Content-Type` the server sent.
+        got: String,
+        /// The start of the body, whitespace collapsed.
+        snippet: String,
+    },
+    /// The exchange rejected the client's credentials (HTTP 401).
+    AuthFailed {
+        /// The API key ID that was rejected.
+        key_id: String,
+    },
+    /// A credential needed by [`Kalshi::from_env`](crate::Kalshi::from_env) was not set.
+    MissingCredentials {
+        /// The environment variable that was absent (or empty).
+        variable: String,
+    },
+    /// The WebSocket connection failed or was dropped.
+    WebSocket(String),
+    /// The exchange rejected a WebSocket command, e.g. a subscription to an unknown
+    /// ticker. Sending the same command again fails the same way.
+    WebSocketRejected {
+        /// The error code the server sent, if any.
+        code: Option<i64>,
+        /// The server's message.
+        message: String,
+    },
+    /// The exchange answered `429 Too Many Requests`.
+    RateLimited {
+        /// The wait the server asked for in its `Retry-After` header, if it sent one.
+        retry_after: Option<Duration>,
+    },
+    /// The exchange answered with a non-2xx status and its JSON error body, e.g. code
+    /// `insufficient_balance` or `market_closed`.
+    Api {
+        /// The HTTP status code.
+        status: u16,
+        /// Kalshi's machine-readable error code; empty if the body had none.
+        code: String,
+        /// Kalshi's human-readable message, with any details appended.
+        message: String,
+    },
     // TODO: add error type specifically for joining threads together.
 }
 
+impl KalshiError {
+    /// Returns `true` if repeating the same request could reasonably succeed.
+    ///
+    /// Timeouts, [`KalshiError::RateLimited`], `5xx` responses (including [`KalshiError::Api`]
+    /// errors with those statuses) and dropped WebSocket connections are retryable. Other
+    /// client errors, rejected WebSocket commands, deserialization failures, bad user input,
+    /// internal and authentication errors are not, since resending the identical request
+    /// will fail the same way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// match kalshi_instance.get_market("SOME-MARKET-2024").await {
+    ///     Err(e) if e.is_retryable() => { /* back off and try again */ }
+    ///     other => { /* handle result */ }
+    /// }
+    /// ```
+    ///
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            KalshiError::RequestError(RequestError::ServerError(_)) | KalshiError::WebSocket(_) => true,
+            KalshiError::RequestError(RequestError::ClientError(e)) => {
+                e.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+            }
+            KalshiError::RateLimited { .. } => true,
+            KalshiError::Api { status, .. } => *status == 429 || *status >= 500,
+            KalshiError::RequestError(RequestError::SerializationError(_))
+            | KalshiError::UserInputError(_)
+            | KalshiError::InternalError(_)
+            | KalshiError::Auth(_)
+            | KalshiError::TickerSeriesMismatch { .. }
+            | KalshiError::ReadOnly { .. }
+            | KalshiError::ResponseTooLarge { .. }
+            | KalshiError::InvalidCursor { .. }
+            | KalshiError::UnexpectedContentType { .. }
+            | KalshiError::AuthFailed { .. }
+            | KalshiError::MissingCredentials { .. }
+            | KalshiError::WebSocketRejected { .. } => false,
+        }
+    }
+
+    /// Short, stable name of the variant, e.g. `"client_error"` or `"read_only"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            KalshiError::RequestError(RequestError::SerializationError(_)) => "serialization_error",
+            KalshiError::RequestError(RequestError::ClientError(_)) => "client_error",
+            KalshiError::RequestError(RequestError::ServerError(_)) => "server_error",
+            KalshiError::UserInputError(_) => "user_input_error",
+            KalshiError::InternalError(_) => "internal_error",
+            KalshiError::Auth(_) => "auth",
+            KalshiError::TickerSeriesMismatch { .. } => "ticker_series_mismatch",
+            KalshiError::ReadOnly { .. } => "read_only",
+            KalshiError::ResponseTooLarge { .. } => "response_too_large",
+            KalshiError::InvalidCursor { .. } => "invalid_cursor",
+            KalshiError::UnexpectedContentType { .. } => "unexpected_content_type",
+            KalshiError::AuthFailed { .. } => "auth_failed",
+            KalshiError::MissingCredentials { .. } => "missing_credentials",
+            KalshiError::WebSocket(_) => "websocket",
+            KalshiError::WebSocketRejected { .. } => "websocket_rejected",
+            KalshiError::RateLimited { .. } => "rate_limited",
+            KalshiError::Api { .. } => "api",
+        }
+    }
+
+    /// The HTTP status code of the response that caused the error, if there was one.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            KalshiError::RequestError(
+                RequestError::SerializationError(e)
+                | RequestError::ClientError(e)
+                | RequestError::ServerError(e),
+            ) => e.status().map(|s| s.as_u16()),
+            KalshiError::RateLimited { .. } => Some(429),
+            KalshiError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Renders the error as a flat JSON object for structured logging.
+    ///
+    /// The object always has `variant` (see [`kind`](KalshiError::kind)), `message` (the
+    /// `Display` output) and `retryable`. `status` is the HTTP status code and `path`
+    /// the request URL or API path, both included only when known. [`KalshiError::Api`]
+    /// and [`KalshiError::WebSocketRejected`] errors also carry `code`. Inner errors that
+    /// aren't serializable are only included through `message`.
+    ///
+    /// `KalshiError` also implements `Serialize`, producing the same object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if let Err(e) = kalshi_instance.get_market("SOME-MARKET-2024").await {
+    ///     println!("{}", e.to_log_value());
+    /// }
+    /// ```
+    ///
+    pub fn to_log_value(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "variant": self.kind(),
+            "message": self.to_string(),
+            "retryable": self.is_retryable(),
+        });
+        let path = match self {
+            KalshiError::RequestError(
+                RequestError::SerializationError(e)
+                | RequestError::ClientError(e)
+                | RequestError::ServerError(e),
+            ) => e.url().map(|u| u.to_string()),
+            KalshiError::ReadOnly { path, .. } => Some(path.clone()),
+            _ => None,
+        };
+        match self {
+            KalshiError::Api { code, .. } => value["code"] = code.clone().into(),
+            KalshiError::WebSocketRejected { code: Some(code), .. } => value["code"] = (*code).into(),
+            _ => {}
+        }
+        if let Some(status) = self.status() {
+            value["status"] = status.into();
+        }
+        if let Some(path) = path {
+            value["path"] = path.into();
+        }
+        value
+    }
+}
+
+impl serde::Serialize for KalshiError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_log_value().serialize(serializer)
+    }
+}
+
 impl fmt::Display for KalshiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             KalshiError::RequestError(e) => write!(f, "HTTP Error: {}", e),
             KalshiError::UserInputError(e) => write!(f, "User Input Error: {}", e),
             KalshiError::InternalError(e) => write!(f, "INTERNAL ERROR, PLEASE EMAIL DEVELOPER OR MAKE A NEW ISSUE ON THE CRATE'S REPOSITORY: https://github.com/dpeachpeach/kalshi-rust. Specific Error: {}", e),
-            KalshiError::Auth(e) => write!(f, "Authentication Error: {}", e)
+            KalshiError::Auth(e) => write!(f, "Authentication Error: {}", e),
+            KalshiError::TickerSeriesMismatch { ticker, series } => write!(
+                f,
+                "Ticker/Series Mismatch: market {} does not belong to series {}",
+                ticker, series
+            ),
+            KalshiError::ReadOnly { method, path } => {
+                write!(f, "Read-Only Client: refused to send {} {}", method, path)
+            }
+            KalshiError::ResponseTooLarge { limit } => {
+                write!(f, "Response Too Large: body exceeds the {} byte limit", limit)
+            }
+            KalshiError::InvalidCursor { cursor } => {
+                write!(f, "Invalid Cursor: the API rejected pagination cursor {:?}", cursor)
+            }
+            KalshiError::UnexpectedContentType { expected, got, snippet } => write!(
+                f,
+                "Unexpected Content Type: expected {} but got {} (the exchange may be down for maintenance): {}",
+                expected, got, snippet
+            ),
+            KalshiError::AuthFailed { key_id } => write!(
+                f,
+                "Authentication Failed: the exchange rejected API key {}",
+                key_id
+            ),
+            KalshiError::MissingCredentials { variable } => {
+                write!(f, "Missing Credentials: environment variable {} is not set", variable)
+            }
+            KalshiError::WebSocket(e) => write!(f, "WebSocket Error: {}", e),
+            KalshiError::WebSocketRejected { code: Some(code), message } => {
+                write!(f, "WebSocket Rejected: server rejected request (code {}): {}", code, message)
+            }
+            KalshiError::WebSocketRejected { code: None, message } => {
+                write!(f, "WebSocket Rejected: server rejected request: {}", message)
+            }
+            KalshiError::RateLimited { retry_after: Some(wait) } => {
+                write!(f, "Rate Limited: retry after {:?}", wait)
+            }
+            KalshiError::RateLimited { retry_after: None } => write!(f, "Rate Limited"),
+            KalshiError::Api { status, code, message } => {
+                write!(f, "API Error: status {} ({}): {}", status, code, message)
+            }
         }
     }
 }
@@ -41,6 +286,17 @@ impl Error for KalshiError {
             KalshiError::UserInputError(_) => None,
             KalshiError::InternalError(_) => None,
             KalshiError::Auth(_) => None,
+            KalshiError::TickerSeriesMismatch { .. } => None,
+            KalshiError::ReadOnly { .. } => None,
+            KalshiError::ResponseTooLarge { .. } => None,
+            KalshiError::InvalidCursor { .. } => None,
+            KalshiError::UnexpectedContentType { .. } => None,
+            KalshiError::AuthFailed { .. } => None,
+            KalshiError::MissingCredentials { .. } => None,
+            KalshiError::WebSocket(_) => None,
+            KalshiError::WebSocketRejected { .. } => None,
+            KalshiError::RateLimited { .. } => None,
+            KalshiError::Api { .. } => None,
         }
     }
 }
@@ -65,6 +321,9 @@ impl From<reqwest::Error> for KalshiError {
             }
         } else if err.is_body() || err.is_timeout() {
             KalshiError::RequestError(RequestError::ServerError(err))
+        } else if err.is_redirect() {
+            // A redirect the client refused to follow, e.g. one leaving https.
+            KalshiError::RequestError(RequestError::ClientError(err))
         } else {
             KalshiError::InternalError(
                 "Theoretically Impossible Error. Internal code 2".to_string(),
@@ -155,3 +414,63 @@ impl From<serde_urlencoded::ser::Error> for KalshiError {
         KalshiError::UserInputError(format!("URL Encoding Error: {}", err))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::KalshiError;
+
+    #[test]
+    fn test_to_log_value() {
+        let err = KalshiError::ReadOnly {
+            method: "POST".to_string(),
+            path: "/portfolio/orders".to_string(),
+        };
+        let value = err.to_log_value();
+        assert_eq!(value["variant"], "read_only");
+        assert_eq!(value["path"], "/portfolio/orders");
+        assert_eq!(value["retryable"], false);
+        assert_eq!(value["message"], err.to_string());
+        assert!(value.get("status").is_none());
+
+        assert_eq!(serde_json::to_value(&err).unwrap(), value);
+        assert_eq!(
+            KalshiError::UserInputError("bad".to_string()).to_log_value()["variant"],
+            "user_input_error"
+        );
+
+        let api = KalshiError::Api {
+            status: 400,
+            code: "insufficient_balance".to_string(),
+            message: "Insufficient balance".to_string(),
+        };
+        let value = api.to_log_value();
+        assert_eq!((value["variant"].as_str(), value["status"].as_u64()), (Some("api"), Some(400)));
+        assert_eq!((value["code"].as_str(), value["retryable"].as_bool()), (Some("insufficient_balance"), Some(false)));
+        assert!(KalshiError::Api { status: 503, code: String::new(), message: String::new() }.is_retryable());
+
+        let rejected = KalshiError::WebSocketRejected { code: Some(6), message: "Market not found".to_string() };
+        let value = rejected.to_log_value();
+        assert_eq!((value["variant"].as_str(), value["code"].as_i64()), (Some("websocket_rejected"), Some(6)));
+        assert_eq!(value["retryable"], false);
+        assert!(KalshiError::WebSocket("connection reset".to_string()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_from_env_missing_credentials() {
+        let from_vars = |vars: &[(&str, &str)]| {
+            let vars: std::collections::HashMap<String, String> =
+                vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+            crate::Kalshi::from_vars(move |name| vars.get(name).cloned())
+        };
+        let missing = |result: Result<crate::Kalshi, KalshiError>| match result {
+            Err(KalshiError::MissingCredentials { variable }) => variable,
+            other => panic!("expected MissingCredentials, got {:?}", other.map(|_| ())),
+        };
+        assert_eq!(missing(from_vars(&[]).await), "KALSHI_API_KEY_ID");
+        assert_eq!(missing(from_vars(&[("KALSHI_API_KEY_ID", " ")]).await), "KALSHI_API_KEY_ID");
+        assert_eq!(missing(from_vars(&[("KALSHI_API_KEY_ID", "key-id")]).await), "KALSHI_PRIVATE_KEY_PATH");
+
+        let staging = from_vars(&[("KALSHI_API_KEY_ID", "key-id"), ("KALSHI_ENV", "staging")]).await;
+        assert!(matches!(staging, Err(KalshiError::UserInputError(_))));
+    }
+}
@@ -36,7 +36,7 @@
 //! Initialize the Kalshi Struct with key-based authentication:
 //! - **IMPORTANT**:  The authentication is handled automatically when creating a new instance.
 //! - Store your key ID and private key file securely, an implementation of extracting these from local environmental variables
-//! is available [here](https://github.com/dpeachpeach/kalshi-rust/blob/main/sample_bot/src/main.rs#L12)
+//!   is available [here](https://github.com/dpeachpeach/kalshi-rust/blob/main/sample_bot/src/main.rs#L12)
 //! ```
 //! use kalshi::Kalshi;
 //! use kalshi::TradingEnvironment;
@@ -112,13 +112,18 @@
 #[macro_use]
 mod utils;
 pub mod generated;
+mod analytics;
 mod auth;
 mod api_keys;
+#[cfg(feature = "cache")]
+mod cache;
 mod collection;
 mod communications;
+mod enums;
 mod events;
 mod exchange;
 mod fcm;
+mod format;
 mod historical;
 mod incentive_programs;
 mod kalshi_error;
@@ -126,15 +131,24 @@ mod live_data;
 mod market;
 mod milestone;
 mod portfolio;
+mod price;
+mod rate_limit;
+mod retry;
 mod search;
 mod structured_targets;
+#[cfg(feature = "metrics")]
+mod telemetry;
+mod websocket;
 
+pub use analytics::*;
 pub use api_keys::*;
 pub use collection::*;
 pub use communications::*;
+pub use enums::*;
 pub use events::*;
 pub use exchange::*;
 pub use fcm::FcmPosition;
+pub use format::*;
 pub use historical::*;
 pub use incentive_programs::*;
 pub use kalshi_error::*;
@@ -142,11 +156,14 @@ pub use live_data::*;
 pub use market::*;
 pub use milestone::*;
 pub use portfolio::*;
+pub use price::Price;
+pub use rate_limit::Priority;
+pub use retry::RetryPolicy;
 pub use search::*;
 pub use structured_targets::*;
+pub use websocket::*;
 
 // imports
-use reqwest;
 use openssl::pkey::{PKey, Private};
 use std::fs;
 use std::path::Path;
@@ -155,6 +172,10 @@ use std::path::Path;
 /// between the user and the market, abstracting away the meat of requests
 /// by encapsulating authentication information and the client itself.
 ///
+/// `Kalshi` is `Send + Sync`, and the futures and streams its methods return are `Send`,
+/// so a single instance can be shared (or cheaply cloned, reusing its connection pool)
+/// across tasks on a multi-threaded tokio runtime.
+///
 /// ## Creating a new `Kalshi` instance for demo mode:
 ///
 /// ```
@@ -176,8 +197,32 @@ pub struct Kalshi {
     private_key: PKey<Private>,
     /// - `client`: The HTTP client used for making requests to the marketplace.
     client: reqwest::Client,
+    /// - `https_client`: Client for third-party downloads that must stay on `https`, such as contract documents.
+    https_client: reqwest::Client,
+    /// - `etag_cache`: Responses kept for `ETag` revalidation (`cache` feature only).
+    #[cfg(feature = "cache")]
+    etag_cache: cache::EtagCache,
+    /// - `read_only`: When set, every request other than `GET` is refused.
+    read_only: bool,
+    /// - `max_response_bytes`: Largest response body the client will read.
+    max_response_bytes: usize,
+    /// - `default_headers`: Extra headers added to every request to the API's host.
+    default_headers: reqwest::header::HeaderMap,
+    /// - `dry_run`: When set, order writes are logged and answered locally instead of sent.
+    dry_run: bool,
+    /// - `market_cache`: Short-lived `get_market` results shared by concurrent callers, if enabled.
+    market_cache: Option<std::sync::Arc<market::MarketCache>>,
+    /// - `rate_limiter`: Token buckets every request waits on, if a rate limit is set.
+    rate_limiter: Option<std::sync::Arc<rate_limit::RateLimiter>>,
+    /// - `retry_policy`: How `429` and `5xx` responses are retried, if at all.
+    retry_policy: Option<RetryPolicy>,
+    /// - `priority`: Rate limit priority for every request, overriding the per-method default.
+    priority: Option<Priority>,
 }
 
+/// Default for [`Kalshi::set_max_response_bytes`]: 64 MiB.
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 64 * 1024 * 1024;
+
 impl Kalshi {
     /// Creates a new instance of Kalshi with the specified trading environment and authenticates immediately.
     /// This environment determines the base URL used for API requests.
@@ -216,8 +261,73 @@ impl Kalshi {
                 return Err(e.into());
             }
         };
-        
-        let private_key = match PKey::private_key_from_pem(&pem) {
+
+        Self::from_pem(trading_env, key_id, &pem).await
+    }
+
+    /// Creates a new instance of Kalshi from credentials in environment variables.
+    ///
+    /// The variables read are:
+    ///
+    /// * `KALSHI_API_KEY_ID` - The UUID shown next to the key in your Kalshi UI. Required.
+    /// * `KALSHI_PRIVATE_KEY_PATH` - Path to the private key file you downloaded.
+    /// * `KALSHI_PRIVATE_KEY` - The PEM-encoded private key itself, for deployments that
+    ///   inject secrets as values rather than files. Used only if `KALSHI_PRIVATE_KEY_PATH`
+    ///   is unset; one of the two is required.
+    /// * `KALSHI_ENV` - `demo` or `prod`. Optional, defaults to `demo`.
+    ///
+    /// Empty variables count as unset. Like [`new`](Kalshi::new), the credentials are
+    /// verified against the exchange before the client is returned.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Kalshi)`: An authenticated client.
+    /// - `Err(KalshiError)`: `MissingCredentials` naming the first required variable that is
+    ///   unset, a `UserInputError` if `KALSHI_ENV` is not `demo` or `prod`, or whatever
+    ///   [`new`](Kalshi::new) would return for a bad key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // KALSHI_API_KEY_ID=... KALSHI_PRIVATE_KEY_PATH=./kalshi.pem KALSHI_ENV=demo
+    /// let kalshi = kalshi::Kalshi::from_env().await?;
+    /// ```
+    ///
+    pub async fn from_env() -> Result<Self, crate::kalshi_error::KalshiError> {
+        Self::from_vars(|name| std::env::var(name).ok()).await
+    }
+
+    /// [`from_env`](Kalshi::from_env) with the variables read through `lookup`, so tests
+    /// don't have to touch the process environment.
+    async fn from_vars(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, crate::kalshi_error::KalshiError> {
+        let var = |name: &str| lookup(name).filter(|v| !v.trim().is_empty());
+        let missing = |name: &str| crate::kalshi_error::KalshiError::MissingCredentials { variable: name.to_string() };
+
+        let key_id = var("KALSHI_API_KEY_ID").ok_or_else(|| missing("KALSHI_API_KEY_ID"))?;
+        let trading_env = match var("KALSHI_ENV").as_deref().map(str::to_ascii_lowercase).as_deref() {
+            None | Some("demo") => TradingEnvironment::DemoMode,
+            Some("prod") => TradingEnvironment::ProdMode,
+            Some(other) => {
+                return Err(crate::kalshi_error::KalshiError::UserInputError(format!(
+                    "KALSHI_ENV must be \"demo\" or \"prod\", got {:?}",
+                    other
+                )))
+            }
+        };
+        if let Some(pem_path) = var("KALSHI_PRIVATE_KEY_PATH") {
+            return Self::new(trading_env, &key_id, &pem_path).await;
+        }
+        let pem = var("KALSHI_PRIVATE_KEY").ok_or_else(|| missing("KALSHI_PRIVATE_KEY_PATH"))?;
+        Self::from_pem(trading_env, &key_id, pem.as_bytes()).await
+    }
+
+    /// Parses `pem`, builds the client and verifies the credentials with the exchange.
+    async fn from_pem(
+        trading_env: TradingEnvironment,
+        key_id: &str,
+        pem: &[u8],
+    ) -> Result<Self, crate::kalshi_error::KalshiError> {
+        let private_key = match PKey::private_key_from_pem(pem) {
             Ok(key) => {
                 println!("Successfully parsed private key");
                 key
@@ -229,11 +339,25 @@ impl Kalshi {
         };
         
         let base_url = utils::build_base_url(trading_env).to_string();
+        let https_client = reqwest::Client::builder().https_only(true).build().map_err(|e| {
+            crate::kalshi_error::KalshiError::InternalError(format!("building the download client: {}", e))
+        })?;
         let kalshi = Self {
             base_url,
             key_id: key_id.to_string(),
             private_key,
             client: reqwest::Client::new(),
+            https_client,
+            #[cfg(feature = "cache")]
+            etag_cache: Default::default(),
+            read_only: false,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            default_headers: reqwest::header::HeaderMap::new(),
+            dry_run: false,
+            market_cache: None,
+            rate_limiter: None,
+            retry_policy: None,
+            priority: None,
         };
         
         // Verify authentication by hitting the exchange status endpoint
@@ -250,6 +374,189 @@ impl Kalshi {
             }
         }
     }
+
+    /// Returns a copy of this client that can only read.
+    ///
+    /// The copy shares the same connection pool and credentials, but every write
+    /// (any `POST`, `PUT` or `DELETE`, such as placing, amending or cancelling orders)
+    /// fails with [`KalshiError::ReadOnly`] before anything is sent. Hand it to
+    /// components that should never be able to trade.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let dashboard_client = kalshi_instance.read_only();
+    /// assert!(dashboard_client.is_read_only());
+    /// ```
+    ///
+    pub fn read_only(&self) -> Kalshi {
+        Kalshi {
+            read_only: true,
+            ..self.clone()
+        }
+    }
+
+    /// Returns `true` if this client was created by [`read_only`](Kalshi::read_only).
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Turns dry-run (paper trading) mode on or off.
+    ///
+    /// While enabled, [`create_order`](Kalshi::create_order), [`cancel_order`](Kalshi::cancel_order),
+    /// [`amend_order`](Kalshi::amend_order), [`decrease_order`](Kalshi::decrease_order),
+    /// [`batch_create_order`](Kalshi::batch_create_order) and
+    /// [`batch_cancel_order`](Kalshi::batch_cancel_order) log the intended action and
+    /// return a synthetic [`Order`] instead of calling the API. The actions are logged at
+    /// `info` level with target `kalshi::dry_run` through the [`log`](https://docs.rs/log)
+    /// facade, so they show up in whichever logger the application installs.
+    ///
+    /// Synthetic orders have an id starting with `dry-run-` and status
+    /// `OrderStatus::Unknown("dry_run")`; nothing about fills is simulated. Input validation still runs, and read methods behave normally.
+    /// Other writes (order groups, subaccounts, API keys) are not intercepted; use
+    /// [`read_only`](Kalshi::read_only) to block those.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is a mutable instance of `Kalshi`
+    /// kalshi_instance.set_dry_run(true);
+    /// let order = kalshi_instance.create_order(req).await.unwrap();
+    /// assert!(order.order_id.starts_with("dry-run-"));
+    /// ```
+    ///
+    pub fn set_dry_run(&mut self, dry_run: bool) {
+        self.dry_run = dry_run;
+    }
+
+    /// Returns `true` if dry-run mode is enabled. See [`set_dry_run`](Kalshi::set_dry_run).
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Enables (or, with `None`, disables) a short-lived cache for [`get_market`](Kalshi::get_market).
+    ///
+    /// While enabled, a market fetched within the last `ttl` is returned without another
+    /// request, and concurrent calls for the same ticker share a single in-flight request
+    /// instead of each hitting the API. Failed requests are not cached; the next caller
+    /// retries. Clones of this client made afterwards share the cache. Calling this again
+    /// starts a fresh, empty cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is a mutable instance of `Kalshi`
+    /// kalshi_instance.set_market_cache_ttl(Some(std::time::Duration::from_secs(1)));
+    /// let (a, b) = tokio::join!(
+    ///     kalshi_instance.get_market("SOME-MARKET-2024"),
+    ///     kalshi_instance.get_market("SOME-MARKET-2024"),
+    /// ); // one request
+    /// ```
+    ///
+    pub fn set_market_cache_ttl(&mut self, ttl: Option<std::time::Duration>) {
+        self.market_cache = ttl
+            .filter(|ttl| !ttl.is_zero())
+            .map(|ttl| std::sync::Arc::new(market::MarketCache::new(ttl)));
+    }
+
+    /// Caps how many bytes of any single response body the client will read.
+    ///
+    /// Bodies are streamed and counted before they are deserialized. Once a response
+    /// exceeds `limit` (or announces a larger `Content-Length`), the request fails with
+    /// [`KalshiError::ResponseTooLarge`] and the rest of the body is discarded. This
+    /// applies to every API call as well as [`get_bytes`](Kalshi::get_bytes) downloads.
+    /// Defaults to 64 MiB.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is a mutable instance of `Kalshi`
+    /// kalshi_instance.set_max_response_bytes(8 * 1024 * 1024);
+    /// ```
+    ///
+    pub fn set_max_response_bytes(&mut self, limit: usize) {
+        self.max_response_bytes = limit;
+    }
+
+    /// The current response body limit. See [`set_max_response_bytes`](Kalshi::set_max_response_bytes).
+    pub fn max_response_bytes(&self) -> usize {
+        self.max_response_bytes
+    }
+
+    /// Sets headers to add to every API request, replacing any set previously.
+    ///
+    /// Useful when Kalshi is reached through a proxy or gateway that expects its own
+    /// headers, such as an internal auth token or a trace id. A default header is only
+    /// added when the request doesn't already carry that header, so the signing headers
+    /// (`KALSHI-ACCESS-*`) and per-request headers such as `Content-Type` always win.
+    /// Requests to any other host than the API's, such as [`get_bytes`](Kalshi::get_bytes)
+    /// downloads, never carry them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-gateway-token", HeaderValue::from_static("secret"));
+    /// // Assuming `kalshi_instance` is a mutable instance of `Kalshi`
+    /// kalshi_instance.set_default_headers(headers);
+    /// ```
+    ///
+    pub fn set_default_headers(&mut self, headers: reqwest::header::HeaderMap) {
+        self.default_headers = headers;
+    }
+}
+
+// GENERAL TYPES
+// -----------------------------------------------
+
+/// One page of results from a paginated endpoint.
+///
+/// `cursor` is the opaque token for the next page, or `None` on the last page. It can
+/// be persisted and passed back later (e.g. to [`Kalshi::get_markets_from_cursor`]) to
+/// resume a scan; cursors the API no longer accepts fail with
+/// [`KalshiError::InvalidCursor`].
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// Cursor for the next page, `None` if this is the last one.
+    pub cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Returns `true` if there are no further pages.
+    pub fn is_last(&self) -> bool {
+        self.cursor.is_none()
+    }
+}
+
+/// One page of results parsed element by element, from the `*_lenient` methods such as
+/// [`Kalshi::get_markets_lenient`].
+///
+/// Elements that failed to deserialize are left out of `items` and reported in `skipped`
+/// instead of failing the whole page.
+#[derive(Debug, Clone)]
+pub struct LenientPage<T> {
+    /// The elements that deserialized successfully, in the order the API returned them.
+    pub items: Vec<T>,
+    /// The elements that didn't.
+    pub skipped: Vec<SkippedItem>,
+    /// Cursor for the next page, `None` if this is the last one.
+    pub cursor: Option<String>,
+}
+
+/// An element of a list response that couldn't be deserialized.
+#[derive(Debug, Clone)]
+pub struct SkippedItem {
+    /// Position of the element in the page's array.
+    pub index: usize,
+    /// Why deserialization failed.
+    pub error: String,
+    /// The element as received.
+    pub raw: serde_json::Value,
 }
 
 // GENERAL ENUMS
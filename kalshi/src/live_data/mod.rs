@@ -101,7 +101,7 @@ impl Kalshi {
 
         let url = format!("{}{}", self.base_url, path);
         let final_url = reqwest::Url::parse_with_params(&url, &params)?;
-        let res: LiveDataBatchResponse = self.client.get(final_url).send().await?.json().await?;
+        let res: LiveDataBatchResponse = self.send_json(self.client.get(final_url)).await?;
         Ok(res.live_datas)
     }
 }
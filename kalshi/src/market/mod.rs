@@ -1,20 +1,292 @@
-use super::Kalshi;
+use super::{Kalshi, LenientPage, Page};
+use crate::analytics::FairValueModel;
 use crate::kalshi_error::*;
+use crate::utils::next_cursor;
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
 // All public types are re-exported from the OpenAPI-generated module.
 pub use crate::generated::types::{
     GetMarketOrderbookResponse, GetMarketOrderbooksResponse, GetMarketResponse, GetMarketsResponse,
-    GetSeriesListResponse, GetSeriesResponse, GetTradesResponse, Market, MarketCandlestick,
-    MarketCandlesticksResponse, MarketMarketType, MarketOrderbookFp, MarketResult, MarketStatus,
-    MultivariateEventCollection, OrderbookCountFp, PriceLevelDollarsCountFp, Series,
-    SettlementSource, Trade, TradeTakerSide,
+    BidAskDistribution, GetSeriesListResponse, GetSeriesResponse, GetTradesResponse, Market,
+    MarketCandlestick, PriceDistribution,
+    MarketCandlesticksResponse, MarketOrderbookFp, MultivariateEventCollection, OrderbookCountFp,
+    PriceLevelDollarsCountFp, Series, SettlementSource, Trade,
+};
+use crate::enums::{
+    MarketMarketType, MarketResult, MarketStatus, MarketStrikeType, OrderAction, OrderSide, TradeTakerSide,
+};
+use crate::generated::types::{
+    CreateOrderRequest, CreateOrderRequestAction, CreateOrderRequestSide,
+    CreateOrderRequestTimeInForce, FixedPointCount, FixedPointDollars,
 };
 
 /// Alias for [`OrderbookCountFp`] — the dollar-denominated orderbook returned by the API.
 pub type Orderbook = OrderbookCountFp;
 
-impl<'a> Kalshi {
+/// Alias for [`MarketCandlestick`] — one OHLC bucket returned by the candlestick endpoints.
+pub type Candle = MarketCandlestick;
+
+/// One market's candlestick query for [`Kalshi::get_candlesticks_multi`].
+///
+/// Fields mirror the arguments of [`Kalshi::get_market_candlesticks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CandleRequest {
+    /// The market ticker.
+    pub ticker: String,
+    /// The series the market belongs to.
+    pub series_ticker: String,
+    /// Only candles ending on or after this Unix timestamp.
+    pub start_ts: Option<i64>,
+    /// Only candles ending on or before this Unix timestamp.
+    pub end_ts: Option<i64>,
+    /// Candle length in minutes (1, 60 or 1440).
+    pub period_interval: Option<i32>,
+}
+
+impl CandleRequest {
+    /// A request for every available candle of `period_interval` minutes.
+    pub fn new(ticker: &str, series_ticker: &str, period_interval: i32) -> CandleRequest {
+        CandleRequest {
+            ticker: ticker.to_string(),
+            series_ticker: series_ticker.to_string(),
+            start_ts: None,
+            end_ts: None,
+            period_interval: Some(period_interval),
+        }
+    }
+}
+
+/// Page size used when a helper walks every page of `/markets`.
+const MARKETS_PAGE_LIMIT: i64 = 1000;
+
+/// Page size used when a helper walks every page of `/markets/trades`.
+const TRADES_PAGE_LIMIT: i64 = 1000;
+
+/// Number of recent trades fetched by [`Kalshi::get_new_trades`].
+const NEW_TRADES_LIMIT: i64 = 1000;
+
+/// Largest contract document [`Kalshi::get_series_contract`] downloads: 16 MiB.
+const CONTRACT_MAX_BYTES: usize = 16 * 1024 * 1024;
+
+/// Maximum number of tickers sent in a single `tickers=` filter by
+/// [`Kalshi::get_markets_by_tickers`], keeping request URLs well below common length caps.
+const TICKERS_PER_REQUEST: usize = 100;
+
+/// Most candles the candlestick endpoint returns for one request, used by
+/// [`Kalshi::get_candlesticks_range`] to size its chunks.
+const MAX_CANDLES_PER_REQUEST: i64 = 5000;
+
+/// Server-side filters accepted by `/markets`, minus the pagination parameters.
+///
+/// Used by the helpers that page through the endpoint on the caller's behalf.
+/// Every field maps one-to-one onto the argument of the same name in
+/// [`Kalshi::get_markets`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketFilter {
+    /// Only return markets belonging to this event.
+    pub event_ticker: Option<String>,
+    /// Only return markets belonging to this series.
+    pub series_ticker: Option<String>,
+    /// Only return markets with this status (e.g. "open").
+    pub status: Option<String>,
+    /// Comma-separated list of market tickers to return.
+    pub tickers: Option<String>,
+    /// Minimum close timestamp (Unix seconds).
+    pub min_close_ts: Option<i64>,
+    /// Maximum close timestamp (Unix seconds).
+    pub max_close_ts: Option<i64>,
+}
+
+/// A market search for [`Kalshi::search_markets`]: server-side filters plus predicates
+/// the `/markets` endpoint doesn't support, checked client-side.
+///
+/// Every field left as `None` matches all markets.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketQuery {
+    /// Server-side filters, sent with every page request.
+    pub filter: MarketFilter,
+    /// Only markets whose title contains this text, ignoring case.
+    pub title_contains: Option<String>,
+    /// Only markets whose last traded price is at least this many dollars.
+    pub min_last_price: Option<f64>,
+    /// Only markets whose last traded price is at most this many dollars.
+    pub max_last_price: Option<f64>,
+    /// Only markets that have traded at least this many contracts.
+    pub min_volume: Option<i64>,
+    /// Only markets of series in this category (e.g. "Climate and Weather").
+    pub category: Option<String>,
+}
+
+impl MarketQuery {
+    /// Returns `true` if `market` passes the title, price and volume predicates.
+    ///
+    /// The category and the server-side `filter` aren't checked here, since a market
+    /// doesn't carry either; [`Kalshi::search_markets`] applies them.
+    pub fn matches(&self, market: &Market) -> bool {
+        if let Some(needle) = &self.title_contains {
+            let title = market.title.as_deref().unwrap_or_default().to_lowercase();
+            if !title.contains(&needle.to_lowercase()) {
+                return false;
+            }
+        }
+        if self.min_last_price.is_some() || self.max_last_price.is_some() {
+            let Some(price) = market.last_price_dollars.to_f64() else {
+                return false;
+            };
+            if self.min_last_price.is_some_and(|min| price < min) || self.max_last_price.is_some_and(|max| price > max) {
+                return false;
+            }
+        }
+        if let Some(min) = self.min_volume {
+            if market.volume_fp.to_f64().is_none_or(|volume| volume < min as f64) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// One page request for [`Kalshi::markets`], built with chained setters.
+///
+/// Unset parameters are left out of the request, so the server defaults apply.
+///
+/// # Example
+///
+/// ```
+/// let request = GetMarketsRequest::new().status("open").event_ticker("SOME-EVENT").limit(50);
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GetMarketsRequest {
+    /// Server-side filters.
+    pub filter: MarketFilter,
+    /// Page size.
+    pub limit: Option<i64>,
+    /// Pagination cursor from a previous page.
+    pub cursor: Option<String>,
+}
+
+impl GetMarketsRequest {
+    /// A request with no parameters: the first page of all markets.
+    pub fn new() -> GetMarketsRequest {
+        GetMarketsRequest::default()
+    }
+
+    /// Sets the page size.
+    pub fn limit(mut self, limit: i64) -> GetMarketsRequest {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the cursor of the page to fetch.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> GetMarketsRequest {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Only markets belonging to this event.
+    pub fn event_ticker(mut self, event_ticker: impl Into<String>) -> GetMarketsRequest {
+        self.filter.event_ticker = Some(event_ticker.into());
+        self
+    }
+
+    /// Only markets belonging to this series.
+    pub fn series_ticker(mut self, series_ticker: impl Into<String>) -> GetMarketsRequest {
+        self.filter.series_ticker = Some(series_ticker.into());
+        self
+    }
+
+    /// Only markets with this status (e.g. "open").
+    pub fn status(mut self, status: impl Into<String>) -> GetMarketsRequest {
+        self.filter.status = Some(status.into());
+        self
+    }
+
+    /// Only these markets.
+    pub fn tickers<I, S>(mut self, tickers: I) -> GetMarketsRequest
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let tickers: Vec<String> = tickers.into_iter().map(|t| t.as_ref().to_string()).collect();
+        self.filter.tickers = Some(tickers.join(","));
+        self
+    }
+
+    /// Only markets closing at or after this Unix timestamp.
+    pub fn min_close_ts(mut self, min_close_ts: i64) -> GetMarketsRequest {
+        self.filter.min_close_ts = Some(min_close_ts);
+        self
+    }
+
+    /// Only markets closing at or before this Unix timestamp.
+    pub fn max_close_ts(mut self, max_close_ts: i64) -> GetMarketsRequest {
+        self.filter.max_close_ts = Some(max_close_ts);
+        self
+    }
+}
+
+/// An item of [`Kalshi::markets_stream_with_checkpoints`].
+#[derive(Debug, Clone)]
+pub enum MarketStreamItem {
+    /// A market matching the filter, boxed to keep checkpoints small.
+    Market(Box<Market>),
+    /// Every market before this point has been yielded; resume after it with its cursor.
+    Checkpoint(StreamCheckpoint),
+}
+
+/// A resumable position in a paginated scan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamCheckpoint {
+    /// Cursor of the first page not yet yielded.
+    pub cursor: String,
+}
+
+impl Kalshi {
+    /// Retrieves one page of markets described by a [`GetMarketsRequest`].
+    ///
+    /// The recommended way to query `/markets`: the same request as
+    /// [`get_markets`](Kalshi::get_markets), without a long list of positional `None`s.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((Option<String>, Vec<Market>))`: The cursor of the next page, if any, and the
+    ///   markets on this one.
+    /// - `Err(KalshiError)`: `InvalidCursor` if the API rejects the request's cursor, or
+    ///   another error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let request = GetMarketsRequest::new().status("open").event_ticker("SOME-EVENT").limit(50);
+    /// let (cursor, markets) = kalshi_instance.markets(request).await.unwrap();
+    /// ```
+    ///
+    pub async fn markets(&self, request: GetMarketsRequest) -> Result<(Option<String>, Vec<Market>), KalshiError> {
+        let GetMarketsRequest { filter, limit, cursor } = request;
+        self.get_markets(
+            limit,
+            cursor,
+            filter.event_ticker,
+            filter.series_ticker,
+            filter.status,
+            filter.tickers,
+            filter.min_close_ts,
+            filter.max_close_ts,
+        )
+        .await
+    }
+
     /// Retrieves a list of markets from the Kalshi exchange based on specified criteria.
     ///
+    /// Prefer [`markets`](Kalshi::markets), which takes the same parameters as a
+    /// [`GetMarketsRequest`]; this positional form is kept for compatibility.
+    ///
     /// This method fetches multiple markets, allowing for filtering by event ticker, series ticker,
     /// status, tickers, time range, and pagination. Markets represent the individual trading
     /// instruments within events.
@@ -34,7 +306,8 @@ impl<'a> Kalshi {
     ///
     /// - `Ok((Option<String>, Vec<Market>))`: A tuple containing an optional pagination cursor
     ///   and a vector of `Market` objects on successful retrieval.
-    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    /// - `Err(KalshiError)`: `InvalidCursor` if the API rejects `cursor` (for example because
+    ///   it expired), or another error if there is an issue with the request.
     ///
     /// # Example
     ///
@@ -46,6 +319,7 @@ impl<'a> Kalshi {
     /// ).await.unwrap();
     /// ```
     ///
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_markets(
         &self,
         limit: Option<i64>, cursor: Option<String>,
@@ -56,7 +330,7 @@ impl<'a> Kalshi {
         let url = format!("{}/markets", self.base_url);
         let mut p = vec![];
         add_param!(p, "limit", limit);
-        add_param!(p, "cursor", cursor);
+        add_param!(p, "cursor", cursor.clone());
         add_param!(p, "event_ticker", event_ticker);
         add_param!(p, "series_ticker", series_ticker);
         add_param!(p, "status", status);
@@ -64,13 +338,413 @@ impl<'a> Kalshi {
         add_param!(p, "min_close_ts", min_close_ts);
         add_param!(p, "max_close_ts", max_close_ts);
 
-        let res: GetMarketsResponse = self.client
-            .get(reqwest::Url::parse_with_params(&url, &p)?)
-            .send().await?.json().await?;
+        let res: GetMarketsResponse = self
+            .send_json_with_cursor(
+                self.client.get(reqwest::Url::parse_with_params(&url, &p)?),
+                cursor.as_deref(),
+            )
+            .await?;
         let cursor = if res.cursor.is_empty() { None } else { Some(res.cursor) };
         Ok((cursor, res.markets))
     }
 
+    /// Retrieves one page of markets, skipping any market that fails to deserialize.
+    ///
+    /// [`get_markets`](Kalshi::get_markets) fails the whole page if a single market in it
+    /// is malformed. This variant parses each market separately and reports the ones it
+    /// couldn't parse in [`LenientPage::skipped`], so a large catalog scan doesn't lose a
+    /// page of good markets to one bad record.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Server-side filters for the request.
+    /// * `limit` - An optional page size.
+    /// * `cursor` - An optional pagination cursor from a previous page.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(LenientPage<Market>)`: The markets that parsed, the ones that didn't and the
+    ///   cursor for the next page.
+    /// - `Err(KalshiError)`: An error if the request fails or the response as a whole
+    ///   isn't a list of markets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let page = kalshi_instance.get_markets_lenient(&MarketFilter::default(), Some(1000), None).await.unwrap();
+    /// for skipped in &page.skipped {
+    ///     eprintln!("market #{} skipped: {}", skipped.index, skipped.error);
+    /// }
+    /// ```
+    ///
+    pub async fn get_markets_lenient(
+        &self,
+        filter: &MarketFilter,
+        limit: Option<i64>,
+        cursor: Option<String>,
+    ) -> Result<LenientPage<Market>, KalshiError> {
+        let url = format!("{}/markets", self.base_url);
+        let mut p = vec![];
+        add_param!(p, "limit", limit);
+        add_param!(p, "cursor", cursor.clone());
+        add_param!(p, "event_ticker", filter.event_ticker.clone());
+        add_param!(p, "series_ticker", filter.series_ticker.clone());
+        add_param!(p, "status", filter.status.clone());
+        add_param!(p, "tickers", filter.tickers.clone());
+        add_param!(p, "min_close_ts", filter.min_close_ts);
+        add_param!(p, "max_close_ts", filter.max_close_ts);
+
+        let res: RawMarketsResponse = self
+            .send_json_with_cursor(
+                self.client.get(reqwest::Url::parse_with_params(&url, &p)?),
+                cursor.as_deref(),
+            )
+            .await?;
+        let (items, skipped) = crate::utils::parse_lenient(res.markets);
+        let cursor = res.cursor.filter(|c| !c.is_empty());
+        Ok(LenientPage { items, skipped, cursor })
+    }
+
+    /// Resumes a `/markets` scan from a cursor saved from an earlier page.
+    ///
+    /// Intended for incremental syncs that persist their position and pick up where they
+    /// left off after a restart. The page is fetched with the maximum page size and the
+    /// same `filter` the cursor was originally obtained with.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - A cursor returned by a previous page (see [`Page::cursor`]).
+    /// * `filter` - Server-side filters; these should match the ones used to obtain `cursor`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Page<Market>)`: The next page of markets and the cursor that follows it.
+    /// - `Err(KalshiError)`: `InvalidCursor` if the cursor is empty or the API no longer
+    ///   accepts it, in which case the caller should fall back to a fresh scan, or another
+    ///   error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let filter = MarketFilter { status: Some("open".to_string()), ..Default::default() };
+    /// match kalshi_instance.get_markets_from_cursor(saved_cursor, &filter).await {
+    ///     Ok(page) => { /* process page.items, then persist page.cursor */ }
+    ///     Err(KalshiError::InvalidCursor { .. }) => { /* restart the scan from scratch */ }
+    ///     Err(e) => return Err(e),
+    /// }
+    /// ```
+    ///
+    pub async fn get_markets_from_cursor(
+        &self,
+        cursor: String,
+        filter: &MarketFilter,
+    ) -> Result<Page<Market>, KalshiError> {
+        if cursor.is_empty() {
+            return Err(KalshiError::InvalidCursor { cursor });
+        }
+        let (cursor, items) = self.get_markets_page(filter, Some(cursor)).await?;
+        Ok(Page { items, cursor })
+    }
+
+    /// Fetches every market matching `filter` and groups them by `event_ticker`.
+    ///
+    /// All pages of `/markets` are walked before returning. Within each event the markets
+    /// keep the order in which the API returned them, so repeated calls produce the same
+    /// per-event ordering.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Server-side filters applied to every page request.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(HashMap<String, Vec<Market>>)`: Markets keyed by their event ticker.
+    /// - `Err(KalshiError)`: An error if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let filter = MarketFilter { series_ticker: Some("KXHIGHNY".to_string()), ..Default::default() };
+    /// let by_event = kalshi_instance.markets_grouped_by_event(&filter).await.unwrap();
+    /// ```
+    ///
+    pub async fn markets_grouped_by_event(
+        &self,
+        filter: &MarketFilter,
+    ) -> Result<HashMap<String, Vec<Market>>, KalshiError> {
+        let mut grouped: HashMap<String, Vec<Market>> = HashMap::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let (next, markets) = self.get_markets_page(filter, cursor.clone()).await?;
+            for market in markets {
+                grouped.entry(market.event_ticker.clone()).or_default().push(market);
+            }
+            cursor = next_cursor(cursor.as_deref(), next)?;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(grouped)
+    }
+
+    /// Streams every market matching `filter`, following pagination cursors.
+    ///
+    /// Pages are requested with the maximum page size and the same `filter` on every
+    /// request. The next page is only fetched once the consumer has drained the current
+    /// one, so a slow consumer never causes pages to pile up in memory. If a request
+    /// fails, or the API hands back the cursor it was just sent, the error is yielded and
+    /// the stream ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Server-side filters applied to every page request.
+    ///
+    /// # Returns
+    ///
+    /// A stream of `Result<Market, KalshiError>` that borrows this client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let filter = MarketFilter { status: Some("open".to_string()), ..Default::default() };
+    /// let markets = kalshi_instance.markets_stream(filter);
+    /// futures_util::pin_mut!(markets);
+    /// while let Some(market) = markets.next().await {
+    ///     println!("{}", market.unwrap().ticker);
+    /// }
+    /// ```
+    ///
+    pub fn markets_stream(&self, filter: MarketFilter) -> impl Stream<Item = Result<Market, KalshiError>> + '_ {
+        self.market_pages(filter, None).flat_map(|page| {
+            stream::iter(match page {
+                Ok((markets, _)) => markets.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            })
+        })
+    }
+
+    /// Like [`markets_stream`](Kalshi::markets_stream), but also yields a checkpoint after
+    /// every page so a long scan can be resumed after a restart.
+    ///
+    /// After the markets of each page (up to 1000) comes a
+    /// [`MarketStreamItem::Checkpoint`] carrying the cursor of the next page. Once every
+    /// market before a checkpoint has been processed, persisting its cursor is enough to
+    /// pick up from there later, by passing it back as `start_cursor` or to
+    /// [`get_markets_from_cursor`](Kalshi::get_markets_from_cursor). No checkpoint follows
+    /// the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Server-side filters applied to every page request; keep it the same
+    ///   when resuming.
+    /// * `start_cursor` - A checkpointed cursor to resume from, or `None` to start at the
+    ///   beginning.
+    ///
+    /// # Returns
+    ///
+    /// A stream of `Result<MarketStreamItem, KalshiError>` that borrows this client.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let items = kalshi_instance.markets_stream_with_checkpoints(MarketFilter::default(), load_cursor());
+    /// futures_util::pin_mut!(items);
+    /// while let Some(item) = items.next().await {
+    ///     match item.unwrap() {
+    ///         MarketStreamItem::Market(market) => store(market),
+    ///         MarketStreamItem::Checkpoint(checkpoint) => save_cursor(&checkpoint.cursor),
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub fn markets_stream_with_checkpoints(
+        &self,
+        filter: MarketFilter,
+        start_cursor: Option<String>,
+    ) -> impl Stream<Item = Result<MarketStreamItem, KalshiError>> + '_ {
+        self.market_pages(filter, start_cursor).flat_map(|page| {
+            stream::iter(match page {
+                Ok((markets, next)) => {
+                    let checkpoint = next.map(|cursor| MarketStreamItem::Checkpoint(StreamCheckpoint { cursor }));
+                    markets.into_iter().map(|m| MarketStreamItem::Market(Box::new(m))).chain(checkpoint).map(Ok).collect()
+                }
+                Err(e) => vec![Err(e)],
+            })
+        })
+    }
+
+    /// Pages of `/markets` matching `filter` with the cursor of the page after each,
+    /// starting at `start`. Ends after the last page or the first error.
+    fn market_pages(
+        &self,
+        filter: MarketFilter,
+        start: Option<String>,
+    ) -> impl Stream<Item = Result<(Vec<Market>, Option<String>), KalshiError>> + '_ {
+        // `None` once the last page has been fetched; `Some(cursor)` otherwise.
+        stream::unfold((filter, Some(start)), move |(filter, state)| async move {
+            let cursor = state?;
+            let page = self
+                .get_markets_page(&filter, cursor.clone())
+                .await
+                .and_then(|(next, markets)| Ok((markets, next_cursor(cursor.as_deref(), next)?)));
+            Some(match page {
+                Ok((markets, next)) => (Ok((markets, next.clone())), (filter, next.map(Some))),
+                Err(e) => (Err(e), (filter, None)),
+            })
+        })
+    }
+
+    /// Collects every market matching `filter` into a `Vec`.
+    ///
+    /// Drains [`markets_stream`](Kalshi::markets_stream); markets keep the order the
+    /// API returned them in.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Market>)`: All matching markets.
+    /// - `Err(KalshiError)`: The first error hit while paginating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let filter = MarketFilter { event_ticker: Some("SOME-EVENT".to_string()), ..Default::default() };
+    /// let markets = kalshi_instance.collect_markets(&filter).await.unwrap();
+    /// ```
+    ///
+    pub async fn collect_markets(&self, filter: &MarketFilter) -> Result<Vec<Market>, KalshiError> {
+        self.markets_stream(filter.clone()).try_collect().await
+    }
+
+    /// Finds every market matching `query`, including predicates `/markets` can't filter on.
+    ///
+    /// The server-side `query.filter` is applied first, so narrowing it (by series, event
+    /// or status, say) keeps the scan short; every page of the result is then checked
+    /// against [`MarketQuery::matches`]. A `category` is looked up once with
+    /// [`get_series_list`](Kalshi::get_series_list), and a market is kept if its event
+    /// ticker starts with one of the category's series tickers followed by `-`, Kalshi's
+    /// naming convention for events.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The server-side filters and client-side predicates.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Market>)`: The matching markets, in the order the API returned them.
+    /// - `Err(KalshiError)`: The first error hit while paginating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let query = MarketQuery {
+    ///     filter: MarketFilter { status: Some("open".to_string()), ..Default::default() },
+    ///     title_contains: Some("temperature".to_string()),
+    ///     min_last_price: Some(0.10),
+    ///     max_last_price: Some(0.90),
+    ///     ..Default::default()
+    /// };
+    /// let markets = kalshi_instance.search_markets(query).await.unwrap();
+    /// ```
+    ///
+    pub async fn search_markets(&self, query: MarketQuery) -> Result<Vec<Market>, KalshiError> {
+        let series = match &query.category {
+            Some(category) => {
+                let series = self.get_series_list(None, None, Some(category.clone()), None).await?;
+                if series.is_empty() {
+                    return Ok(Vec::new());
+                }
+                Some(series.into_iter().map(|s| s.ticker).collect::<Vec<_>>())
+            }
+            None => None,
+        };
+        let in_category = |market: &Market| {
+            series.as_ref().is_none_or(|series| {
+                series.iter().any(|ticker| {
+                    market.event_ticker.strip_prefix(ticker.as_str()).is_some_and(|rest| rest.starts_with('-'))
+                })
+            })
+        };
+        self.markets_stream(query.filter.clone())
+            .try_filter(|market| std::future::ready(query.matches(market) && in_category(market)))
+            .try_collect()
+            .await
+    }
+
+    /// Retrieves several markets by ticker using the `tickers` filter of `/markets`.
+    ///
+    /// This is much cheaper than calling [`get_market`](Kalshi::get_market) once per ticker.
+    /// Tickers are sent in chunks of up to 100 per request, and every page of each chunk
+    /// is followed.
+    ///
+    /// Tickers the exchange does not know about are skipped rather than treated as an
+    /// error, so the result can be shorter than `tickers`. Compare each market's `ticker`
+    /// against the input to detect misses. Duplicate tickers in the input are returned
+    /// once per occurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - The market tickers to fetch.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Market>)`: The markets found, in the same order as `tickers`.
+    /// - `Err(KalshiError)`: An error if any request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let watchlist = vec!["SOME-MARKET-2024".to_string(), "OTHER-MARKET-2024".to_string()];
+    /// let markets = kalshi_instance.get_markets_by_tickers(&watchlist).await.unwrap();
+    /// ```
+    ///
+    pub async fn get_markets_by_tickers(&self, tickers: &[String]) -> Result<Vec<Market>, KalshiError> {
+        let mut found: HashMap<String, Market> = HashMap::with_capacity(tickers.len());
+        for chunk in tickers.chunks(TICKERS_PER_REQUEST) {
+            let filter = MarketFilter { tickers: Some(chunk.join(",")), ..Default::default() };
+            let mut cursor: Option<String> = None;
+            loop {
+                let (next, markets) = self.get_markets_page(&filter, cursor.clone()).await?;
+                found.extend(markets.into_iter().map(|m| (m.ticker.clone(), m)));
+                cursor = next_cursor(cursor.as_deref(), next)?;
+                if cursor.is_none() {
+                    break;
+                }
+            }
+        }
+        Ok(tickers.iter().filter_map(|t| found.get(t).cloned()).collect())
+    }
+
+    /// Fetches one page of `/markets` for `filter` using the maximum page size.
+    async fn get_markets_page(
+        &self,
+        filter: &MarketFilter,
+        cursor: Option<String>,
+    ) -> Result<(Option<String>, Vec<Market>), KalshiError> {
+        self.get_markets(
+            Some(MARKETS_PAGE_LIMIT),
+            cursor,
+            filter.event_ticker.clone(),
+            filter.series_ticker.clone(),
+            filter.status.clone(),
+            filter.tickers.clone(),
+            filter.min_close_ts,
+            filter.max_close_ts,
+        )
+        .await
+    }
+
     /// Retrieves detailed information about a specific market from the Kalshi exchange.
     ///
     /// This method fetches data for a single market identified by its ticker.
@@ -94,8 +768,19 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn get_market(&self, ticker: &str) -> Result<Market, KalshiError> {
+        let Some(cache) = &self.market_cache else {
+            return self.fetch_market(ticker).await;
+        };
+        let slot = cache.slot(ticker);
+        let (_, market) = slot
+            .get_or_try_init(|| async { Ok::<_, KalshiError>((Instant::now(), self.fetch_market(ticker).await?)) })
+            .await?;
+        Ok(market.clone())
+    }
+
+    async fn fetch_market(&self, ticker: &str) -> Result<Market, KalshiError> {
         let url = format!("{}/markets/{}", self.base_url, ticker);
-        let res: GetMarketResponse = self.client.get(url).send().await?.json().await?;
+        let res: GetMarketResponse = self.send_json(self.client.get(url)).await?;
         Ok(res.market)
     }
 
@@ -107,11 +792,13 @@ impl<'a> Kalshi {
     /// # Arguments
     ///
     /// * `ticker` - A string slice referencing the market's unique ticker identifier.
-    /// * `depth` - Optional depth parameter to limit the number of price levels returned.
+    /// * `depth` - Optional number of price levels to return per side, from 1 to 100. `None`
+    ///   leaves it to the server, which returns the full book.
     ///
     /// # Returns
     ///
     /// - `Ok(OrderbookCountFp)`: The current orderbook data for the specified market on successful retrieval.
+    /// - `Err(KalshiError::UserInputError)`: If `depth` is outside `1..=100`.
     /// - `Err(KalshiError)`: An error if there is an issue with the request.
     ///
     /// # Example
@@ -123,19 +810,81 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn get_orderbook(&self, ticker: &str, depth: Option<i32>) -> Result<OrderbookCountFp, KalshiError> {
+        if let Some(d) = depth.filter(|d| !(1..=100).contains(d)) {
+            return Err(KalshiError::UserInputError(format!(
+                "orderbook depth must be between 1 and 100, got {}; pass None for the full book",
+                d
+            )));
+        }
         let mut url = format!("{}/markets/{}/orderbook", self.base_url, ticker);
         if let Some(d) = depth {
             url.push_str(&format!("?depth={}", d));
         }
-        let res: GetMarketOrderbookResponse = self.client.get(&url).send().await?.json().await?;
+        let res: GetMarketOrderbookResponse = self.send_json(self.client.get(&url)).await?;
         Ok(res.orderbook_fp)
     }
 
-    /// Retrieves the orderbook for a specific market (without depth limit).
+    /// Retrieves the full orderbook for a specific market, every price level included.
     pub async fn get_orderbook_full(&self, ticker: &str) -> Result<OrderbookCountFp, KalshiError> {
         self.get_orderbook(ticker, None).await
     }
 
+    /// Fetches candlesticks for many markets concurrently.
+    ///
+    /// Each [`CandleRequest`] is sent as a [`get_market_candlesticks`](Kalshi::get_market_candlesticks)
+    /// call, with at most `concurrency` in flight at once (at least one). A failing request
+    /// doesn't affect the others: every request gets its own `Result`.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - The markets, series, intervals and ranges to fetch.
+    /// * `concurrency` - Maximum number of requests in flight.
+    ///
+    /// # Returns
+    ///
+    /// A map from market ticker to that request's candles or error. If several requests
+    /// share a ticker, only the one listed last is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let requests = vec![
+    ///     CandleRequest::new("SOME-MARKET-2024", "SOME-SERIES", 60),
+    ///     CandleRequest::new("OTHER-MARKET-2024", "SOME-SERIES", 60),
+    /// ];
+    /// for (ticker, candles) in kalshi_instance.get_candlesticks_multi(requests, 4).await {
+    ///     println!("{}: {:?}", ticker, candles.map(|c| c.len()));
+    /// }
+    /// ```
+    ///
+    pub async fn get_candlesticks_multi(
+        &self,
+        requests: Vec<CandleRequest>,
+        concurrency: usize,
+    ) -> HashMap<String, Result<Vec<Candle>, KalshiError>> {
+        let mut results: Vec<(usize, String, Result<Vec<Candle>, KalshiError>)> =
+            stream::iter(requests.into_iter().enumerate())
+                .map(|(i, req)| async move {
+                    let candles = self
+                        .get_market_candlesticks(
+                            &req.ticker,
+                            &req.series_ticker,
+                            req.start_ts,
+                            req.end_ts,
+                            req.period_interval,
+                        )
+                        .await;
+                    (i, req.ticker, candles)
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+        // Completion order is arbitrary; insert in request order so the last one wins.
+        results.sort_by_key(|(i, _, _)| *i);
+        results.into_iter().map(|(_, ticker, candles)| (ticker, candles)).collect()
+    }
+
     /// Retrieves candlestick data for a specific market from the Kalshi exchange.
     ///
     /// This method fetches historical price data in candlestick format for a market,
@@ -152,6 +901,8 @@ impl<'a> Kalshi {
     /// # Returns
     ///
     /// - `Ok(Vec<Candle>)`: A vector of `Candle` objects on successful retrieval.
+    /// - `Err(KalshiError::TickerSeriesMismatch)`: If the API reports the market as not found
+    ///   because `ticker` belongs to a different series than `series_ticker`.
     /// - `Err(KalshiError)`: An error if there is an issue with the request.
     ///
     /// # Example
@@ -178,16 +929,135 @@ impl<'a> Kalshi {
         add_param!(p, "end_ts", end_ts);
         add_param!(p, "period_interval", period_interval);
 
-        let res: MarketCandlesticksResponse = self.client
-            .get(reqwest::Url::parse_with_params(&url, &p)?)
-            .send().await?.json().await?;
+        let resp = self
+            .send(self.client.get(reqwest::Url::parse_with_params(&url, &p)?))
+            .await?;
+
+        // A mismatched ticker/series pair surfaces as a bare 404; find out whether
+        // that's what happened so the caller gets an actionable error.
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            let actual = self.resolve_series_ticker(ticker).await?;
+            if !actual.eq_ignore_ascii_case(series_ticker) {
+                return Err(KalshiError::TickerSeriesMismatch {
+                    ticker: ticker.to_string(),
+                    series: series_ticker.to_string(),
+                });
+            }
+        }
+
+        let res: MarketCandlesticksResponse =
+            self.buffered(self.check_status(resp).await?).await?.json().await?;
         Ok(res.candlesticks)
     }
 
-    /// Retrieves a list of trades from the Kalshi exchange based on specified criteria.
+    /// Retrieves every candle of a market between two timestamps, however long the range.
     ///
-    /// This method fetches multiple trades, allowing for filtering by ticker, time range,
-    /// and pagination. Trades represent executed orders between buyers and sellers.
+    /// The candlestick endpoint caps how many candles one response holds, so the range is
+    /// split into chunks of at most that many periods, fetched one after another. The
+    /// candles are returned in chronological order, with any candle returned by two
+    /// chunks (same `end_period_ts`) kept once.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - The market ticker.
+    /// * `series_ticker` - The series the market belongs to.
+    /// * `start_ts` - Only candles ending on or after this Unix timestamp.
+    /// * `end_ts` - Only candles ending on or before this Unix timestamp.
+    /// * `period_interval` - Candle length in minutes (1, 60 or 1440).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Candle>)`: Every candle in the range; empty if `start_ts > end_ts`.
+    /// - `Err(KalshiError::UserInputError)`: If `period_interval` isn't positive.
+    /// - `Err(KalshiError)`: The first error from any chunk's request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// // A year of daily candles.
+    /// let candles = kalshi_instance.get_candlesticks_range(
+    ///     "SOME-MARKET-2024", "SOME-SERIES", 1672531200, 1704067200, 1440
+    /// ).await.unwrap();
+    /// ```
+    ///
+    pub async fn get_candlesticks_range(
+        &self,
+        ticker: &str,
+        series_ticker: &str,
+        start_ts: i64,
+        end_ts: i64,
+        period_interval: i32,
+    ) -> Result<Vec<Candle>, KalshiError> {
+        if period_interval <= 0 {
+            return Err(KalshiError::UserInputError(format!(
+                "period_interval must be positive, got {}",
+                period_interval
+            )));
+        }
+        let chunk_secs = MAX_CANDLES_PER_REQUEST * i64::from(period_interval) * 60;
+
+        let mut candles: Vec<Candle> = Vec::new();
+        let mut chunk_start = start_ts;
+        while chunk_start <= end_ts {
+            let chunk_end = chunk_start.saturating_add(chunk_secs - 1).min(end_ts);
+            candles.extend(
+                self.get_market_candlesticks(
+                    ticker,
+                    series_ticker,
+                    Some(chunk_start),
+                    Some(chunk_end),
+                    Some(period_interval),
+                )
+                .await?,
+            );
+            if chunk_end == i64::MAX {
+                break;
+            }
+            chunk_start = chunk_end + 1;
+        }
+        candles.sort_by_key(|c| c.end_period_ts);
+        candles.dedup_by_key(|c| c.end_period_ts);
+        Ok(candles)
+    }
+
+    /// Retrieves candlestick data for a market, looking up its series ticker automatically.
+    ///
+    /// Identical to [`get_market_candlesticks`](Kalshi::get_market_candlesticks), except the
+    /// series is resolved from the market's event, which costs two extra requests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let candlesticks = kalshi_instance.get_market_candlesticks_by_ticker(
+    ///     "SOME-MARKET-2024", Some(1640995200), Some(1641081600), Some(60)
+    /// ).await.unwrap();
+    /// ```
+    ///
+    pub async fn get_market_candlesticks_by_ticker(
+        &self,
+        ticker: &str,
+        start_ts: Option<i64>,
+        end_ts: Option<i64>,
+        period_interval: Option<i32>,
+    ) -> Result<Vec<MarketCandlestick>, KalshiError> {
+        let series_ticker = self.resolve_series_ticker(ticker).await?;
+        self.get_market_candlesticks(ticker, &series_ticker, start_ts, end_ts, period_interval)
+            .await
+    }
+
+    /// Looks up the series a market belongs to via its event.
+    async fn resolve_series_ticker(&self, ticker: &str) -> Result<String, KalshiError> {
+        let market = self.get_market(ticker).await?;
+        let event = self.get_event(&market.event_ticker).await?;
+        Ok(event.series_ticker)
+    }
+
+    /// Retrieves a list of trades from the Kalshi exchange based on specified criteria.
+    ///
+    /// This method fetches multiple trades, allowing for filtering by ticker, time range,
+    /// and pagination. Trades represent executed orders between buyers and sellers.
     ///
     /// # Arguments
     ///
@@ -201,7 +1071,8 @@ impl<'a> Kalshi {
     ///
     /// - `Ok((Option<String>, Vec<Trade>))`: A tuple containing an optional pagination cursor
     ///   and a vector of `Trade` objects on successful retrieval.
-    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    /// - `Err(KalshiError)`: `InvalidCursor` if the API rejects `cursor`, or an error if
+    ///   there is an issue with the request.
     ///
     /// # Example
     ///
@@ -226,13 +1097,187 @@ impl<'a> Kalshi {
         add_param!(p, "min_ts", min_ts);
         add_param!(p, "max_ts", max_ts);
 
-        let res: GetTradesResponse = self.client
-            .get(reqwest::Url::parse_with_params(&url, &p)?)
-            .send().await?.json().await?;
+        let res: GetTradesResponse = self
+            .send_json_with_cursor(
+                self.client.get(reqwest::Url::parse_with_params(&url, &p)?),
+                cursor.as_deref(),
+            )
+            .await?;
         let cursor = if res.cursor.is_empty() { None } else { Some(res.cursor) };
         Ok((cursor, res.trades))
     }
 
+    /// Retrieves every trade whose taker (aggressor) was on `taker_side`.
+    ///
+    /// `/markets/trades` has no server-side taker filter, so this walks every page of
+    /// the endpoint for the given ticker and time range and drops the other side's
+    /// trades client-side. Narrow the range with `min_ts`/`max_ts` on busy markets, since
+    /// the whole tape for the range is downloaded either way.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - An optional string to filter trades by market ticker.
+    /// * `min_ts` - An optional minimum timestamp for trade creation time.
+    /// * `max_ts` - An optional maximum timestamp for trade creation time.
+    /// * `taker_side` - Which side's aggressor trades to keep.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Trade>)`: The matching trades, in the order the API returned them.
+    /// - `Err(KalshiError)`: An error if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let yes_lifts = kalshi_instance
+    ///     .get_trades_by_taker_side(
+    ///         Some("SOME-MARKET-2024".to_string()), Some(1640995200), None, TradeTakerSide::Yes,
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// ```
+    ///
+    pub async fn get_trades_by_taker_side(
+        &self,
+        ticker: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+        taker_side: TradeTakerSide,
+    ) -> Result<Vec<Trade>, KalshiError> {
+        self.collect_trades(ticker, min_ts, max_ts, None, |t| t.taker_side == taker_side)
+            .await
+    }
+
+    /// Retrieves the full trade history of a market within a time range.
+    ///
+    /// Walks every page of `/markets/trades` for `ticker`, so callers don't have to
+    /// thread the cursor themselves. A wide range on a busy market can hold millions of
+    /// trades; `max_trades` guards against pulling those by accident.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - The market ticker to fetch trades for.
+    /// * `min_ts` - An optional minimum timestamp for trade creation time.
+    /// * `max_ts` - An optional maximum timestamp for trade creation time.
+    /// * `max_trades` - Give up once more than this many trades have been fetched.
+    ///   `None` fetches everything.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Trade>)`: Every trade in the range, ordered oldest first by `created_time`.
+    /// - `Err(KalshiError)`: A `UserInputError` if the range holds more than `max_trades`
+    ///   trades, or another error if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let history = kalshi_instance
+    ///     .get_all_trades("SOME-MARKET-2024", Some(1640995200), Some(1641081600), Some(100_000))
+    ///     .await
+    ///     .unwrap();
+    /// ```
+    ///
+    pub async fn get_all_trades(
+        &self,
+        ticker: &str,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+        max_trades: Option<usize>,
+    ) -> Result<Vec<Trade>, KalshiError> {
+        let mut trades = self
+            .collect_trades(Some(ticker.to_string()), min_ts, max_ts, max_trades, |_| true)
+            .await?;
+        // Pages come back newest first; a stable sort keeps same-timestamp trades in API order.
+        trades.reverse();
+        trades.sort_by_key(|t| t.created_time);
+        Ok(trades)
+    }
+
+    /// Walks every page of `/markets/trades` and keeps the trades accepted by `keep`,
+    /// failing once more than `max_trades` have been kept.
+    async fn collect_trades(
+        &self,
+        ticker: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+        max_trades: Option<usize>,
+        keep: impl Fn(&Trade) -> bool,
+    ) -> Result<Vec<Trade>, KalshiError> {
+        let mut kept = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let (next, trades) = self
+                .get_trades(Some(TRADES_PAGE_LIMIT), cursor.clone(), ticker.clone(), min_ts, max_ts)
+                .await?;
+            kept.extend(trades.into_iter().filter(|t| keep(t)));
+            if let Some(max) = max_trades.filter(|max| kept.len() > *max) {
+                return Err(KalshiError::UserInputError(format!(
+                    "more than {} trades in range; narrow min_ts/max_ts or raise max_trades",
+                    max
+                )));
+            }
+            cursor = next_cursor(cursor.as_deref(), next)?;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Fetches the trades in a market that happened after a previously seen trade.
+    ///
+    /// Intended for polling a live tape without WebSockets: pass the `trade_id` of the
+    /// newest trade from the previous call and only newer trades are returned. The most
+    /// recent 1000 trades are requested in a single call.
+    ///
+    /// If `since_trade_id` is not among them, more trades happened between polls than
+    /// one request can return. In that case every fetched trade is returned and
+    /// [`NewTrades::gap`] is set, since some trades were missed. Passing `None` returns
+    /// the most recent trades without flagging a gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - The market ticker to fetch trades for.
+    /// * `since_trade_id` - The `trade_id` of the last trade already seen, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(NewTrades)`: The new trades ordered oldest first (newest last), plus the gap flag.
+    /// - `Err(KalshiError)`: An error if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let mut last_seen: Option<String> = None;
+    /// loop {
+    ///     let update = kalshi_instance
+    ///         .get_new_trades("SOME-MARKET-2024", last_seen.as_deref())
+    ///         .await
+    ///         .unwrap();
+    ///     if update.gap {
+    ///         eprintln!("tape gap: some trades were missed");
+    ///     }
+    ///     if let Some(newest) = update.trades.last() {
+    ///         last_seen = Some(newest.trade_id.clone());
+    ///     }
+    ///     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+    /// }
+    /// ```
+    ///
+    pub async fn get_new_trades(
+        &self,
+        ticker: &str,
+        since_trade_id: Option<&str>,
+    ) -> Result<NewTrades, KalshiError> {
+        let (_, trades) = self
+            .get_trades(Some(NEW_TRADES_LIMIT), None, Some(ticker.to_string()), None, None)
+            .await?;
+        Ok(NewTrades::after(trades, since_trade_id))
+    }
+
     /// Retrieves a list of series from the Kalshi exchange based on specified criteria.
     ///
     /// This method fetches multiple series, allowing for filtering by category, tags,
@@ -286,7 +1331,7 @@ impl<'a> Kalshi {
             for series in arr.iter_mut() {
                 if let Some(obj) = series.as_object_mut() {
                     for key in ["additional_prohibitions", "settlement_sources", "tags"] {
-                        if obj.get(key).map_or(false, |v| v.is_null()) {
+                        if obj.get(key).is_some_and(|v| v.is_null()) {
                             obj.insert(key.to_string(), serde_json::Value::Array(vec![]));
                         }
                     }
@@ -325,12 +1370,115 @@ impl<'a> Kalshi {
     /// let series = kalshi_instance.get_series(series_ticker).await.unwrap();
     /// ```
     ///
+    /// With the `cache` feature enabled, repeated calls revalidate with `If-None-Match`
+    /// and reuse the cached series when the server answers `304 Not Modified`.
+    ///
     pub async fn get_series(&self, series_ticker: &str) -> Result<Series, KalshiError> {
         let url = format!("{}/series/{}", self.base_url, series_ticker);
-        let res: GetSeriesResponse = self.client.get(url).send().await?.json().await?;
+        #[cfg(feature = "cache")]
+        let res: GetSeriesResponse = self.etag_get(self.client.get(url)).await?;
+        #[cfg(not(feature = "cache"))]
+        let res: GetSeriesResponse = self.send_json(self.client.get(url)).await?;
         Ok(res.series)
     }
 
+    /// Downloads an arbitrary resource and returns its raw body.
+    ///
+    /// Meant for non-JSON documents referenced by the API, such as the contract terms
+    /// PDF at [`Series::contract_url`]. URLs under this client's API base URL are sent
+    /// with the usual authentication headers; any other URL is fetched anonymously so
+    /// credentials never leak to third-party hosts.
+    ///
+    /// Only `http` and `https` URLs are accepted. The body counts against the limit
+    /// set with [`set_max_response_bytes`](Kalshi::set_max_response_bytes).
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The absolute URL to fetch.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<u8>)`: The response body.
+    /// - `Err(KalshiError)`: A `UserInputError` for an invalid URL, `ResponseTooLarge` for an
+    ///   oversized body, or an error if the request fails or returns a non-success status.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let series = kalshi_instance.get_series("KXHIGHNY").await.unwrap();
+    /// let pdf = kalshi_instance.get_bytes(&series.contract_url).await.unwrap();
+    /// std::fs::write("contract.pdf", pdf).unwrap();
+    /// ```
+    ///
+    pub async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, KalshiError> {
+        let parsed = reqwest::Url::parse(url)?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(KalshiError::UserInputError(format!(
+                "unsupported URL scheme '{}', expected http or https",
+                parsed.scheme()
+            )));
+        }
+
+        let builder = match url.strip_prefix(self.base_url.as_str()) {
+            Some(path) if path.is_empty() || path.starts_with('/') => self.signed_builder("GET", path)?,
+            _ => self.client.get(parsed),
+        };
+        let resp = self.check_status(self.send(builder).await?).await?;
+        self.read_body(resp).await
+    }
+
+    /// Downloads the contract document of a series.
+    ///
+    /// Looks up the series, then fetches the document at its [`Series::contract_url`]
+    /// through [`get_bytes`](Kalshi::get_bytes). Only `https` URLs are followed, including
+    /// redirects: a redirect to a plain `http` URL is refused rather than downgraded. The
+    /// download is capped at 16 MiB, or the limit set with
+    /// [`set_max_response_bytes`](Kalshi::set_max_response_bytes) if that is lower.
+    ///
+    /// # Arguments
+    ///
+    /// * `series_ticker` - The ticker of the series whose contract to download.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<u8>)`: The raw document, usually a PDF.
+    /// - `Err(KalshiError)`: A `UserInputError` if the series has no contract URL or it
+    ///   isn't `https`, a `RequestError` if the download redirects away from `https`,
+    ///   `ResponseTooLarge` if the document exceeds the cap, or an error if either request
+    ///   fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let contract = kalshi_instance.get_series_contract("KXHIGHNY").await.unwrap();
+    /// std::fs::write("KXHIGHNY-contract.pdf", contract).unwrap();
+    /// ```
+    ///
+    pub async fn get_series_contract(&self, series_ticker: &str) -> Result<Vec<u8>, KalshiError> {
+        let series = self.get_series(series_ticker).await?;
+        if series.contract_url.is_empty() {
+            return Err(KalshiError::UserInputError(format!(
+                "series {} has no contract URL",
+                series_ticker
+            )));
+        }
+        let url = reqwest::Url::parse(&series.contract_url)?;
+        if url.scheme() != "https" {
+            return Err(KalshiError::UserInputError(format!(
+                "refusing to download contract over '{}', expected https",
+                url.scheme()
+            )));
+        }
+        let capped = Kalshi {
+            max_response_bytes: self.max_response_bytes.min(CONTRACT_MAX_BYTES),
+            client: self.https_client.clone(),
+            ..self.clone()
+        };
+        capped.get_bytes(url.as_str()).await
+    }
+
     /// Retrieves orderbooks for multiple markets in a single request.
     ///
     /// Returns a list of `(ticker, orderbook)` pairs. The orderbook format is the
@@ -373,5 +1521,2592 @@ impl<'a> Kalshi {
     }
 }
 
+// -------- Response wrappers --------
+
+/// `/markets` with each market left as raw JSON, for [`Kalshi::get_markets_lenient`].
+#[derive(Debug, serde::Deserialize)]
+struct RawMarketsResponse {
+    cursor: Option<String>,
+    markets: Vec<serde_json::Value>,
+}
+
+// -------- Market helpers --------
+
+/// A cached market and when it was fetched; empty while the first fetch is in flight.
+type MarketSlot = Arc<OnceCell<(Instant, Market)>>;
+
+/// Per-ticker slots backing [`Kalshi::set_market_cache_ttl`].
+///
+/// A slot is filled once by whichever caller gets there first; everyone else awaiting
+/// the same slot receives that result. Slots older than `ttl` are replaced.
+#[derive(Debug)]
+pub(crate) struct MarketCache {
+    ttl: Duration,
+    slots: Mutex<HashMap<String, MarketSlot>>,
+}
+
+impl MarketCache {
+    pub(crate) fn new(ttl: Duration) -> MarketCache {
+        MarketCache { ttl, slots: Mutex::new(HashMap::new()) }
+    }
+
+    /// The current slot for `ticker`, replacing it if its market has expired.
+    fn slot(&self, ticker: &str) -> MarketSlot {
+        let fresh = |cell: &OnceCell<(Instant, Market)>| cell.get().is_none_or(|(at, _)| at.elapsed() < self.ttl);
+        let mut slots = self.slots.lock().unwrap();
+        if !slots.contains_key(ticker) {
+            slots.retain(|_, cell| fresh(cell));
+        }
+        let slot = slots.entry(ticker.to_string()).or_default();
+        if !fresh(slot) {
+            *slot = Arc::default();
+        }
+        slot.clone()
+    }
+}
+
+/// Thresholds used by [`Market::is_tradeable_with`].
+///
+/// The [`Default`] value accepts any market with non-zero liquidity and a
+/// two-sided yes book, regardless of how wide the spread is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeableParams {
+    /// Liquidity (in dollars) the market must exceed. Defaults to `0.0`.
+    pub min_liquidity_dollars: f64,
+    /// Widest acceptable yes bid/ask spread in dollars. `None` disables the check.
+    pub max_spread_dollars: Option<f64>,
+}
+
+impl Default for TradeableParams {
+    fn default() -> Self {
+        TradeableParams {
+            min_liquidity_dollars: 0.0,
+            max_spread_dollars: None,
+        }
+    }
+}
+
+impl MarketStatus {
+    /// Returns `true` if a market may legally move from this status to `next`.
+    ///
+    /// The lifecycle only moves forward. The allowed transitions are:
+    ///
+    /// * `initialized` → `active` or `inactive`
+    /// * `active` ↔ `inactive` (trading paused and resumed), and either → `closed`
+    /// * `closed` → `determined`
+    /// * `determined` → `disputed` or `finalized`
+    /// * `disputed` → `determined` (result upheld) or `amended` (result changed)
+    /// * `amended` → `disputed` or `finalized`
+    /// * `finalized` is terminal
+    ///
+    /// Staying in the same status is always allowed. Any transition to or from an
+    /// [`Unknown`](MarketStatus::Unknown) status returns `false`, so monitors flag it
+    /// for inspection rather than silently accepting it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// if !previous.status.can_transition_to(&market.status) {
+    ///     eprintln!("unexpected status change on {}: {} -> {}", market.ticker, previous.status, market.status);
+    /// }
+    /// ```
+    ///
+    pub fn can_transition_to(&self, next: &MarketStatus) -> bool {
+        use MarketStatus::*;
+        match (self, next) {
+            (Unknown(_), _) | (_, Unknown(_)) => false,
+            (current, next) if current == next => true,
+            (Initialized, Active | Inactive)
+            | (Active, Inactive | Closed)
+            | (Inactive, Active | Closed)
+            | (Closed, Determined)
+            | (Determined, Disputed | Finalized)
+            | (Disputed, Determined | Amended)
+            | (Amended, Disputed | Finalized) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Market {
+    /// Fetches the market with `ticker`; shorthand for [`Kalshi::get_market`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = Market::load(&kalshi_instance, "SOME-MARKET-2024").await.unwrap();
+    /// ```
+    ///
+    pub async fn load(kalshi: &Kalshi, ticker: &str) -> Result<Market, KalshiError> {
+        kalshi.get_market(ticker).await
+    }
+
+    /// Parses a market from JSON in either the wrapped or the bare shape.
+    ///
+    /// `GET /markets/{ticker}` wraps the market as `{"market": {...}}`, while lists,
+    /// event payloads and logged data carry the market object directly. Both are
+    /// accepted, so JSON from any of those sources can be parsed the same way.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Market)`: The parsed market.
+    /// - `Err(KalshiError)`: A `UserInputError` if the value is neither shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let line = std::fs::read_to_string("market.json").unwrap();
+    /// let market = Market::from_response_value(serde_json::from_str(&line).unwrap()).unwrap();
+    /// ```
+    ///
+    pub fn from_response_value(value: serde_json::Value) -> Result<Market, KalshiError> {
+        let value = match value {
+            serde_json::Value::Object(mut fields) if fields.len() == 1 && fields.contains_key("market") => {
+                fields.remove("market").unwrap_or_default()
+            }
+            other => other,
+        };
+        serde_json::from_value(value)
+            .map_err(|e| KalshiError::UserInputError(format!("invalid market JSON: {}", e)))
+    }
+
+    /// Returns `true` if an order could reasonably be placed on this market at `now`.
+    ///
+    /// Equivalent to [`is_tradeable_with`](Market::is_tradeable_with) using
+    /// [`TradeableParams::default`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// if market.is_tradeable(chrono::Utc::now()) {
+    ///     // place the order
+    /// }
+    /// ```
+    ///
+    pub fn is_tradeable(&self, now: DateTime<Utc>) -> bool {
+        self.is_tradeable_with(now, &TradeableParams::default())
+    }
+
+    /// Returns `true` only when every one of the following holds:
+    ///
+    /// * `status` is [`MarketStatus::Active`] (the API's "open" state).
+    /// * `now` is strictly before `close_time`.
+    /// * `liquidity_dollars` is greater than `params.min_liquidity_dollars`.
+    /// * Both a yes bid and a yes ask are present (non-zero) and the bid is below the ask.
+    /// * If `params.max_spread_dollars` is set, the yes spread does not exceed it.
+    ///
+    /// Unparseable price fields are treated as absent, so they fail the check.
+    pub fn is_tradeable_with(&self, now: DateTime<Utc>, params: &TradeableParams) -> bool {
+        if self.status != MarketStatus::Active || now >= self.close_time {
+            return false;
+        }
+        let liquidity = self.liquidity_dollars.to_f64().unwrap_or(0.0);
+        if liquidity <= params.min_liquidity_dollars {
+            return false;
+        }
+        let (bid, ask) = match (self.yes_bid_dollars.to_f64(), self.yes_ask_dollars.to_f64()) {
+            (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 && bid < ask => (bid, ask),
+            _ => return false,
+        };
+        match params.max_spread_dollars {
+            Some(max_spread) => ask - bid <= max_spread,
+            None => true,
+        }
+    }
+
+    /// Midpoint of the yes bid and ask in dollars, i.e. the market's implied probability.
+    ///
+    /// Returns `None` unless both quotes are present (non-zero) and the bid doesn't exceed
+    /// the ask.
+    pub fn yes_mid_dollars(&self) -> Option<f64> {
+        match (self.yes_bid_dollars.to_f64(), self.yes_ask_dollars.to_f64()) {
+            (Some(bid), Some(ask)) if bid > 0.0 && ask > 0.0 && bid <= ask => Some((bid + ask) / 2.0),
+            _ => None,
+        }
+    }
+
+    /// The cheapest way to get long `target`: buying it, or selling its complement.
+    ///
+    /// Long yes can be entered by buying yes at the yes ask, or by selling no at the no
+    /// bid, which costs `1 - no_bid` per contract; long no likewise by buying no or selling
+    /// yes. The two quotes usually mirror each other, but the market snapshot's fields
+    /// can disagree, so both routes are priced and the cheaper one is returned. Ties go
+    /// to buying `target`.
+    ///
+    /// Returns `None` if `target` is [`OrderSide::Unknown`] or neither route is quoted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// if let Some(plan) = market.best_entry_for(OrderSide::Yes) {
+    ///     println!("{} {} at {:.2}, yes exposure costs {:.2}", plan.action, plan.side, plan.price_dollars, plan.effective_cost_dollars);
+    /// }
+    /// ```
+    ///
+    pub fn best_entry_for(&self, target: OrderSide) -> Option<EntryPlan> {
+        let (ask, complement_bid, complement) = match target {
+            OrderSide::Yes => (&self.yes_ask_dollars, &self.no_bid_dollars, OrderSide::No),
+            OrderSide::No => (&self.no_ask_dollars, &self.yes_bid_dollars, OrderSide::Yes),
+            OrderSide::Unknown(_) => return None,
+        };
+        let quoted = |price: &FixedPointDollars| price.to_f64().filter(|p| *p > 0.0 && *p < 1.0);
+
+        let buy = quoted(ask).map(|price| EntryPlan {
+            action: OrderAction::Buy,
+            side: target,
+            price_dollars: price,
+            effective_cost_dollars: price,
+        });
+        let sell = quoted(complement_bid).map(|price| EntryPlan {
+            action: OrderAction::Sell,
+            side: complement,
+            price_dollars: price,
+            effective_cost_dollars: self::complement(price),
+        });
+        match (buy, sell) {
+            (Some(buy), Some(sell)) if sell.effective_cost_dollars < buy.effective_cost_dollars => Some(sell),
+            (Some(buy), _) => Some(buy),
+            (None, sell) => sell,
+        }
+    }
+
+    /// Edge of `model` over the market: its fair yes probability minus the market's mid.
+    ///
+    /// Positive values mean the model thinks yes is underpriced. Returns `None` if the
+    /// model has no estimate for this market or the market has no two-sided quote (see
+    /// [`yes_mid_dollars`](Market::yes_mid_dollars)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// struct Coinflip;
+    /// impl FairValueModel for Coinflip {
+    ///     fn fair_yes_prob(&self, _: &Market) -> Option<f64> { Some(0.5) }
+    /// }
+    ///
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// if market.edge_vs(&Coinflip).is_some_and(|edge| edge > 0.05) {
+    ///     // buy yes
+    /// }
+    /// ```
+    ///
+    pub fn edge_vs(&self, model: &impl FairValueModel) -> Option<f64> {
+        Some(model.fair_yes_prob(self)? - self.yes_mid_dollars()?)
+    }
 
+    /// Per-contract notional value in cents, parsed from `notional_value_dollars`.
+    ///
+    /// This is what one contract pays out if it settles in the money (100 cents for
+    /// a standard binary market). Returns 0 if the field can't be parsed.
+    pub fn notional_cents(&self) -> i64 {
+        self.notional_value_dollars
+            .to_f64()
+            .map(|dollars| (dollars * 100.0).round() as i64)
+            .unwrap_or(0)
+    }
 
+    /// Total notional exposure of `count` contracts, in cents.
+    ///
+    /// Computed as `count * notional_cents()`, where the notional is per contract.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// // 25 contracts of a $1 market => 2500 cents of exposure
+    /// let exposure = market.position_notional(25);
+    /// ```
+    ///
+    pub fn position_notional(&self, count: i32) -> i64 {
+        i64::from(count) * self.notional_cents()
+    }
+
+    /// Worst-case loss, in cents, of buying `count` contracts on `side`.
+    ///
+    /// `yes_price` is the yes price of the order in cents. Buying yes risks the
+    /// premium paid, `yes_price * count`. Buying no costs the complement, so it
+    /// risks `(notional_cents() - yes_price) * count`. For an
+    /// [`OrderSide::Unknown`] side the larger of the two is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// // Buying 10 no contracts while yes trades at 30 cents risks 10 * 70 = 700 cents
+    /// let risk = market.max_loss(OrderSide::No, 30, 10);
+    /// ```
+    ///
+    pub fn max_loss(&self, side: OrderSide, yes_price: i64, count: i32) -> i64 {
+        let yes_loss = yes_price * i64::from(count);
+        let no_loss = (self.notional_cents() - yes_price) * i64::from(count);
+        match side {
+            OrderSide::Yes => yes_loss,
+            OrderSide::No => no_loss,
+            OrderSide::Unknown(_) => yes_loss.max(no_loss),
+        }
+    }
+
+    /// Every price, in cents, at which an order can be placed on this market, ascending.
+    ///
+    /// When `price_ranges` is populated and every range steps in whole cents, the ranges
+    /// are enumerated. Otherwise the deprecated `tick_size` is used, yielding each multiple
+    /// of it. A missing, zero or out-of-range tick size falls back to 1 cent. In both
+    /// cases prices are kept strictly between 0 and 100 cents, the bounds of a contract.
+    ///
+    /// Markets with sub-cent ranges (e.g. `0.001` steps near the tails) can't be
+    /// represented in whole cents and fall back to `tick_size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// let ladder: Vec<i32> = market.valid_prices().collect();
+    /// ```
+    ///
+    pub fn valid_prices(&self) -> impl Iterator<Item = i32> {
+        let mut prices: Vec<i32> = match self.price_ranges_cents() {
+            Some(ranges) => ranges
+                .into_iter()
+                .flat_map(|(start, end, step)| (start..=end).step_by(step as usize))
+                .collect(),
+            None => {
+                let tick = self.tick_size.filter(|t| (1..100).contains(t)).unwrap_or(1) as i32;
+                (tick..100).step_by(tick as usize).collect()
+            }
+        };
+        prices.retain(|p| (1..100).contains(p));
+        prices.sort_unstable();
+        prices.dedup();
+        prices.into_iter()
+    }
+
+    /// `price_ranges` as `(start, end, step)` in cents, or `None` if there are none or any
+    /// range doesn't parse or step in whole cents.
+    fn price_ranges_cents(&self) -> Option<Vec<(i32, i32, i32)>> {
+        let cents = |dollars: &str| -> Option<i32> {
+            let cents = dollars.trim().parse::<f64>().ok()? * 100.0;
+            ((cents - cents.round()).abs() < 1e-6).then_some(cents.round() as i32)
+        };
+        if self.price_ranges.is_empty() {
+            return None;
+        }
+        self.price_ranges
+            .iter()
+            .map(|r| Some((cents(&r.start)?, cents(&r.end)?, cents(&r.step).filter(|s| *s > 0)?)))
+            .collect()
+    }
+
+    /// Interprets `result` in light of the market's type and strikes.
+    ///
+    /// A bare "yes" only means something together with what yes was a bet on. For binary
+    /// markets the strike fields are turned into a [`StrikeCondition`] (e.g. "in [10, 20]"
+    /// for a range market), so the outcome says whether the condition held. Scalar markets
+    /// report their settlement value instead. The `Display` impl of the returned value
+    /// renders a one-line description suitable for settlement reports.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// println!("{}: {}", market.ticker, market.interpret_result());
+    /// // e.g. "SOME-MARKET-2024: Yes: value was in [70, 74] (settled on 72.5)"
+    /// ```
+    ///
+    pub fn interpret_result(&self) -> ResolvedOutcome {
+        let value = Some(self.expiration_value.trim().to_string()).filter(|v| !v.is_empty());
+        match (&self.result, &self.market_type) {
+            (MarketResult::Undetermined, _) => ResolvedOutcome::Pending,
+            (MarketResult::Scalar, _) | (_, MarketMarketType::Scalar) => ResolvedOutcome::Scalar {
+                value,
+                payout_dollars: self.settlement_value_dollars.as_ref().and_then(|p| p.to_f64()),
+            },
+            (MarketResult::Yes | MarketResult::No, _) => ResolvedOutcome::Binary {
+                yes: self.result == MarketResult::Yes,
+                condition: self.strike_condition(),
+                value,
+            },
+            (MarketResult::Unknown(raw), _) => ResolvedOutcome::Unknown(raw.clone()),
+        }
+    }
+
+    /// What a yes contract pays out on, from `strike_type` and the strike fields.
+    ///
+    /// Returns `None` if the strike type is missing or the strikes it needs are absent.
+    pub fn strike_condition(&self) -> Option<StrikeCondition> {
+        let described = || {
+            self.functional_strike
+                .clone()
+                .or_else(|| Some(self.yes_sub_title.clone()))
+                .filter(|s| !s.trim().is_empty())
+                .map(StrikeCondition::Other)
+        };
+        match self.strike_type.as_ref()? {
+            MarketStrikeType::Greater => Some(StrikeCondition::Above { strike: self.floor_strike?, inclusive: false }),
+            MarketStrikeType::GreaterOrEqual => {
+                Some(StrikeCondition::Above { strike: self.floor_strike?, inclusive: true })
+            }
+            MarketStrikeType::Less => Some(StrikeCondition::Below { strike: self.cap_strike?, inclusive: false }),
+            MarketStrikeType::LessOrEqual => Some(StrikeCondition::Below { strike: self.cap_strike?, inclusive: true }),
+            MarketStrikeType::Between => {
+                Some(StrikeCondition::Between { floor: self.floor_strike?, cap: self.cap_strike? })
+            }
+            _ => described(),
+        }
+    }
+}
+
+/// How to enter a position, returned by [`Market::best_entry_for`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryPlan {
+    /// Whether to buy the target side or sell its complement.
+    pub action: OrderAction,
+    /// The side the order is placed on.
+    pub side: OrderSide,
+    /// Limit price of the order on `side`, in dollars.
+    pub price_dollars: f64,
+    /// What one contract of exposure to the target side costs this way, in dollars.
+    pub effective_cost_dollars: f64,
+}
+
+/// The condition a yes contract pays out on. See [`Market::strike_condition`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StrikeCondition {
+    /// The value ends above `strike` (or at it, if `inclusive`).
+    Above { strike: f64, inclusive: bool },
+    /// The value ends below `strike` (or at it, if `inclusive`).
+    Below { strike: f64, inclusive: bool },
+    /// The value ends in `[floor, cap]`.
+    Between { floor: f64, cap: f64 },
+    /// A functional, custom or structured strike, described in words.
+    Other(String),
+}
+
+impl std::fmt::Display for StrikeCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrikeCondition::Above { strike, inclusive: false } => write!(f, "above {}", strike),
+            StrikeCondition::Above { strike, inclusive: true } => write!(f, "at or above {}", strike),
+            StrikeCondition::Below { strike, inclusive: false } => write!(f, "below {}", strike),
+            StrikeCondition::Below { strike, inclusive: true } => write!(f, "at or below {}", strike),
+            StrikeCondition::Between { floor, cap } => write!(f, "in [{}, {}]", floor, cap),
+            StrikeCondition::Other(text) => f.write_str(text),
+        }
+    }
+}
+
+/// What a market's result means, returned by [`Market::interpret_result`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedOutcome {
+    /// The market has not resolved yet.
+    Pending,
+    /// A binary market resolved yes (`yes == true`) or no.
+    Binary {
+        /// Whether the market resolved yes.
+        yes: bool,
+        /// What yes was a bet on, if the strike fields describe it.
+        condition: Option<StrikeCondition>,
+        /// The observed value the market settled on (`expiration_value`), if reported.
+        value: Option<String>,
+    },
+    /// A scalar market settled on a value.
+    Scalar {
+        /// The observed value the market settled on (`expiration_value`), if reported.
+        value: Option<String>,
+        /// Payout per yes contract in dollars (`settlement_value_dollars`), if reported.
+        payout_dollars: Option<f64>,
+    },
+    /// A result value not known to this version of the crate, kept verbatim.
+    Unknown(String),
+}
+
+impl std::fmt::Display for ResolvedOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = |v: &Option<String>| v.as_ref().map(|v| format!(" (settled on {})", v)).unwrap_or_default();
+        match self {
+            ResolvedOutcome::Pending => f.write_str("not yet resolved"),
+            ResolvedOutcome::Binary { yes, condition: None, value: v } => {
+                write!(f, "{}{}", if *yes { "Yes" } else { "No" }, value(v))
+            }
+            ResolvedOutcome::Binary { yes: true, condition: Some(c), value: v } => {
+                write!(f, "Yes: value was {}{}", c, value(v))
+            }
+            ResolvedOutcome::Binary { yes: false, condition: Some(c), value: v } => {
+                write!(f, "No: value was not {}{}", c, value(v))
+            }
+            ResolvedOutcome::Scalar { value: v, payout_dollars: Some(p) } => {
+                write!(f, "Scalar: ${:.4} per yes contract{}", p, value(v))
+            }
+            ResolvedOutcome::Scalar { value: v, payout_dollars: None } => write!(f, "Scalar{}", value(v)),
+            ResolvedOutcome::Unknown(raw) => write!(f, "unknown result {:?}", raw),
+        }
+    }
+}
+
+// -------- Stable schema --------
+
+/// Version of the JSON written by [`Market::to_stable_json`].
+///
+/// Bumped whenever a field of the stable schema is removed, renamed or changes meaning.
+/// Adding an optional field doesn't bump it.
+pub const MARKET_STABLE_SCHEMA_VERSION: u32 = 1;
+
+/// Version 1 of the stable market schema. Field names and formats here are frozen;
+/// see [`Market::to_stable_json`].
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct StableMarketV1 {
+    schema_version: u32,
+    ticker: String,
+    event_ticker: String,
+    market_type: String,
+    title: Option<String>,
+    subtitle: Option<String>,
+    yes_sub_title: String,
+    no_sub_title: String,
+    rules_primary: String,
+    rules_secondary: String,
+    status: String,
+    result: String,
+    can_close_early: bool,
+    strike_type: Option<String>,
+    floor_strike: Option<f64>,
+    cap_strike: Option<f64>,
+    /// Added after version 1 shipped, so older documents may lack it.
+    #[serde(default)]
+    created_time: Option<DateTime<Utc>>,
+    open_time: DateTime<Utc>,
+    close_time: DateTime<Utc>,
+    expiration_time: Option<DateTime<Utc>>,
+    latest_expiration_time: DateTime<Utc>,
+    settlement_ts: Option<DateTime<Utc>>,
+    updated_time: DateTime<Utc>,
+    yes_bid_dollars: String,
+    yes_ask_dollars: String,
+    no_bid_dollars: String,
+    no_ask_dollars: String,
+    last_price_dollars: String,
+    notional_value_dollars: String,
+    liquidity_dollars: String,
+    settlement_value_dollars: Option<String>,
+    volume_fp: String,
+    volume_24h_fp: String,
+    open_interest_fp: String,
+}
+
+impl Market {
+    /// Serializes the market to a versioned JSON schema that stays the same across
+    /// releases of this crate, for storing markets in a database or cache.
+    ///
+    /// [`Market`] is generated from the exchange's OpenAPI spec, so its fields change
+    /// whenever the spec does. The stable schema is a fixed subset of them:
+    ///
+    /// - `schema_version`: [`MARKET_STABLE_SCHEMA_VERSION`], currently `1`.
+    /// - Identity and text: `ticker`, `event_ticker`, `market_type`, `title`, `subtitle`,
+    ///   `yes_sub_title`, `no_sub_title`, `rules_primary`, `rules_secondary`.
+    /// - Lifecycle: `status`, `result`, `can_close_early`, and the RFC 3339 times
+    ///   `created_time`, `open_time`, `close_time`, `expiration_time`,
+    ///   `latest_expiration_time`, `settlement_ts` and `updated_time`.
+    /// - Strike: `strike_type`, `floor_strike`, `cap_strike`.
+    /// - Prices in dollars as decimal strings: `yes_bid_dollars`, `yes_ask_dollars`,
+    ///   `no_bid_dollars`, `no_ask_dollars`, `last_price_dollars`,
+    ///   `notional_value_dollars`, `liquidity_dollars`, `settlement_value_dollars`.
+    /// - Contract counts as decimal strings: `volume_fp`, `volume_24h_fp`, `open_interest_fp`.
+    ///
+    /// Enums are written as the API's strings, and optional fields as `null`. Other fields
+    /// of [`Market`] are not stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let market = kalshi_instance.get_market("SOME-MARKET-2024").await.unwrap();
+    /// let stored = market.to_stable_json().to_string();
+    /// // Later, possibly with a newer version of the crate:
+    /// let market = kalshi::Market::from_stable_json(serde_json::from_str(&stored).unwrap()).unwrap();
+    /// ```
+    ///
+    pub fn to_stable_json(&self) -> serde_json::Value {
+        let stable = StableMarketV1 {
+            schema_version: MARKET_STABLE_SCHEMA_VERSION,
+            ticker: self.ticker.clone(),
+            event_ticker: self.event_ticker.clone(),
+            market_type: self.market_type.to_string(),
+            title: self.title.clone(),
+            subtitle: self.subtitle.clone(),
+            yes_sub_title: self.yes_sub_title.clone(),
+            no_sub_title: self.no_sub_title.clone(),
+            rules_primary: self.rules_primary.clone(),
+            rules_secondary: self.rules_secondary.clone(),
+            status: self.status.to_string(),
+            result: self.result.to_string(),
+            can_close_early: self.can_close_early,
+            strike_type: self.strike_type.as_ref().map(|s| s.to_string()),
+            floor_strike: self.floor_strike,
+            cap_strike: self.cap_strike,
+            created_time: Some(self.created_time),
+            open_time: self.open_time,
+            close_time: self.close_time,
+            expiration_time: self.expiration_time,
+            latest_expiration_time: self.latest_expiration_time,
+            settlement_ts: self.settlement_ts,
+            updated_time: self.updated_time,
+            yes_bid_dollars: self.yes_bid_dollars.0.clone(),
+            yes_ask_dollars: self.yes_ask_dollars.0.clone(),
+            no_bid_dollars: self.no_bid_dollars.0.clone(),
+            no_ask_dollars: self.no_ask_dollars.0.clone(),
+            last_price_dollars: self.last_price_dollars.0.clone(),
+            notional_value_dollars: self.notional_value_dollars.0.clone(),
+            liquidity_dollars: self.liquidity_dollars.0.clone(),
+            settlement_value_dollars: self.settlement_value_dollars.as_ref().map(|v| v.0.clone()),
+            volume_fp: self.volume_fp.0.clone(),
+            volume_24h_fp: self.volume_24h_fp.0.clone(),
+            open_interest_fp: self.open_interest_fp.0.clone(),
+        };
+        serde_json::to_value(stable).expect("the stable market schema always serializes")
+    }
+
+    /// Reads a market written by [`to_stable_json`](Market::to_stable_json).
+    ///
+    /// Fields outside the stable schema are placeholders, not the market's real values:
+    ///
+    /// - `None`: `custom_strike`, `early_close_condition`, `expected_expiration_time`,
+    ///   `fee_waiver_expiration_time`, `functional_strike`, `is_provisional`,
+    ///   `mve_collection_ticker`, `primary_participant_key`, `response_price_units`,
+    ///   `tick_size`.
+    /// - Empty: `expiration_value`, `price_level_structure`, `mve_selected_legs`,
+    ///   `price_ranges`.
+    /// - Zero: `previous_price_dollars`, `previous_yes_ask_dollars`,
+    ///   `previous_yes_bid_dollars`, `yes_ask_size_fp`, `yes_bid_size_fp`,
+    ///   `settlement_timer_seconds`.
+    /// - `false`: `fractional_trading_enabled`.
+    ///
+    /// Documents written before `created_time` joined the schema get `open_time` for it.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Market)`: The stored market.
+    /// - `Err(KalshiError)`: A `UserInputError` if `value` has no `schema_version`, its
+    ///   version is `0` or newer than this crate knows, or it doesn't match the schema.
+    ///
+    pub fn from_stable_json(value: serde_json::Value) -> Result<Market, KalshiError> {
+        let version = value.get("schema_version").and_then(|v| v.as_u64());
+        match version {
+            Some(0) => {
+                return Err(KalshiError::UserInputError(
+                    "stable market schema version 0 is not valid; versions start at 1".to_string(),
+                ))
+            }
+            Some(version) if version <= u64::from(MARKET_STABLE_SCHEMA_VERSION) => {}
+            Some(version) => {
+                return Err(KalshiError::UserInputError(format!(
+                    "stable market schema version {} is newer than this crate supports ({})",
+                    version, MARKET_STABLE_SCHEMA_VERSION
+                )))
+            }
+            None => {
+                return Err(KalshiError::UserInputError(
+                    "stable market JSON has no schema_version".to_string(),
+                ))
+            }
+        }
+        let stable: StableMarketV1 = serde_json::from_value(value)
+            .map_err(|e| KalshiError::UserInputError(format!("invalid stable market JSON: {}", e)))?;
+
+        let zero_dollars = || FixedPointDollars("0.0000".to_string());
+        let zero_count = || FixedPointCount("0.00".to_string());
+        fn parse<T: std::str::FromStr<Err = std::convert::Infallible>>(value: String) -> T {
+            match value.parse() {
+                Ok(parsed) => parsed,
+                Err(never) => match never {},
+            }
+        }
+        Ok(Market {
+            ticker: stable.ticker,
+            event_ticker: stable.event_ticker,
+            market_type: parse(stable.market_type),
+            title: stable.title,
+            subtitle: stable.subtitle,
+            yes_sub_title: stable.yes_sub_title,
+            no_sub_title: stable.no_sub_title,
+            rules_primary: stable.rules_primary,
+            rules_secondary: stable.rules_secondary,
+            status: parse(stable.status),
+            result: parse(stable.result),
+            can_close_early: stable.can_close_early,
+            strike_type: stable.strike_type.map(parse),
+            floor_strike: stable.floor_strike,
+            cap_strike: stable.cap_strike,
+            created_time: stable.created_time.unwrap_or(stable.open_time),
+            open_time: stable.open_time,
+            close_time: stable.close_time,
+            expiration_time: stable.expiration_time,
+            latest_expiration_time: stable.latest_expiration_time,
+            settlement_ts: stable.settlement_ts,
+            updated_time: stable.updated_time,
+            yes_bid_dollars: FixedPointDollars(stable.yes_bid_dollars),
+            yes_ask_dollars: FixedPointDollars(stable.yes_ask_dollars),
+            no_bid_dollars: FixedPointDollars(stable.no_bid_dollars),
+            no_ask_dollars: FixedPointDollars(stable.no_ask_dollars),
+            last_price_dollars: FixedPointDollars(stable.last_price_dollars),
+            notional_value_dollars: FixedPointDollars(stable.notional_value_dollars),
+            liquidity_dollars: FixedPointDollars(stable.liquidity_dollars),
+            settlement_value_dollars: stable.settlement_value_dollars.map(FixedPointDollars),
+            volume_fp: FixedPointCount(stable.volume_fp),
+            volume_24h_fp: FixedPointCount(stable.volume_24h_fp),
+            open_interest_fp: FixedPointCount(stable.open_interest_fp),
+            custom_strike: None,
+            early_close_condition: None,
+            expected_expiration_time: None,
+            expiration_value: String::new(),
+            fee_waiver_expiration_time: None,
+            fractional_trading_enabled: false,
+            functional_strike: None,
+            is_provisional: None,
+            mve_collection_ticker: None,
+            mve_selected_legs: Vec::new(),
+            previous_price_dollars: zero_dollars(),
+            previous_yes_ask_dollars: zero_dollars(),
+            previous_yes_bid_dollars: zero_dollars(),
+            price_level_structure: String::new(),
+            price_ranges: Vec::new(),
+            primary_participant_key: None,
+            response_price_units: None,
+            settlement_timer_seconds: 0,
+            tick_size: None,
+            yes_ask_size_fp: zero_count(),
+            yes_bid_size_fp: zero_count(),
+        })
+    }
+}
+
+// -------- Series helpers --------
+
+/// Cadence of a series, parsed from the free-form [`Series::frequency`] string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeriesFrequency {
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Annual,
+    /// A series with a single event.
+    OneOff,
+    /// Any other description, kept verbatim (e.g. `"custom"`).
+    Other(String),
+}
+
+impl SeriesFrequency {
+    /// Parses a frequency description, ignoring case and surrounding whitespace.
+    pub fn parse(raw: &str) -> SeriesFrequency {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "hourly" => SeriesFrequency::Hourly,
+            "daily" => SeriesFrequency::Daily,
+            "weekly" => SeriesFrequency::Weekly,
+            "monthly" => SeriesFrequency::Monthly,
+            "annual" | "annually" | "yearly" => SeriesFrequency::Annual,
+            "one-off" | "one_off" | "oneoff" => SeriesFrequency::OneOff,
+            _ => SeriesFrequency::Other(raw.to_string()),
+        }
+    }
+}
+
+impl Series {
+    /// Fetches the series with `series_ticker`; shorthand for [`Kalshi::get_series`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let series = Series::load(&kalshi_instance, "KXHIGHNY").await.unwrap();
+    /// ```
+    ///
+    pub async fn load(kalshi: &Kalshi, series_ticker: &str) -> Result<Series, KalshiError> {
+        kalshi.get_series(series_ticker).await
+    }
+
+    /// The series' [`frequency`](Series::frequency) as a [`SeriesFrequency`].
+    pub fn frequency_kind(&self) -> SeriesFrequency {
+        SeriesFrequency::parse(&self.frequency)
+    }
+
+    /// Estimates when the next event of a recurring series opens, strictly after `after`.
+    ///
+    /// The estimate is the next UTC calendar boundary for the series' cadence: the top of
+    /// the hour, midnight, Monday midnight, the first of the month or January 1st. Actual
+    /// listing times vary by series, so treat this as the point to start polling rather
+    /// than an exact schedule.
+    ///
+    /// Returns `None` for one-off and unrecognised frequencies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let series = kalshi_instance.get_series("KXHIGHNY").await.unwrap();
+    /// if let Some(next) = series.estimated_next_event(chrono::Utc::now()) {
+    ///     println!("next event expected around {}", next);
+    /// }
+    /// ```
+    ///
+    pub fn estimated_next_event(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let date = after.date_naive();
+        let next = match self.frequency_kind() {
+            SeriesFrequency::Hourly => {
+                let hour = date.and_hms_opt(after.hour(), 0, 0)?;
+                hour + chrono::Duration::hours(1)
+            }
+            SeriesFrequency::Daily => date.succ_opt()?.and_hms_opt(0, 0, 0)?,
+            SeriesFrequency::Weekly => {
+                let days = 7 - i64::from(date.weekday().num_days_from_monday());
+                (date + chrono::Duration::days(days)).and_hms_opt(0, 0, 0)?
+            }
+            SeriesFrequency::Monthly => {
+                let (year, month) = if date.month() == 12 {
+                    (date.year() + 1, 1)
+                } else {
+                    (date.year(), date.month() + 1)
+                };
+                NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?
+            }
+            SeriesFrequency::Annual => NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)?.and_hms_opt(0, 0, 0)?,
+            SeriesFrequency::OneOff | SeriesFrequency::Other(_) => return None,
+        };
+        Some(next.and_utc())
+    }
+}
+
+// -------- Ladder detection --------
+
+/// How the markets of an event relate to each other through their strikes.
+/// See [`classify_ladder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LadderKind {
+    /// "Above X" thresholds (`greater` / `greater_or_equal`), ordered by `floor_strike`.
+    /// Several can resolve yes at once.
+    Above,
+    /// "Below X" thresholds (`less` / `less_or_equal`), ordered by `cap_strike`.
+    /// Several can resolve yes at once.
+    Below,
+    /// Mutually exclusive buckets covering a range: `between` markets ordered by
+    /// `floor_strike`, optionally with a `less` market below them and a `greater`
+    /// market above them as open-ended tails. Exactly one resolves yes.
+    Between,
+    /// Anything else: markets whose strikes don't form a ladder, or fewer than two markets.
+    Independent,
+}
+
+/// Works out whether a set of markets (typically one event's) forms a strike ladder.
+///
+/// Every market must have the strike fields its `strike_type` relies on (`floor_strike`
+/// for `greater*` and `between`, `cap_strike` for `less*` and `between`), otherwise the
+/// set is [`LadderKind::Independent`]. Mixing directions, or any other strike type, also
+/// yields `Independent`.
+///
+/// # Returns
+///
+/// The ladder kind and the markets ordered from lowest to highest strike. For
+/// `Independent` the input order is kept.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `kalshi_instance` is an instance of `Kalshi`
+/// let event = kalshi_instance.get_event("SOME-EVENT").await.unwrap();
+/// let (kind, ordered) = classify_ladder(&event.markets);
+/// if kind == LadderKind::Between {
+///     // render as a histogram of buckets
+/// }
+/// ```
+///
+pub fn classify_ladder(markets: &[Market]) -> (LadderKind, Vec<&Market>) {
+    fn sorted(mut strikes: Vec<(f64, &Market)>) -> impl Iterator<Item = &Market> {
+        strikes.sort_by(|a, b| a.0.total_cmp(&b.0));
+        strikes.into_iter().map(|(_, m)| m)
+    }
+
+    let independent = || (LadderKind::Independent, markets.iter().collect());
+    if markets.len() < 2 {
+        return independent();
+    }
+
+    let mut above = Vec::new();
+    let mut below = Vec::new();
+    let mut between = Vec::new();
+    for market in markets {
+        match (&market.strike_type, market.floor_strike, market.cap_strike) {
+            (Some(MarketStrikeType::Greater | MarketStrikeType::GreaterOrEqual), Some(floor), _) => {
+                above.push((floor, market))
+            }
+            (Some(MarketStrikeType::Less | MarketStrikeType::LessOrEqual), _, Some(cap)) => {
+                below.push((cap, market))
+            }
+            (Some(MarketStrikeType::Between), Some(floor), Some(_)) => between.push((floor, market)),
+            _ => return independent(),
+        }
+    }
+
+    match (above.len(), below.len(), between.len()) {
+        (_, 0, 0) => (LadderKind::Above, sorted(above).collect()),
+        (0, _, 0) => (LadderKind::Below, sorted(below).collect()),
+        (0..=1, 0..=1, _) if !between.is_empty() => {
+            let ordered = sorted(below).chain(sorted(between)).chain(sorted(above)).collect();
+            (LadderKind::Between, ordered)
+        }
+        _ => independent(),
+    }
+}
+
+// -------- Orderbook helpers --------
+
+/// Which side of the consolidated yes book an [`OrderbookLevel`] sits on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookSide {
+    /// Resting interest to buy yes.
+    Bid,
+    /// Resting interest to sell yes.
+    Ask,
+}
+
+/// A single aggregated price level, expressed in yes terms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderbookLevel {
+    /// Whether this level is a yes bid or a yes ask.
+    pub side: BookSide,
+    /// Yes price in dollars (e.g. `0.56`).
+    pub price_dollars: f64,
+    /// Total number of contracts resting at this price.
+    pub quantity: f64,
+}
+
+/// A resting bid on one side of the book, as published by the API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceLevel {
+    /// Bid price in dollars on the level's own side (e.g. `0.56`).
+    pub price_dollars: f64,
+    /// Number of contracts resting at this price.
+    pub quantity: f64,
+}
+
+impl Orderbook {
+    /// The bids resting on `side`, parsed into [`PriceLevel`]s, best (highest) first.
+    ///
+    /// This is a typed view of the raw `yes_dollars`/`no_dollars` fields, whose levels are
+    /// `[price, quantity]` string pairs. Levels whose price or quantity doesn't parse are
+    /// skipped rather than reported as errors; levels are not merged, so a price listed
+    /// twice appears twice. Use [`unified_yes_book`](Orderbook::unified_yes_book) for a
+    /// single aggregated book in yes terms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let book = kalshi_instance.get_orderbook("SOME-MARKET-2024", None).await.unwrap();
+    /// for level in book.levels(OrderSide::No) {
+    ///     println!("{} @ ${:.2}", level.quantity, level.price_dollars);
+    /// }
+    /// ```
+    ///
+    pub fn levels(&self, side: OrderSide) -> Vec<PriceLevel> {
+        let raw = match side {
+            OrderSide::Yes => &self.yes_dollars,
+            OrderSide::No => &self.no_dollars,
+            OrderSide::Unknown(_) => return Vec::new(),
+        };
+        let mut levels: Vec<PriceLevel> = raw
+            .iter()
+            .filter_map(|level| {
+                Some(PriceLevel {
+                    price_dollars: level[0].trim().parse().ok()?,
+                    quantity: level[1].trim().parse().ok()?,
+                })
+            })
+            .collect();
+        levels.sort_by(|a, b| b.price_dollars.total_cmp(&a.price_dollars));
+        levels
+    }
+
+    /// Folds the no side of the book into yes-equivalent levels.
+    ///
+    /// Kalshi only publishes bids: `yes_dollars` holds yes bids and `no_dollars`
+    /// holds no bids. Buying no at `p` is the same as selling yes at `1 - p`
+    /// (`100 - p` in cents), so every no bid becomes a yes ask at the complement
+    /// price. Levels that land on the same price are summed.
+    ///
+    /// # Returns
+    ///
+    /// Bids ordered best (highest) first, followed by asks ordered best (lowest)
+    /// first. Levels whose price or size can't be parsed, or whose size is zero,
+    /// are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let book = kalshi_instance.get_orderbook("SOME-MARKET-2024", None).await.unwrap();
+    /// let best_ask = book
+    ///     .unified_yes_book()
+    ///     .into_iter()
+    ///     .find(|level| level.side == BookSide::Ask);
+    /// ```
+    ///
+    pub fn unified_yes_book(&self) -> Vec<OrderbookLevel> {
+        // Keyed on hundredths of a cent so float noise from `1 - p` can't split a level.
+        let mut bids: BTreeMap<i64, f64> = BTreeMap::new();
+        let mut asks: BTreeMap<i64, f64> = BTreeMap::new();
+
+        for (book, levels, complement) in [
+            (&mut bids, &self.yes_dollars, false),
+            (&mut asks, &self.no_dollars, true),
+        ] {
+            for level in levels {
+                let (Ok(price), Ok(quantity)) =
+                    (level[0].trim().parse::<f64>(), level[1].trim().parse::<f64>())
+                else {
+                    continue;
+                };
+                if quantity <= 0.0 {
+                    continue;
+                }
+                let price = if complement { 1.0 - price } else { price };
+                *book.entry((price * 10_000.0).round() as i64).or_default() += quantity;
+            }
+        }
+
+        let level = |side, (key, quantity): (i64, f64)| OrderbookLevel {
+            side,
+            price_dollars: key as f64 / 10_000.0,
+            quantity,
+        };
+        bids.into_iter()
+            .rev()
+            .map(|entry| level(BookSide::Bid, entry))
+            .chain(asks.into_iter().map(|entry| level(BookSide::Ask, entry)))
+            .collect()
+    }
+
+    /// Best (highest) yes bid as `(price_dollars, quantity)`.
+    ///
+    /// Each raw level in `yes_dollars`/`no_dollars` is `[price, quantity]`: index 0 is
+    /// the price in dollars and index 1 the number of contracts. This and the other
+    /// `best_*` helpers read the aggregated book from
+    /// [`unified_yes_book`](Orderbook::unified_yes_book), so quantities resting at the
+    /// same price are summed. Returns `None` if the side is empty.
+    pub fn best_yes_bid(&self) -> Option<(f64, f64)> {
+        self.best_level(BookSide::Bid)
+    }
+
+    /// Best (lowest) yes ask as `(price_dollars, quantity)`, implied by the best no bid.
+    pub fn best_yes_ask(&self) -> Option<(f64, f64)> {
+        self.best_level(BookSide::Ask)
+    }
+
+    /// Best (highest) no bid as `(price_dollars, quantity)`.
+    pub fn best_no_bid(&self) -> Option<(f64, f64)> {
+        self.best_yes_ask().map(|(price, quantity)| (complement(price), quantity))
+    }
+
+    /// Best (lowest) no ask as `(price_dollars, quantity)`, implied by the best yes bid.
+    pub fn best_no_ask(&self) -> Option<(f64, f64)> {
+        self.best_yes_bid().map(|(price, quantity)| (complement(price), quantity))
+    }
+
+    /// Yes bid/ask spread in cents, or `None` unless both sides have resting interest.
+    ///
+    /// The ask is the complement of the best no bid (see
+    /// [`best_yes_ask`](Orderbook::best_yes_ask)), so yes bids and no bids are compared
+    /// on the same scale. The spread is rounded to the nearest cent, and is zero or
+    /// negative if the book is locked or crossed.
+    pub fn yes_spread(&self) -> Option<i32> {
+        let (bid, _) = self.best_yes_bid()?;
+        let (ask, _) = self.best_yes_ask()?;
+        Some(((ask - bid) * 100.0).round() as i32)
+    }
+
+    /// Midpoint of the best yes bid and ask in dollars, or `None` unless both sides have
+    /// resting interest.
+    pub fn yes_mid(&self) -> Option<f64> {
+        let (bid, _) = self.best_yes_bid()?;
+        let (ask, _) = self.best_yes_ask()?;
+        Some(((bid + ask) / 2.0 * 10_000.0).round() / 10_000.0)
+    }
+
+    fn best_level(&self, side: BookSide) -> Option<(f64, f64)> {
+        self.unified_yes_book()
+            .into_iter()
+            .find(|l| l.side == side)
+            .map(|l| (l.price_dollars, l.quantity))
+    }
+
+    /// Flattens the top of the book into a [`Snapshot`] taken at `ts`.
+    ///
+    /// The quotes come from [`best_yes_bid`](Orderbook::best_yes_bid),
+    /// [`best_yes_ask`](Orderbook::best_yes_ask) and their no complements, and `yes_price`
+    /// is [`yes_mid`](Orderbook::yes_mid). A side with no
+    /// resting interest leaves its fields at `0.0`, and `yes_price` is only set when both
+    /// sides are present. The book carries no `volume` or `open_interest`, so those stay
+    /// zero for the caller to fill in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let book = kalshi_instance.get_orderbook("SOME-MARKET-2024", None).await.unwrap();
+    /// let snapshot = book.to_snapshot(chrono::Utc::now().timestamp());
+    /// ```
+    ///
+    pub fn to_snapshot(&self, ts: i64) -> Snapshot {
+        let price = |level: Option<(f64, f64)>| level.map_or(0.0, |(price, _)| price);
+        Snapshot {
+            ts,
+            yes_price: self.yes_mid().unwrap_or(0.0),
+            yes_bid: price(self.best_yes_bid()),
+            yes_ask: price(self.best_yes_ask()),
+            no_bid: price(self.best_no_bid()),
+            no_ask: price(self.best_no_ask()),
+            volume: 0,
+            open_interest: 0,
+        }
+    }
+}
+
+/// `1 - price`, rounded to the hundredth of a cent so float noise doesn't leak out.
+pub(crate) fn complement(price: f64) -> f64 {
+    ((1.0 - price) * 10_000.0).round() / 10_000.0
+}
+
+/// A flat, point-in-time view of a market's quotes, in dollars.
+///
+/// Built from an orderbook with [`Orderbook::to_snapshot`], or streamed by
+/// [`KalshiWebSocket::subscribe_ticker`](crate::KalshiWebSocket::subscribe_ticker);
+/// fields that weren't available are `0`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Snapshot {
+    /// Unix timestamp (seconds) the snapshot was taken at.
+    pub ts: i64,
+    /// Mid price of yes when built from an orderbook; the last traded price when
+    /// streamed from the ticker channel.
+    pub yes_price: f64,
+    /// Best yes bid.
+    pub yes_bid: f64,
+    /// Best yes ask.
+    pub yes_ask: f64,
+    /// Best no bid.
+    pub no_bid: f64,
+    /// Best no ask.
+    pub no_ask: f64,
+    /// Contracts traded.
+    pub volume: i64,
+    /// Open interest in contracts.
+    pub open_interest: i64,
+}
+
+/// Outcome of [`simulate_fill`].
+///
+/// Prices are in dollars on the order's own side: a no order reports no prices.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulatedFill {
+    /// Each price level the order took liquidity from, as `(price_dollars, count)`,
+    /// in the order they were consumed.
+    pub fills: Vec<(f64, f64)>,
+    /// Total contracts filled.
+    pub filled_count: f64,
+    /// Volume-weighted average fill price, or `None` if nothing filled.
+    pub average_price_dollars: Option<f64>,
+    /// Premium exchanged for the filled contracts (`Σ price * count`), excluding fees.
+    pub total_cost_dollars: f64,
+    /// Contracts that would be left resting on the book after the immediate fills.
+    pub resting_count: f64,
+    /// `true` if the exchange would reject the order outright (a `post_only` order
+    /// that crosses the book).
+    pub rejected: bool,
+}
+
+/// Simulates how `req` would execute against `book` without sending anything.
+///
+/// The no side of the book is folded in with [`Orderbook::unified_yes_book`], so a yes
+/// order can match resting no bids and vice versa. Buying takes liquidity priced at or
+/// better than the limit. Selling takes bids at or above it. Without a limit price the
+/// order is treated as a market order and walks the whole opposite side. For buys,
+/// `buy_max_cost` (in cents) caps the spend.
+///
+/// Whatever doesn't fill rests for good-till-canceled limit orders. It is dropped for
+/// `immediate_or_cancel`, market orders and `fill_or_kill` orders. A `fill_or_kill`
+/// order that can't fill completely fills nothing. Queue position, fees and
+/// self-trade prevention are not modelled.
+///
+/// # Returns
+///
+/// - `Ok(SimulatedFill)`: The fills the order would receive and what would be left resting.
+/// - `Err(KalshiError)`: A `UserInputError` if the request has no count.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `kalshi_instance` is an instance of `Kalshi` and `req` a `CreateOrderRequest`
+/// let book = kalshi_instance.get_orderbook(&req.ticker, None).await.unwrap();
+/// let sim = simulate_fill(&book, &req).unwrap();
+/// println!("would fill {} @ {:?}", sim.filled_count, sim.average_price_dollars);
+/// ```
+///
+pub fn simulate_fill(book: &Orderbook, req: &CreateOrderRequest) -> Result<SimulatedFill, KalshiError> {
+    let count = req
+        .count
+        .map(|c| c.get() as f64)
+        .or_else(|| req.count_fp.as_ref().and_then(|c| c.to_f64()))
+        .ok_or_else(|| KalshiError::UserInputError("order has no count or count_fp".to_string()))?;
+
+    let cents = |c: std::num::NonZeroU64| c.get() as f64 / 100.0;
+    let yes_limit = req
+        .yes_price
+        .map(cents)
+        .or_else(|| req.yes_price_dollars.as_ref().and_then(|p| p.to_f64()));
+    let no_limit = req
+        .no_price
+        .map(cents)
+        .or_else(|| req.no_price_dollars.as_ref().and_then(|p| p.to_f64()));
+    // Everything is matched in yes terms; `flip` converts back to the order's side.
+    let flip = req.side == CreateOrderRequestSide::No;
+    let limit = match req.side {
+        CreateOrderRequestSide::Yes => yes_limit.or(no_limit.map(|p| 1.0 - p)),
+        CreateOrderRequestSide::No => no_limit.or(yes_limit.map(|p| 1.0 - p)),
+    };
+    let yes_limit = limit.map(|p| if flip { 1.0 - p } else { p });
+    let is_buy = req.action == CreateOrderRequestAction::Buy;
+    // Buying yes or selling no lifts yes asks; the other two hit yes bids.
+    let takes_asks = is_buy != flip;
+
+    let levels = book
+        .unified_yes_book()
+        .into_iter()
+        .filter(|l| (l.side == BookSide::Ask) == takes_asks)
+        .take_while(|l| match yes_limit {
+            Some(limit) if takes_asks => l.price_dollars <= limit + 1e-9,
+            Some(limit) => l.price_dollars >= limit - 1e-9,
+            None => true,
+        });
+
+    let mut sim = SimulatedFill::default();
+    let mut budget = req.buy_max_cost.filter(|_| is_buy).map(|c| c as f64 / 100.0);
+    for level in levels {
+        let price = if flip { 1.0 - level.price_dollars } else { level.price_dollars };
+        let mut take = level.quantity.min(count - sim.filled_count);
+        if let Some(remaining) = budget {
+            take = take.min((remaining / price).floor());
+            budget = Some(remaining - take * price);
+        }
+        if take <= 0.0 {
+            break;
+        }
+        sim.fills.push((price, take));
+        sim.filled_count += take;
+        sim.total_cost_dollars += price * take;
+    }
+
+    if req.post_only == Some(true) && !sim.fills.is_empty() {
+        return Ok(SimulatedFill { rejected: true, ..Default::default() });
+    }
+    let unfilled = count - sim.filled_count;
+    match req.time_in_force {
+        Some(CreateOrderRequestTimeInForce::FillOrKill) if unfilled > 0.0 => {
+            return Ok(SimulatedFill::default());
+        }
+        Some(CreateOrderRequestTimeInForce::FillOrKill)
+        | Some(CreateOrderRequestTimeInForce::ImmediateOrCancel) => {}
+        _ if limit.is_some() => sim.resting_count = unfilled,
+        _ => {}
+    }
+    if sim.filled_count > 0.0 {
+        sim.average_price_dollars = Some(sim.total_cost_dollars / sim.filled_count);
+    }
+    Ok(sim)
+}
+
+// -------- Depth recording --------
+
+/// One row recorded by a [`DepthRecorder`]. Prices are yes prices in dollars.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DepthSample {
+    /// Unix timestamp (seconds) of the book the sample was taken from.
+    pub ts: i64,
+    /// Best yes bid, or `None` if there were no bids.
+    pub best_bid: Option<f64>,
+    /// Best yes ask, or `None` if there were no asks.
+    pub best_ask: Option<f64>,
+    /// Total contracts resting on the bid side.
+    pub bid_depth: f64,
+    /// Total contracts resting on the ask side.
+    pub ask_depth: f64,
+}
+
+/// Records the top of the book and total depth of one market over time.
+///
+/// The API only serves the current orderbook, so depth history has to be collected
+/// by polling. Feed every book you fetch to [`record`](DepthRecorder::record); a
+/// sample is kept at most once per `interval_secs`, so the polling rate and the
+/// sampling rate can differ.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `kalshi_instance` is an instance of `Kalshi`
+/// let mut recorder = DepthRecorder::new(60);
+/// for _ in 0..60 {
+///     let book = kalshi_instance.get_orderbook("SOME-MARKET-2024", None).await.unwrap();
+///     recorder.record(chrono::Utc::now().timestamp(), &book);
+///     tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+/// }
+/// recorder.write_csv(std::fs::File::create("depth.csv").unwrap()).unwrap();
+/// ```
+///
+#[derive(Debug, Clone, Default)]
+pub struct DepthRecorder {
+    interval_secs: i64,
+    samples: Vec<DepthSample>,
+}
+
+impl DepthRecorder {
+    /// Creates a recorder that keeps at most one sample every `interval_secs` seconds.
+    /// An interval of `0` keeps every book passed to [`record`](DepthRecorder::record).
+    pub fn new(interval_secs: i64) -> Self {
+        DepthRecorder { interval_secs: interval_secs.max(0), samples: Vec::new() }
+    }
+
+    /// Samples `book`, observed at Unix time `ts`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a sample was stored; `false` if `ts` is less than `interval_secs`
+    /// after the previous sample (or earlier than it).
+    pub fn record(&mut self, ts: i64, book: &Orderbook) -> bool {
+        if let Some(last) = self.samples.last() {
+            if ts < last.ts || ts - last.ts < self.interval_secs {
+                return false;
+            }
+        }
+
+        let mut sample = DepthSample { ts, ..Default::default() };
+        for level in book.unified_yes_book() {
+            match level.side {
+                BookSide::Bid => {
+                    sample.best_bid.get_or_insert(level.price_dollars);
+                    sample.bid_depth += level.quantity;
+                }
+                BookSide::Ask => {
+                    sample.best_ask.get_or_insert(level.price_dollars);
+                    sample.ask_depth += level.quantity;
+                }
+            }
+        }
+        self.samples.push(sample);
+        true
+    }
+
+    /// The samples recorded so far, oldest first.
+    pub fn samples(&self) -> &[DepthSample] {
+        &self.samples
+    }
+
+    /// Consumes the recorder, returning its samples.
+    pub fn into_samples(self) -> Vec<DepthSample> {
+        self.samples
+    }
+
+    /// Writes the samples as CSV with a `ts,best_bid,best_ask,bid_depth,ask_depth` header.
+    ///
+    /// A missing best bid or ask is written as an empty field.
+    pub fn write_csv<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let price = |p: Option<f64>| p.map(|p| format!("{:.4}", p)).unwrap_or_default();
+        writeln!(writer, "ts,best_bid,best_ask,bid_depth,ask_depth")?;
+        for s in &self.samples {
+            writeln!(
+                writer,
+                "{},{},{},{},{}",
+                s.ts,
+                price(s.best_bid),
+                price(s.best_ask),
+                s.bid_depth,
+                s.ask_depth
+            )?;
+        }
+        writer.flush()
+    }
+}
+
+// -------- Trade helpers --------
+
+impl Trade {
+    /// When the trade executed, as Unix seconds: the representation candles use for
+    /// `end_period_ts`, so trades can be bucketed against them directly.
+    ///
+    /// `created_time` is parsed from RFC 3339 when the trade is deserialized, whatever
+    /// its offset, so this can't fail.
+    pub fn created_ts(&self) -> i64 {
+        self.created_time.timestamp()
+    }
+}
+
+/// Result of [`Kalshi::get_new_trades`].
+#[derive(Debug, Clone)]
+pub struct NewTrades {
+    /// Trades newer than the last one seen, ordered oldest first.
+    pub trades: Vec<Trade>,
+    /// `true` if the last seen trade was not in the fetched window, meaning trades
+    /// in between may have been missed.
+    pub gap: bool,
+}
+
+impl NewTrades {
+    /// Keeps the trades in `newest_first` that came after `since_trade_id`,
+    /// reversed into chronological order.
+    fn after(mut newest_first: Vec<Trade>, since_trade_id: Option<&str>) -> NewTrades {
+        let gap = match since_trade_id {
+            Some(since) => match newest_first.iter().position(|t| t.trade_id == since) {
+                Some(index) => {
+                    newest_first.truncate(index);
+                    false
+                }
+                None => true,
+            },
+            None => false,
+        };
+        newest_first.reverse();
+        NewTrades { trades: newest_first, gap }
+    }
+}
+
+// -------- Candlestick helpers --------
+
+/// A broken invariant found by [`Candle::anomalies`] or [`find_anomalies`].
+///
+/// `series` names the OHLC distribution at fault: `"price"` (traded price),
+/// `"yes_bid"` or `"yes_ask"`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandleAnomaly {
+    /// A price field is present but can't be parsed as a number.
+    Unparseable { series: &'static str },
+    /// A price lies outside the `[0, 1]` dollar range of a contract.
+    PriceOutOfRange { series: &'static str },
+    /// `low <= open, close <= high` does not hold.
+    InconsistentOhlc { series: &'static str },
+    /// Volume or open interest is negative or unparseable.
+    NegativeVolume,
+    /// `end_period_ts` is not after the previous candle's.
+    OutOfOrder { previous_end_ts: i64, end_ts: i64 },
+    /// The traded price opened more than the allowed distance from the previous close.
+    PriceJump { from_dollars: f64, to_dollars: f64 },
+}
+
+impl MarketCandlestick {
+    /// Returns `true` if [`anomalies`](MarketCandlestick::anomalies) finds nothing wrong.
+    pub fn is_valid(&self) -> bool {
+        self.anomalies().is_empty()
+    }
+
+    /// Checks the invariants that hold for any single well-formed candle.
+    ///
+    /// For the traded price and both yes bid/ask distributions, every price must parse,
+    /// lie in `[0, 1]` dollars and satisfy `low <= open, close <= high`. The traded price
+    /// is skipped when its OHLC fields are absent (no trades in the period). Volume and
+    /// open interest must be non-negative.
+    ///
+    /// Ordering between candles is checked by [`find_anomalies`].
+    pub fn anomalies(&self) -> Vec<CandleAnomaly> {
+        let mut found = Vec::new();
+        let price = &self.price;
+        if let (Some(open), Some(high), Some(low), Some(close)) =
+            (&price.open_dollars, &price.high_dollars, &price.low_dollars, &price.close_dollars)
+        {
+            check_ohlc("price", [open, high, low, close], &mut found);
+        }
+        for (series, dist) in [("yes_bid", &self.yes_bid), ("yes_ask", &self.yes_ask)] {
+            check_ohlc(
+                series,
+                [&dist.open_dollars, &dist.high_dollars, &dist.low_dollars, &dist.close_dollars],
+                &mut found,
+            );
+        }
+        let non_negative = |count: &FixedPointCount| count.to_f64().is_some_and(|c| c >= 0.0);
+        if !non_negative(&self.volume_fp) || !non_negative(&self.open_interest_fp) {
+            found.push(CandleAnomaly::NegativeVolume);
+        }
+        found
+    }
+
+    /// The typical traded yes price of the period in dollars, `(high + low + close) / 3`.
+    ///
+    /// `NaN` when nothing traded in the period, so the traded high, low or close is
+    /// missing or unparseable.
+    pub fn yes_typical_price(&self) -> f64 {
+        let price = &self.price;
+        [&price.high_dollars, &price.low_dollars, &price.close_dollars]
+            .iter()
+            .map(|p| p.as_ref().and_then(|p| p.to_f64()))
+            .sum::<Option<f64>>()
+            .map_or(f64::NAN, |sum| sum / 3.0)
+    }
+}
+
+/// Checks one `[open, high, low, close]` distribution, pushing any violations.
+fn check_ohlc(series: &'static str, ohlc: [&FixedPointDollars; 4], found: &mut Vec<CandleAnomaly>) {
+    let Some([open, high, low, close]) = ohlc
+        .iter()
+        .map(|p| p.to_f64())
+        .collect::<Option<Vec<f64>>>()
+        .and_then(|v| <[f64; 4]>::try_from(v).ok())
+    else {
+        found.push(CandleAnomaly::Unparseable { series });
+        return;
+    };
+    if [open, high, low, close].iter().any(|p| !(0.0..=1.0).contains(p)) {
+        found.push(CandleAnomaly::PriceOutOfRange { series });
+    }
+    if low > open.min(close) || high < open.max(close) {
+        found.push(CandleAnomaly::InconsistentOhlc { series });
+    }
+}
+
+/// Scans a candle series for malformed data, returning each offending index and why.
+///
+/// Every candle is checked with [`Candle::anomalies`]. Consecutive candles must have
+/// strictly increasing `end_period_ts`. When both candles traded, the open must not
+/// jump more than `max_jump_dollars` away from the previous close. An index appears
+/// once per violation, in ascending order.
+///
+/// # Example
+///
+/// ```
+/// // Assuming `kalshi_instance` is an instance of `Kalshi`
+/// let candles = kalshi_instance
+///     .get_market_candlesticks("SOME-MARKET-2024", "SERIES", Some(start), Some(end), Some(60))
+///     .await
+///     .unwrap();
+/// for (index, anomaly) in find_anomalies(&candles, 0.5) {
+///     eprintln!("candle {index}: {anomaly:?}");
+/// }
+/// ```
+///
+pub fn find_anomalies(candles: &[Candle], max_jump_dollars: f64) -> Vec<(usize, CandleAnomaly)> {
+    let mut found = Vec::new();
+    for (index, candle) in candles.iter().enumerate() {
+        found.extend(candle.anomalies().into_iter().map(|a| (index, a)));
+        let Some(previous) = index.checked_sub(1).map(|i| &candles[i]) else {
+            continue;
+        };
+        if candle.end_period_ts <= previous.end_period_ts {
+            found.push((
+                index,
+                CandleAnomaly::OutOfOrder {
+                    previous_end_ts: previous.end_period_ts,
+                    end_ts: candle.end_period_ts,
+                },
+            ));
+        }
+        let prev_close = previous.price.close_dollars.as_ref().and_then(|p| p.to_f64());
+        let open = candle.price.open_dollars.as_ref().and_then(|p| p.to_f64());
+        if let (Some(from), Some(to)) = (prev_close, open) {
+            if (to - from).abs() > max_jump_dollars {
+                found.push((index, CandleAnomaly::PriceJump { from_dollars: from, to_dollars: to }));
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::generated::types::FixedPointDollars;
+    use chrono::TimeZone;
+
+    fn sample_market() -> Market {
+        serde_json::from_value(serde_json::json!({
+            "can_close_early": true,
+            "close_time": "2030-01-01T00:00:00Z",
+            "created_time": "2024-01-01T00:00:00Z",
+            "event_ticker": "EVENT",
+            "expiration_value": "",
+            "fractional_trading_enabled": false,
+            "last_price_dollars": "0.5000",
+            "latest_expiration_time": "2030-01-02T00:00:00Z",
+            "liquidity_dollars": "1000.0000",
+            "market_type": "binary",
+            "no_ask_dollars": "0.5200",
+            "no_bid_dollars": "0.4600",
+            "no_sub_title": "",
+            "notional_value_dollars": "1.0000",
+            "open_interest_fp": "100.00",
+            "open_time": "2024-01-01T00:00:00Z",
+            "previous_price_dollars": "0.5000",
+            "previous_yes_ask_dollars": "0.5400",
+            "previous_yes_bid_dollars": "0.4800",
+            "price_level_structure": "linear_cent",
+            "price_ranges": [],
+            "result": "",
+            "rules_primary": "",
+            "rules_secondary": "",
+            "settlement_timer_seconds": 0,
+            "status": "active",
+            "ticker": "EVENT-T1",
+            "updated_time": "2024-01-01T00:00:00Z",
+            "volume_24h_fp": "10.00",
+            "volume_fp": "250.00",
+            "yes_ask_dollars": "0.5400",
+            "yes_ask_size_fp": "5.00",
+            "yes_bid_dollars": "0.4800",
+            "yes_bid_size_fp": "7.00",
+            "yes_sub_title": ""
+        }))
+        .unwrap()
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_is_tradeable() {
+        let market = sample_market();
+        assert!(market.is_tradeable(now()));
+
+        let mut closed = market.clone();
+        closed.status = MarketStatus::Closed;
+        assert!(!closed.is_tradeable(now()));
+
+        assert!(!market.is_tradeable(Utc.with_ymd_and_hms(2031, 1, 1, 0, 0, 0).unwrap()));
+
+        let mut illiquid = market.clone();
+        illiquid.liquidity_dollars = FixedPointDollars("0.0000".into());
+        assert!(!illiquid.is_tradeable(now()));
+
+        let mut one_sided = market.clone();
+        one_sided.yes_bid_dollars = FixedPointDollars("0.0000".into());
+        assert!(!one_sided.is_tradeable(now()));
+    }
+
+    #[test]
+    fn test_is_tradeable_with_params() {
+        let market = sample_market();
+        let tight = TradeableParams { max_spread_dollars: Some(0.02), ..Default::default() };
+        assert!(!market.is_tradeable_with(now(), &tight));
+
+        let deep = TradeableParams { min_liquidity_dollars: 5000.0, ..Default::default() };
+        assert!(!market.is_tradeable_with(now(), &deep));
+
+        let loose = TradeableParams { min_liquidity_dollars: 10.0, max_spread_dollars: Some(0.10) };
+        assert!(market.is_tradeable_with(now(), &loose));
+    }
+
+    fn level(price: &str, size: &str) -> PriceLevelDollarsCountFp {
+        PriceLevelDollarsCountFp([price.to_string(), size.to_string()])
+    }
+
+    #[test]
+    fn test_unified_yes_book() {
+        let book = Orderbook {
+            yes_dollars: vec![level("0.4000", "10.00"), level("0.4200", "5.00")],
+            // 0.55 and 0.5500 both map to a yes ask at 0.45 and must be aggregated.
+            no_dollars: vec![
+                level("0.5500", "3.00"),
+                level("0.55", "4.00"),
+                level("0.5000", "2.00"),
+                level("0.4900", "0.00"),
+                level("bogus", "1.00"),
+            ],
+        };
+
+        let unified = book.unified_yes_book();
+        let summary: Vec<_> = unified.iter().map(|l| (l.side, l.price_dollars, l.quantity)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (BookSide::Bid, 0.42, 5.0),
+                (BookSide::Bid, 0.40, 10.0),
+                (BookSide::Ask, 0.45, 7.0),
+                (BookSide::Ask, 0.50, 2.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unified_yes_book_complement() {
+        let book = Orderbook {
+            yes_dollars: vec![],
+            no_dollars: vec![level("0.0100", "1.00"), level("0.9900", "1.00"), level("0.6700", "1.00")],
+        };
+        let asks: Vec<_> = book.unified_yes_book().iter().map(|l| l.price_dollars).collect();
+        assert_eq!(asks, vec![0.01, 0.33, 0.99]);
+    }
+
+    #[test]
+    fn test_orderbook_levels() {
+        let book = Orderbook {
+            yes_dollars: vec![level("0.4000", "10.00"), level("bogus", "1.00"), level("0.4200", "5.00")],
+            no_dollars: vec![level("0.5500", "3.00")],
+        };
+        assert_eq!(
+            book.levels(OrderSide::Yes),
+            vec![
+                PriceLevel { price_dollars: 0.42, quantity: 5.0 },
+                PriceLevel { price_dollars: 0.40, quantity: 10.0 },
+            ]
+        );
+        assert_eq!(book.levels(OrderSide::No), vec![PriceLevel { price_dollars: 0.55, quantity: 3.0 }]);
+        assert!(book.levels(OrderSide::Unknown("maybe".to_string())).is_empty());
+    }
+
+    #[test]
+    fn test_orderbook_best_levels() {
+        let book = Orderbook {
+            yes_dollars: vec![level("0.4000", "10.00"), level("0.4200", "5.00"), level("0.4200", "1.00")],
+            no_dollars: vec![level("0.5500", "3.00"), level("0.5000", "2.00")],
+        };
+        assert_eq!(book.best_yes_bid(), Some((0.42, 6.0)));
+        assert_eq!(book.best_yes_ask(), Some((0.45, 3.0)));
+        assert_eq!(book.best_no_bid(), Some((0.55, 3.0)));
+        assert_eq!(book.best_no_ask(), Some((0.58, 6.0)));
+        assert_eq!(book.yes_spread(), Some(3));
+        assert_eq!(book.yes_mid(), Some(0.435));
+
+        let one_sided = Orderbook { yes_dollars: vec![level("0.4000", "1.00")], no_dollars: vec![] };
+        assert_eq!(one_sided.best_yes_ask(), None);
+        assert_eq!(one_sided.best_no_bid(), None);
+        assert_eq!(one_sided.best_no_ask(), Some((0.6, 1.0)));
+        assert_eq!((one_sided.yes_spread(), one_sided.yes_mid()), (None, None));
+    }
+
+    #[test]
+    fn test_depth_recorder() {
+        let book = Orderbook {
+            yes_dollars: vec![level("0.4000", "10.00"), level("0.4200", "5.00")],
+            no_dollars: vec![level("0.5500", "3.00")],
+        };
+        let empty_asks = Orderbook { yes_dollars: vec![level("0.4000", "1.50")], no_dollars: vec![] };
+
+        let mut recorder = DepthRecorder::new(60);
+        assert!(recorder.record(1_000, &book));
+        assert!(!recorder.record(1_030, &book));
+        assert!(!recorder.record(900, &book));
+        assert!(recorder.record(1_060, &empty_asks));
+        assert_eq!(
+            recorder.samples()[0],
+            DepthSample { ts: 1_000, best_bid: Some(0.42), best_ask: Some(0.45), bid_depth: 15.0, ask_depth: 3.0 }
+        );
+
+        let mut csv = Vec::new();
+        recorder.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "ts,best_bid,best_ask,bid_depth,ask_depth\n1000,0.4200,0.4500,15,3\n1060,0.4000,,1.5,0\n"
+        );
+    }
+
+    #[test]
+    fn test_orderbook_to_snapshot() {
+        let book = Orderbook {
+            yes_dollars: vec![level("0.4000", "10.00"), level("0.4200", "5.00")],
+            no_dollars: vec![level("0.5500", "3.00"), level("0.5000", "2.00")],
+        };
+        let snapshot = book.to_snapshot(1_700_000_000);
+        assert_eq!(
+            snapshot,
+            Snapshot {
+                ts: 1_700_000_000,
+                yes_price: 0.435,
+                yes_bid: 0.42,
+                yes_ask: 0.45,
+                no_bid: 0.55,
+                no_ask: 0.58,
+                volume: 0,
+                open_interest: 0,
+            }
+        );
+
+        let one_sided = Orderbook { yes_dollars: vec![level("0.4200", "5.00")], no_dollars: vec![] };
+        let snapshot = one_sided.to_snapshot(0);
+        assert_eq!((snapshot.yes_bid, snapshot.no_ask), (0.42, 0.58));
+        assert_eq!((snapshot.yes_price, snapshot.yes_ask, snapshot.no_bid), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_position_notional() {
+        let mut market = sample_market();
+        assert_eq!(market.notional_cents(), 100);
+        assert_eq!(market.position_notional(25), 2500);
+        assert_eq!(market.position_notional(0), 0);
+
+        market.notional_value_dollars = FixedPointDollars("0.5000".into());
+        assert_eq!(market.position_notional(3), 150);
+    }
+
+    #[test]
+    fn test_max_loss() {
+        let market = sample_market();
+        assert_eq!(market.max_loss(OrderSide::Yes, 30, 10), 300);
+        assert_eq!(market.max_loss(OrderSide::No, 30, 10), 700);
+        assert_eq!(market.max_loss(OrderSide::Unknown("maybe".into()), 30, 10), 700);
+    }
+
+    fn candle(end_ts: i64, ohlc: [&str; 4], volume: &str) -> Candle {
+        let dist = |[o, h, l, c]: [&str; 4]| serde_json::json!({
+            "open_dollars": o, "high_dollars": h, "low_dollars": l, "close_dollars": c,
+        });
+        serde_json::from_value(serde_json::json!({
+            "end_period_ts": end_ts,
+            "open_interest_fp": "10.00",
+            "price": dist(ohlc),
+            "volume_fp": volume,
+            "yes_ask": dist(["0.5500", "0.6000", "0.5000", "0.5500"]),
+            "yes_bid": dist(["0.4500", "0.5000", "0.4000", "0.4500"]),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_candle_is_valid() {
+        assert!(candle(60, ["0.5000", "0.5500", "0.4500", "0.5200"], "5.00").is_valid());
+
+        let inverted = candle(60, ["0.5000", "0.4800", "0.4500", "0.5200"], "5.00");
+        assert_eq!(inverted.anomalies(), vec![CandleAnomaly::InconsistentOhlc { series: "price" }]);
+
+        let negative = candle(60, ["0.5000", "0.5500", "0.4500", "0.5200"], "-1.00");
+        assert_eq!(negative.anomalies(), vec![CandleAnomaly::NegativeVolume]);
+
+        let out_of_range = candle(60, ["0.5000", "1.5000", "0.4500", "0.5200"], "5.00");
+        assert!(out_of_range.anomalies().contains(&CandleAnomaly::PriceOutOfRange { series: "price" }));
+    }
+
+    #[test]
+    fn test_candle_yes_typical_price() {
+        let traded = candle(60, ["0.5000", "0.6000", "0.4500", "0.5400"], "5.00");
+        assert!((traded.yes_typical_price() - 0.53).abs() < 1e-9);
+
+        let mut untraded = traded.clone();
+        untraded.price.close_dollars = None;
+        assert!(untraded.yes_typical_price().is_nan());
+    }
+
+    #[test]
+    fn test_find_anomalies() {
+        let ok = ["0.5000", "0.5500", "0.4500", "0.5000"];
+        let candles = vec![
+            candle(60, ok, "5.00"),
+            candle(120, ok, "5.00"),
+            candle(120, ok, "5.00"),
+            candle(180, ["0.9500", "0.9900", "0.9000", "0.9500"], "5.00"),
+        ];
+        assert_eq!(
+            find_anomalies(&candles, 0.25),
+            vec![
+                (2, CandleAnomaly::OutOfOrder { previous_end_ts: 120, end_ts: 120 }),
+                (3, CandleAnomaly::PriceJump { from_dollars: 0.5, to_dollars: 0.95 }),
+            ]
+        );
+    }
+
+    fn trade(trade_id: &str) -> Trade {
+        trade_with(trade_id, "yes", "2024-01-01T00:00:00Z")
+    }
+
+    /// A one-contract trade at 60¢ yes in `EVENT-T1`, shared with other modules' tests.
+    pub(crate) fn trade_with(trade_id: &str, taker_side: &str, created_time: &str) -> Trade {
+        serde_json::from_value(serde_json::json!({
+            "count_fp": "1.00",
+            "created_time": created_time,
+            "no_price_dollars": "0.4000",
+            "taker_side": taker_side,
+            "ticker": "EVENT-T1",
+            "trade_id": trade_id,
+            "yes_price_dollars": "0.6000",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_new_trades_after() {
+        let ids = |update: &NewTrades| update.trades.iter().map(|t| t.trade_id.clone()).collect::<Vec<_>>();
+        let fetched = || vec![trade("t4"), trade("t3"), trade("t2"), trade("t1")];
+
+        let update = NewTrades::after(fetched(), Some("t2"));
+        assert_eq!(ids(&update), ["t3", "t4"]);
+        assert!(!update.gap);
+
+        let update = NewTrades::after(fetched(), Some("t4"));
+        assert!(update.trades.is_empty());
+        assert!(!update.gap);
+
+        let update = NewTrades::after(fetched(), Some("t0"));
+        assert_eq!(ids(&update), ["t1", "t2", "t3", "t4"]);
+        assert!(update.gap);
+
+        let update = NewTrades::after(fetched(), None);
+        assert_eq!(ids(&update), ["t1", "t2", "t3", "t4"]);
+        assert!(!update.gap);
+    }
+
+    #[test]
+    fn test_valid_prices_tick_size() {
+        let mut market = sample_market();
+        market.tick_size = Some(5);
+        let prices: Vec<i32> = market.valid_prices().collect();
+        assert_eq!(prices.first(), Some(&5));
+        assert_eq!(prices.last(), Some(&95));
+        assert_eq!(prices.len(), 19);
+        assert!(prices.iter().all(|p| p % 5 == 0));
+
+        market.tick_size = Some(0);
+        assert_eq!(market.valid_prices().collect::<Vec<_>>(), (1..=99).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_valid_prices_price_ranges() {
+        let range = |start: &str, end: &str, step: &str| {
+            serde_json::from_value(serde_json::json!({ "start": start, "end": end, "step": step }))
+                .unwrap()
+        };
+        let mut market = sample_market();
+        market.price_ranges = vec![
+            range("0.0000", "0.1000", "0.0500"),
+            range("0.1000", "0.9000", "0.2000"),
+            range("0.9000", "1.0000", "0.0500"),
+        ];
+        assert_eq!(market.valid_prices().collect::<Vec<_>>(), [5, 10, 30, 50, 70, 90, 95]);
+
+        // Sub-cent steps can't be expressed in cents, so tick_size is used instead.
+        market.price_ranges = vec![range("0.0000", "0.1000", "0.0010")];
+        market.tick_size = Some(10);
+        assert_eq!(market.valid_prices().count(), 9);
+    }
+
+    fn order(action: &str, side: &str, count: u64, price_field: &str, price: u64) -> CreateOrderRequest {
+        let mut req = serde_json::json!({
+            "action": action,
+            "side": side,
+            "count": count,
+            "ticker": "EVENT-T1",
+        });
+        if price > 0 {
+            req[price_field] = serde_json::json!(price);
+        }
+        serde_json::from_value(req).unwrap()
+    }
+
+    fn sim_book() -> Orderbook {
+        Orderbook {
+            // yes bids at 0.40 x 10 and 0.38 x 20
+            yes_dollars: vec![level("0.4000", "10.00"), level("0.3800", "20.00")],
+            // no bids at 0.55 x 5 and 0.50 x 10 => yes asks at 0.45 x 5 and 0.50 x 10
+            no_dollars: vec![level("0.5500", "5.00"), level("0.5000", "10.00")],
+        }
+    }
+
+    #[test]
+    fn test_simulate_fill_limit_buy() {
+        let sim = simulate_fill(&sim_book(), &order("buy", "yes", 8, "yes_price", 50)).unwrap();
+        assert_eq!(sim.fills, vec![(0.45, 5.0), (0.5, 3.0)]);
+        assert_eq!(sim.filled_count, 8.0);
+        assert!((sim.average_price_dollars.unwrap() - 3.75 / 8.0).abs() < 1e-9);
+        assert_eq!(sim.resting_count, 0.0);
+
+        let sim = simulate_fill(&sim_book(), &order("buy", "yes", 8, "yes_price", 46)).unwrap();
+        assert_eq!(sim.filled_count, 5.0);
+        assert_eq!(sim.resting_count, 3.0);
+    }
+
+    #[test]
+    fn test_simulate_fill_no_side() {
+        // Buying no at 0.62 matches yes bids at >= 0.38, reported as no prices.
+        let sim = simulate_fill(&sim_book(), &order("buy", "no", 15, "no_price", 62)).unwrap();
+        assert_eq!(sim.fills.len(), 2);
+        assert!((sim.fills[0].0 - 0.60).abs() < 1e-9);
+        assert!((sim.fills[1].0 - 0.62).abs() < 1e-9);
+        assert_eq!(sim.filled_count, 15.0);
+    }
+
+    #[test]
+    fn test_simulate_fill_time_in_force() {
+        let mut req = order("sell", "yes", 50, "", 0);
+        let sim = simulate_fill(&sim_book(), &req).unwrap();
+        assert_eq!(sim.filled_count, 30.0);
+        assert_eq!(sim.resting_count, 0.0);
+
+        req.time_in_force = Some(CreateOrderRequestTimeInForce::FillOrKill);
+        assert_eq!(simulate_fill(&sim_book(), &req).unwrap(), SimulatedFill::default());
+
+        let mut post_only = order("buy", "yes", 1, "yes_price", 45);
+        post_only.post_only = Some(true);
+        assert!(simulate_fill(&sim_book(), &post_only).unwrap().rejected);
+    }
+
+    fn strike_market(ticker: &str, strike_type: &str, floor: Option<f64>, cap: Option<f64>) -> Market {
+        let mut market = sample_market();
+        market.ticker = ticker.to_string();
+        market.strike_type = Some(strike_type.parse().unwrap());
+        market.floor_strike = floor;
+        market.cap_strike = cap;
+        market
+    }
+
+    fn tickers(markets: &[&Market]) -> Vec<String> {
+        markets.iter().map(|m| m.ticker.clone()).collect()
+    }
+
+    #[test]
+    fn test_classify_ladder() {
+        let above = vec![
+            strike_market("A90", "greater", Some(90.0), None),
+            strike_market("A80", "greater_or_equal", Some(80.0), None),
+        ];
+        let (kind, ordered) = classify_ladder(&above);
+        assert_eq!(kind, LadderKind::Above);
+        assert_eq!(tickers(&ordered), ["A80", "A90"]);
+
+        let buckets = vec![
+            strike_market("HI", "greater", Some(90.0), None),
+            strike_market("B85", "between", Some(85.0), Some(89.99)),
+            strike_market("LO", "less", None, Some(80.0)),
+            strike_market("B80", "between", Some(80.0), Some(84.99)),
+        ];
+        let (kind, ordered) = classify_ladder(&buckets);
+        assert_eq!(kind, LadderKind::Between);
+        assert_eq!(tickers(&ordered), ["LO", "B80", "B85", "HI"]);
+
+        let below = vec![
+            strike_market("L2", "less", None, Some(2.0)),
+            strike_market("L1", "less_or_equal", None, Some(1.0)),
+        ];
+        assert_eq!(classify_ladder(&below).0, LadderKind::Below);
+
+        let mixed = vec![above[0].clone(), below[0].clone()];
+        let (kind, ordered) = classify_ladder(&mixed);
+        assert_eq!(kind, LadderKind::Independent);
+        assert_eq!(tickers(&ordered), ["A90", "L2"]);
+
+        let missing_strike = vec![above[0].clone(), strike_market("A?", "greater", None, None)];
+        assert_eq!(classify_ladder(&missing_strike).0, LadderKind::Independent);
+        assert_eq!(classify_ladder(&above[..1]).0, LadderKind::Independent);
+    }
+
+    #[test]
+    fn test_market_status_transitions() {
+        use MarketStatus::*;
+        let all = [Initialized, Inactive, Active, Closed, Determined, Disputed, Amended, Finalized];
+        let legal = [
+            (Initialized, Active),
+            (Initialized, Inactive),
+            (Active, Inactive),
+            (Active, Closed),
+            (Inactive, Active),
+            (Inactive, Closed),
+            (Closed, Determined),
+            (Determined, Disputed),
+            (Determined, Finalized),
+            (Disputed, Determined),
+            (Disputed, Amended),
+            (Amended, Disputed),
+            (Amended, Finalized),
+        ];
+        for from in &all {
+            for to in &all {
+                let expected = from == to || legal.contains(&(from.clone(), to.clone()));
+                assert_eq!(from.can_transition_to(to), expected, "{from} -> {to}");
+            }
+        }
+
+        let unknown = Unknown("paused".to_string());
+        assert!(!Active.can_transition_to(&unknown));
+        assert!(!unknown.can_transition_to(&Active));
+        assert!(!unknown.can_transition_to(&unknown));
+    }
+
+    #[test]
+    fn test_market_from_response_value() {
+        let bare = serde_json::to_value(sample_market()).unwrap();
+        let wrapped = serde_json::json!({ "market": bare.clone() });
+
+        assert_eq!(Market::from_response_value(bare).unwrap().ticker, "EVENT-T1");
+        assert_eq!(Market::from_response_value(wrapped).unwrap().ticker, "EVENT-T1");
+
+        let err = Market::from_response_value(serde_json::json!({ "markets": [] })).unwrap_err();
+        assert!(matches!(err, KalshiError::UserInputError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_orderbook_validates_depth() {
+        // No server: an invalid depth must fail before any request is built.
+        let client = crate::auth::test::offline_client();
+        for depth in [0, -1, 101] {
+            assert!(matches!(
+                client.get_orderbook("SOME-MARKET", Some(depth)).await,
+                Err(KalshiError::UserInputError(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_series_contract_requires_https() {
+        let series = |url: &str| {
+            let series = serde_json::json!({
+                "additional_prohibitions": [],
+                "category": "Climate",
+                "contract_terms_url": "",
+                "contract_url": url,
+                "fee_multiplier": 1.0,
+                "fee_type": "quadratic",
+                "frequency": "daily",
+                "settlement_sources": [],
+                "tags": [],
+                "ticker": "KXHIGHNY",
+                "title": "NYC high temperature"
+            });
+            (200, serde_json::json!({ "series": series }).to_string())
+        };
+        let client = crate::auth::test::mock_client(vec![
+            series(""),
+            series("http://kalshi-public-docs.s3.amazonaws.com/contract.pdf"),
+        ])
+        .await;
+
+        for _ in 0..2 {
+            let err = client.get_series_contract("KXHIGHNY").await.unwrap_err();
+            assert!(matches!(err, KalshiError::UserInputError(_)), "{:?}", err);
+        }
+    }
+
+    #[test]
+    fn test_series_estimated_next_event() {
+        let series = |frequency: &str| -> Series {
+            serde_json::from_value(serde_json::json!({
+                "additional_prohibitions": [],
+                "category": "Climate",
+                "contract_terms_url": "",
+                "contract_url": "",
+                "fee_multiplier": 1.0,
+                "fee_type": "quadratic",
+                "frequency": frequency,
+                "settlement_sources": [],
+                "tags": [],
+                "ticker": "KXHIGHNY",
+                "title": "NYC high temperature"
+            }))
+            .unwrap()
+        };
+        // A Wednesday afternoon at the end of December.
+        let after = Utc.with_ymd_and_hms(2025, 12, 31, 15, 30, 0).unwrap();
+        let next = |frequency: &str| series(frequency).estimated_next_event(after);
+
+        assert_eq!(next("hourly"), Some(Utc.with_ymd_and_hms(2025, 12, 31, 16, 0, 0).unwrap()));
+        assert_eq!(next("Daily"), Some(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()));
+        assert_eq!(next("weekly"), Some(Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap()));
+        assert_eq!(next("monthly"), Some(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()));
+        assert_eq!(next("annual"), Some(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()));
+        assert_eq!(next("one-off"), None);
+        assert_eq!(next("custom"), None);
+
+        let monday = Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+        assert_eq!(
+            series("weekly").estimated_next_event(monday),
+            Some(Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap())
+        );
+        assert_eq!(series("custom").frequency_kind(), SeriesFrequency::Other("custom".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_paginator_stops_on_rejected_cursor() {
+        let page = |cursor: &str| (200, format!(r#"{{"cursor":"{}","markets":[]}}"#, cursor));
+        let client = crate::auth::test::mock_client(vec![
+            page("page-2"),
+            page("bogus&cursor=%zz"),
+            (400, r#"{"error":{"code":"bad_request","message":"invalid cursor"}}"#.to_string()),
+        ])
+        .await;
+        match client.markets_grouped_by_event(&MarketFilter::default()).await {
+            Err(KalshiError::InvalidCursor { cursor }) => assert_eq!(cursor, "bogus&cursor=%zz"),
+            other => panic!("expected InvalidCursor, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_stops_on_repeated_cursor() {
+        let page = (200, r#"{"cursor":"stuck","markets":[]}"#.to_string());
+        let client = crate::auth::test::mock_client(vec![page.clone(), page]).await;
+        match client.markets_grouped_by_event(&MarketFilter::default()).await {
+            Err(KalshiError::InvalidCursor { cursor }) => assert_eq!(cursor, "stuck"),
+            other => panic!("expected InvalidCursor, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_markets_lenient_skips_malformed_markets() {
+        let good = serde_json::to_value(sample_market()).unwrap();
+        let body = serde_json::json!({
+            "cursor": "next",
+            "markets": [good.clone(), { "ticker": "BROKEN" }, good],
+        });
+        let client = crate::auth::test::mock_client(vec![(200, body.to_string())]).await;
+        let page = client.get_markets_lenient(&MarketFilter::default(), None, None).await.unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.skipped.len(), 1);
+        assert_eq!(page.skipped[0].index, 1);
+        assert_eq!(page.skipped[0].raw["ticker"], "BROKEN");
+        assert_eq!(page.cursor.as_deref(), Some("next"));
+    }
+
+    #[test]
+    fn test_interpret_result() {
+        let mut market = sample_market();
+        assert_eq!(market.interpret_result(), ResolvedOutcome::Pending);
+
+        market.result = MarketResult::Yes;
+        market.strike_type = Some(MarketStrikeType::Between);
+        market.floor_strike = Some(70.0);
+        market.cap_strike = Some(74.0);
+        market.expiration_value = "72.5".to_string();
+        assert_eq!(market.interpret_result().to_string(), "Yes: value was in [70, 74] (settled on 72.5)");
+
+        market.result = MarketResult::No;
+        market.strike_type = Some(MarketStrikeType::GreaterOrEqual);
+        market.expiration_value = String::new();
+        assert_eq!(market.interpret_result().to_string(), "No: value was not at or above 70");
+
+        market.strike_type = Some(MarketStrikeType::Less);
+        market.cap_strike = None;
+        assert_eq!(
+            market.interpret_result(),
+            ResolvedOutcome::Binary { yes: false, condition: None, value: None }
+        );
+
+        market.market_type = MarketMarketType::Scalar;
+        market.result = MarketResult::Scalar;
+        market.settlement_value_dollars = Some(FixedPointDollars("0.4500".to_string()));
+        market.expiration_value = "45".to_string();
+        assert_eq!(market.interpret_result().to_string(), "Scalar: $0.4500 per yes contract (settled on 45)");
+
+        market.result = MarketResult::Unknown("void".to_string());
+        market.market_type = MarketMarketType::Binary;
+        assert_eq!(market.interpret_result(), ResolvedOutcome::Unknown("void".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_collect_markets_follows_cursor() {
+        let page = |cursor: &str, tickers: &[&str]| {
+            let markets: Vec<_> = tickers
+                .iter()
+                .map(|t| {
+                    let mut market = serde_json::to_value(sample_market()).unwrap();
+                    market["ticker"] = (*t).into();
+                    market
+                })
+                .collect();
+            (200, serde_json::json!({ "cursor": cursor, "markets": markets }).to_string())
+        };
+        let client = crate::auth::test::mock_client(vec![
+            page("c1", &["A", "B"]),
+            page("c2", &[]),
+            page("", &["C"]),
+        ])
+        .await;
+        let markets = client.collect_markets(&MarketFilter::default()).await.unwrap();
+        let tickers: Vec<_> = markets.iter().map(|m| m.ticker.as_str()).collect();
+        assert_eq!(tickers, ["A", "B", "C"]);
+
+        let client = crate::auth::test::mock_client(vec![page("stuck", &["A"]), page("stuck", &[])]).await;
+        let items: Vec<_> = client.markets_stream(MarketFilter::default()).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(matches!(&items[1], Err(KalshiError::InvalidCursor { cursor }) if cursor == "stuck"));
+    }
+
+    #[tokio::test]
+    async fn test_markets_request_builder() {
+        let request = GetMarketsRequest::new().status("open").event_ticker("X").tickers(["A", "B"]).limit(50);
+        assert_eq!(request.filter.status.as_deref(), Some("open"));
+        assert_eq!(request.filter.event_ticker.as_deref(), Some("X"));
+        assert_eq!(request.filter.tickers.as_deref(), Some("A,B"));
+        assert_eq!((request.limit, request.cursor.as_deref()), (Some(50), None));
+
+        let mut market = serde_json::to_value(sample_market()).unwrap();
+        market["ticker"] = "A".into();
+        let page = serde_json::json!({ "cursor": "next", "markets": [market] });
+        let client = crate::auth::test::mock_client(vec![(200, page.to_string())]).await;
+        let (cursor, markets) = client.markets(request).await.unwrap();
+        assert_eq!((cursor.as_deref(), markets[0].ticker.as_str()), (Some("next"), "A"));
+    }
+
+    #[test]
+    fn test_market_query_matches() {
+        let mut market = sample_market();
+        market.title = Some("Highest temperature in NYC".to_string());
+        assert!(MarketQuery::default().matches(&market));
+
+        let query = |f: fn(&mut MarketQuery)| {
+            let mut query = MarketQuery::default();
+            f(&mut query);
+            query.matches(&market)
+        };
+        assert!(query(|q| q.title_contains = Some("TEMPERATURE".into())));
+        assert!(!query(|q| q.title_contains = Some("rain".into())));
+        assert!(query(|q| (q.min_last_price, q.max_last_price) = (Some(0.5), Some(0.5))));
+        assert!(!query(|q| q.min_last_price = Some(0.51)));
+        assert!(!query(|q| q.max_last_price = Some(0.49)));
+        assert!(query(|q| q.min_volume = Some(250)));
+        assert!(!query(|q| q.min_volume = Some(251)));
+    }
+
+    #[tokio::test]
+    async fn test_search_markets_filters_client_side() {
+        let market = |ticker: &str, event: &str, title: &str| {
+            let mut market = serde_json::to_value(sample_market()).unwrap();
+            market["ticker"] = ticker.into();
+            market["event_ticker"] = event.into();
+            market["title"] = title.into();
+            market
+        };
+        let series = serde_json::json!({
+            "additional_prohibitions": [], "category": "Climate", "contract_terms_url": "",
+            "contract_url": "", "fee_multiplier": 1.0, "fee_type": "quadratic", "frequency": "daily",
+            "settlement_sources": [], "tags": [], "ticker": "KXHIGHNY", "title": "NYC high"
+        });
+        let markets = vec![
+            market("KXHIGHNY-A", "KXHIGHNY-25JUN01", "NYC high above 80"),
+            market("KXHIGHNY-B", "KXHIGHNY-25JUN01", "NYC rain"),
+            market("KXHIGHNYC-A", "KXHIGHNYC-25JUN01", "NYC high above 80"),
+        ];
+        let client = crate::auth::test::mock_client(vec![
+            (200, serde_json::json!({ "series": [series] }).to_string()),
+            (200, serde_json::json!({ "cursor": "", "markets": markets }).to_string()),
+            (200, serde_json::json!({ "series": [] }).to_string()),
+        ])
+        .await;
+        let query = MarketQuery {
+            title_contains: Some("high".to_string()),
+            category: Some("Climate".to_string()),
+            ..Default::default()
+        };
+        let found = client.search_markets(query.clone()).await.unwrap();
+        let tickers: Vec<_> = found.iter().map(|m| m.ticker.as_str()).collect();
+        assert_eq!(tickers, ["KXHIGHNY-A"]);
+
+        // A category without series ends the search before scanning markets.
+        assert!(client.search_markets(query).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_with_checkpoints_resumes() {
+        let page = |cursor: &str, ticker: &str| {
+            let mut market = serde_json::to_value(sample_market()).unwrap();
+            market["ticker"] = ticker.into();
+            (200, serde_json::json!({ "cursor": cursor, "markets": [market] }).to_string())
+        };
+        let (client, mut requests) =
+            crate::auth::test::recording_mock_client(vec![page("c2", "B"), page("", "C")]).await;
+        let items: Vec<_> = client
+            .markets_stream_with_checkpoints(MarketFilter::default(), Some("c1".to_string()))
+            .map(|item| match item.unwrap() {
+                MarketStreamItem::Market(market) => market.ticker,
+                MarketStreamItem::Checkpoint(checkpoint) => format!("checkpoint {}", checkpoint.cursor),
+            })
+            .collect()
+            .await;
+        assert_eq!(items, ["B", "checkpoint c2", "C"]);
+        assert!(requests.recv().await.unwrap().contains("cursor=c1"));
+        assert!(requests.recv().await.unwrap().contains("cursor=c2"));
+    }
+
+    #[test]
+    fn test_market_stable_json_round_trips() {
+        let mut market = sample_market();
+        market.title = Some("Will it happen?".to_string());
+        market.floor_strike = Some(42.5);
+
+        let stored = market.to_stable_json();
+        assert_eq!(stored["schema_version"], 1);
+        assert_eq!(stored["yes_ask_dollars"], "0.5400");
+        assert_eq!(stored["status"], "active");
+        assert_eq!(stored["close_time"], "2030-01-01T00:00:00Z");
+        assert_eq!(stored["created_time"], "2024-01-01T00:00:00Z");
+        assert!(stored.get("price_level_structure").is_none());
+
+        let restored = Market::from_stable_json(stored.clone()).unwrap();
+        assert_eq!(restored.to_stable_json(), stored);
+        assert_eq!((restored.status, restored.floor_strike), (MarketStatus::Active, Some(42.5)));
+
+        let mut future = stored.clone();
+        future["schema_version"] = 2.into();
+        assert!(matches!(Market::from_stable_json(future), Err(KalshiError::UserInputError(_))));
+        let mut zero = stored.clone();
+        zero["schema_version"] = 0.into();
+        assert!(matches!(Market::from_stable_json(zero), Err(KalshiError::UserInputError(_))));
+
+        let mut without_created = stored.clone();
+        without_created.as_object_mut().unwrap().remove("created_time");
+        let restored = Market::from_stable_json(without_created).unwrap();
+        assert_eq!(restored.created_time, restored.open_time);
+        let mut unversioned = stored;
+        unversioned.as_object_mut().unwrap().remove("schema_version");
+        assert!(Market::from_stable_json(unversioned).is_err());
+    }
+
+    #[test]
+    fn test_best_entry_for() {
+        let mut market = sample_market();
+        // yes ask 0.54 vs selling no at 0.46: the same 0.54 either way, so buy.
+        let plan = market.best_entry_for(OrderSide::Yes).unwrap();
+        assert_eq!((plan.action, plan.side, plan.price_dollars), (OrderAction::Buy, OrderSide::Yes, 0.54));
+
+        market.no_bid_dollars = FixedPointDollars("0.4800".to_string());
+        let plan = market.best_entry_for(OrderSide::Yes).unwrap();
+        assert_eq!((plan.action, plan.side), (OrderAction::Sell, OrderSide::No));
+        assert_eq!((plan.price_dollars, plan.effective_cost_dollars), (0.48, 0.52));
+
+        // no ask 0.52 vs selling yes at 0.48, which costs 0.52 too.
+        assert_eq!(market.best_entry_for(OrderSide::No).unwrap().action, OrderAction::Buy);
+        market.no_ask_dollars = FixedPointDollars("0.0000".to_string());
+        assert_eq!(market.best_entry_for(OrderSide::No).unwrap().side, OrderSide::Yes);
+        assert_eq!(market.best_entry_for(OrderSide::Unknown("maybe".to_string())), None);
+    }
+
+    #[test]
+    fn test_market_edge_vs() {
+        struct Fixed(Option<f64>);
+        impl FairValueModel for Fixed {
+            fn fair_yes_prob(&self, _: &Market) -> Option<f64> {
+                self.0
+            }
+        }
+
+        let mut market = sample_market();
+        assert_eq!(market.yes_mid_dollars(), Some(0.51));
+        assert!((market.edge_vs(&Fixed(Some(0.60))).unwrap() - 0.09).abs() < 1e-9);
+        assert!(market.edge_vs(&Fixed(Some(0.40))).unwrap() < 0.0);
+        assert_eq!(market.edge_vs(&Fixed(None)), None);
+
+        market.yes_bid_dollars = FixedPointDollars("0.0000".into());
+        assert_eq!(market.edge_vs(&Fixed(Some(0.60))), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_candlesticks_multi_isolates_errors() {
+        let candles = serde_json::json!({
+            "candlesticks": [serde_json::to_value(candle(60, ["0.5000", "0.5500", "0.4500", "0.5200"], "5.00")).unwrap()],
+            "market_ticker": "A-1"
+        });
+        let client = crate::auth::test::mock_client(vec![
+            (200, candles.to_string()),
+            (500, "{}".to_string()),
+        ])
+        .await;
+        let results = client
+            .get_candlesticks_multi(vec![CandleRequest::new("A-1", "A", 60), CandleRequest::new("B-1", "B", 60)], 1)
+            .await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results["A-1"].as_ref().unwrap().len(), 1);
+        assert!(matches!(
+            results["B-1"],
+            Err(KalshiError::RequestError(RequestError::ServerError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_candlesticks_range_stitches_chunks() {
+        let page = |ends: &[i64]| {
+            let candles: Vec<_> = ends
+                .iter()
+                .map(|end| serde_json::to_value(candle(*end, ["0.5000", "0.5500", "0.4500", "0.5200"], "5.00")).unwrap())
+                .collect();
+            serde_json::json!({ "candlesticks": candles, "market_ticker": "A-1" }).to_string()
+        };
+        // 6000 one-minute periods take two requests of at most 5000.
+        let client = crate::auth::test::mock_client(vec![
+            (200, page(&[60, 120])),
+            (200, page(&[300_060, 120])),
+        ])
+        .await;
+        let candles = client.get_candlesticks_range("A-1", "A", 0, 6000 * 60, 1).await.unwrap();
+        let ends: Vec<i64> = candles.iter().map(|c| c.end_period_ts).collect();
+        assert_eq!(ends, vec![60, 120, 300_060]);
+
+        assert!(client.get_candlesticks_range("A-1", "A", 10, 0, 1).await.unwrap().is_empty());
+        assert!(matches!(
+            client.get_candlesticks_range("A-1", "A", 0, 60, 0).await,
+            Err(KalshiError::UserInputError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_by_taker_side() {
+        let trade = |id: &str, side: &str| serde_json::to_value(trade_with(id, side, "2024-01-01T00:00:00Z")).unwrap();
+        let client = crate::auth::test::mock_client(vec![
+            (200, serde_json::json!({ "cursor": "c1", "trades": [trade("1", "yes"), trade("2", "no")] }).to_string()),
+            (200, serde_json::json!({ "cursor": "", "trades": [trade("3", "yes")] }).to_string()),
+        ])
+        .await;
+        let trades = client
+            .get_trades_by_taker_side(None, None, None, TradeTakerSide::Yes)
+            .await
+            .unwrap();
+        let ids: Vec<_> = trades.iter().map(|t| t.trade_id.as_str()).collect();
+        assert_eq!(ids, ["1", "3"]);
+    }
+
+    #[test]
+    fn test_trade_created_ts() {
+        let trade = |time: &str| trade_with("1", "yes", time);
+        assert_eq!(trade("2024-01-01T00:00:00Z").created_ts(), 1704067200);
+        assert_eq!(trade("2024-01-01T02:00:00+02:00").created_ts(), 1704067200);
+        assert_eq!(trade("2023-12-31T19:30:00.750-04:30").created_ts(), 1704067200);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_trades() {
+        let trade = |id: &str, time: &str| serde_json::to_value(trade_with(id, "yes", time)).unwrap();
+        let pages = || {
+            vec![
+                (
+                    200,
+                    serde_json::json!({ "cursor": "c1", "trades": [
+                        trade("4", "2024-01-01T00:00:03Z"),
+                        trade("3", "2024-01-01T00:00:02Z"),
+                        trade("2", "2024-01-01T00:00:02Z"),
+                    ] })
+                    .to_string(),
+                ),
+                (200, serde_json::json!({ "cursor": "", "trades": [trade("1", "2024-01-01T00:00:01Z")] }).to_string()),
+            ]
+        };
+
+        let client = crate::auth::test::mock_client(pages()).await;
+        let trades = client.get_all_trades("SOME-MARKET", None, None, Some(4)).await.unwrap();
+        let ids: Vec<_> = trades.iter().map(|t| t.trade_id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3", "4"]);
+
+        let client = crate::auth::test::mock_client(pages()).await;
+        let capped = client.get_all_trades("SOME-MARKET", None, None, Some(2)).await;
+        assert!(matches!(capped, Err(KalshiError::UserInputError(_))));
+
+        let client = crate::auth::test::mock_client(vec![
+            pages().remove(0),
+            (400, r#"{"error":{"code":"bad_request","message":"invalid cursor"}}"#.to_string()),
+        ])
+        .await;
+        match client.get_all_trades("SOME-MARKET", None, None, None).await {
+            Err(KalshiError::InvalidCursor { cursor }) => assert_eq!(cursor, "c1"),
+            other => panic!("expected InvalidCursor, got {:?}", other.map(|t| t.len())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_market_coalesces_concurrent_requests() {
+        let body = serde_json::json!({ "market": sample_market() }).to_string();
+        // A single response: any second request would be refused by the mock server.
+        let mut client = crate::auth::test::mock_client(vec![(200, body)]).await;
+        client.set_market_cache_ttl(Some(Duration::from_secs(60)));
+
+        let (a, b, c) = tokio::join!(
+            client.get_market("SOME-MARKET"),
+            client.get_market("SOME-MARKET"),
+            client.get_market("SOME-MARKET"),
+        );
+        assert_eq!(a.unwrap().ticker, b.unwrap().ticker);
+        assert!(c.is_ok());
+        assert!(client.get_market("SOME-MARKET").await.is_ok());
+
+        client.set_market_cache_ttl(None);
+        assert!(client.get_market("SOME-MARKET").await.is_err());
+    }
+}
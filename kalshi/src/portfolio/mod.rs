@@ -1,20 +1,42 @@
 use super::Kalshi;
 use crate::kalshi_error::*;
+use crate::utils::{next_cursor, with_query};
 use serde::{Deserialize, Deserializer, Serialize};
 
 // All public types are re-exported from the OpenAPI-generated module.
 pub use crate::generated::types::{
     AmendOrderRequest, CreateOrderRequest, CreateOrderRequestAction, CreateOrderRequestSide,
     CreateOrderRequestTimeInForce, CreateSubaccountResponse, DecreaseOrderRequest, EventPosition,
-    Fill, FillAction as Action, FillSide as Side, FixedPointCount, FixedPointDollars,
-    MarketPosition, Order, OrderAction, OrderGroup, OrderQueuePosition, OrderSide, OrderStatus,
-    OrderType, SelfTradePreventionType, Settlement, SubaccountBalance, SubaccountNettingConfig,
-    SubaccountTransfer,
+    Fill, FixedPointCount, FixedPointDollars, GetBalanceResponse, MarketPosition, Order, OrderGroup,
+    OrderQueuePosition, Settlement, SubaccountBalance, SubaccountNettingConfig, SubaccountTransfer,
 };
+pub use crate::enums::{Action, Side};
+use crate::enums::{OrderStatus, SettlementMarketResult};
+use crate::Trade;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+/// Alias for [`GetBalanceResponse`] — the account balance and portfolio value, in cents.
+pub type Balance = GetBalanceResponse;
 
 const PORTFOLIO_PATH: &str = "/portfolio";
 
-impl<'a> Kalshi {
+/// Page size used when a helper walks every page of `/portfolio/orders`.
+const ORDERS_PAGE_LIMIT: i32 = 200;
+
+/// Page size used when a helper walks every page of `/portfolio/fills`.
+const FILLS_PAGE_LIMIT: i32 = 200;
+
+/// Page size used when a helper walks every page of `/portfolio/settlements`.
+const SETTLEMENTS_PAGE_LIMIT: i64 = 200;
+
+/// Most orders the batch cancel endpoint accepts in one request.
+const CANCEL_BATCH_SIZE: usize = 20;
+
+/// How often [`Kalshi::create_order_with_timeout`] checks an order's status.
+const ORDER_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl Kalshi {
     /// Retrieves the current balance of the authenticated user from the Kalshi exchange.
     ///
     /// This method fetches the user's balance, requiring a valid authentication token.
@@ -37,6 +59,30 @@ impl<'a> Kalshi {
         Ok(result.balance)
     }
 
+    /// Retrieves the authenticated user's balance together with their portfolio value.
+    ///
+    /// Unlike [`get_balance`](Kalshi::get_balance), which returns only the available
+    /// balance, this keeps every field of `/portfolio/balance`. Both amounts are integer
+    /// cents.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Balance)`: The available balance, the value of open positions (both in cents)
+    ///   and when they were last updated.
+    /// - `Err(KalshiError)`: An error if the user is not authenticated or if there is an issue with the request.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let balance = kalshi_instance.get_balance_details().await.unwrap();
+    /// println!("cash {}¢, positions {}¢", balance.balance, balance.portfolio_value);
+    /// ```
+    ///
+    pub async fn get_balance_details(&self) -> Result<Balance, KalshiError> {
+        self.signed_get(&format!("{}/balance", PORTFOLIO_PATH)).await
+    }
+
     /// Retrieves a list of orders from the Kalshi exchange based on specified criteria.
     ///
     /// This method fetches multiple orders, allowing for filtering by ticker, event ticker, time range,
@@ -49,7 +95,7 @@ impl<'a> Kalshi {
     /// * `event_ticker` - An optional string to filter orders by event ticker.
     /// * `min_ts` - An optional minimum timestamp for order creation time.
     /// * `max_ts` - An optional maximum timestamp for order creation time.
-    /// * `status` - An optional string to filter orders by their status.
+    /// * `status` - An optional [`OrderStatus`] to filter orders by, such as `Resting` or `Executed`.
     /// * `limit` - An optional integer to limit the number of orders returned.
     /// * `cursor` - An optional string for pagination cursor.
     ///
@@ -64,10 +110,11 @@ impl<'a> Kalshi {
     /// ```
     /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
     /// let orders = kalshi_instance.get_orders(
-    ///     Some("ticker_name"), None, None, None, None, None, None
+    ///     Some("ticker_name".to_string()), None, None, None, Some(OrderStatus::Resting), Some(100), None
     /// ).await.unwrap();
     /// ```
     ///
+    #[allow(clippy::too_many_arguments)]
     pub async fn get_orders(
         &self,
         ticker: Option<String>,
@@ -88,19 +135,10 @@ impl<'a> Kalshi {
         add_param!(params, "event_ticker", event_ticker);
         add_param!(params, "status", status.map(|s| s.to_string()));
 
-        let path = if params.is_empty() {
-            format!("{}/orders", PORTFOLIO_PATH)
-        } else {
-            let query_string = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("{}/orders?{}", PORTFOLIO_PATH, query_string)
-        };
-
-        let result: MultipleOrderResponse = self.signed_get(&path).await?;
-        return Ok((result.cursor, result.orders));
+        let path = with_query(format!("{}/orders", PORTFOLIO_PATH), &params)?;
+        let result: MultipleOrderResponse =
+            self.signed_get_with_cursor(&path, cursor.as_deref()).await?;
+        Ok((result.cursor, result.orders))
     }
 
     /// Retrieves detailed information about a specific order from the Kalshi exchange.
@@ -128,7 +166,7 @@ impl<'a> Kalshi {
     pub async fn get_single_order(&self, order_id: &String) -> Result<Order, KalshiError> {
         let path = format!("{}/orders/{}", PORTFOLIO_PATH, order_id);
         let result: SingleOrderResponse = self.signed_get(&path).await?;
-        return Ok(result.order);
+        Ok(result.order)
     }
 
     /// Cancels an existing order on the Kalshi exchange.
@@ -157,6 +195,10 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn cancel_order(&self, order_id: &str) -> Result<(Order, i32, String), KalshiError> {
+        if self.dry_run {
+            log::info!(target: "kalshi::dry_run", "cancel order {}", order_id);
+            return Ok(dry_run_cancel(order_id));
+        }
         let path = format!("{}/orders/{}", PORTFOLIO_PATH, order_id);
         let result: DeleteOrderResponse = self.signed_delete(&path).await?;
         Ok((result.order, result.reduced_by, result.reduced_by_fp))
@@ -193,6 +235,10 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn decrease_order(&self, order_id: &str, req: DecreaseOrderRequest) -> Result<Order, KalshiError> {
+        if self.dry_run {
+            log::info!(target: "kalshi::dry_run", "decrease order {}: {}", order_id, dry_run_json(&req));
+            return Ok(dry_run_order(DryRunOrder { order_id: order_id.to_string(), ..Default::default() }));
+        }
         let path = format!("{}/orders/{}/decrease", PORTFOLIO_PATH, order_id);
         let result: DecreaseOrderResponse = self.signed_post(&path, &req).await?;
         Ok(result.order)
@@ -246,31 +292,82 @@ impl<'a> Kalshi {
         add_param!(params, "max_ts", max_ts);
         add_param!(params, "order_id", order_id);
 
-        let path = if params.is_empty() {
-            format!("{}/fills", PORTFOLIO_PATH)
-        } else {
-            let query_string = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("{}/fills?{}", PORTFOLIO_PATH, query_string)
-        };
+        let path = with_query(format!("{}/fills", PORTFOLIO_PATH), &params)?;
+        let result: MultipleFillsResponse =
+            self.signed_get_with_cursor(&path, cursor.as_deref()).await?;
+        Ok((result.cursor, result.fills))
+    }
 
-        let result: MultipleFillsResponse = self.signed_get(&path).await?;
-        return Ok((result.cursor, result.fills));
+    /// Retrieves every fill matching the filters, following the cursor across pages.
+    ///
+    /// Takes the same filters as [`get_fills`](Kalshi::get_fills), but requests the
+    /// largest page size and keeps going until the exchange reports no further page.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - An optional string to filter fills by market ticker.
+    /// * `order_id` - An optional string to filter fills by order ID.
+    /// * `min_ts` - An optional minimum timestamp for fill creation time.
+    /// * `max_ts` - An optional maximum timestamp for fill creation time.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Fill>)`: Every matching fill, in the order the pages returned them (newest first).
+    /// - `Err(KalshiError)`: An error if the user is not authenticated or if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let fills = kalshi_instance
+    ///     .get_all_fills(None, None, Some(1704067200), Some(1706745600))
+    ///     .await
+    ///     .unwrap();
+    /// ```
+    ///
+    pub async fn get_all_fills(
+        &self,
+        ticker: Option<String>,
+        order_id: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
+    ) -> Result<Vec<Fill>, KalshiError> {
+        let mut fills = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let (next, page) = self
+                .get_fills(
+                    ticker.clone(),
+                    order_id.clone(),
+                    min_ts,
+                    max_ts,
+                    Some(FILLS_PAGE_LIMIT),
+                    cursor.clone(),
+                )
+                .await?;
+            fills.extend(page);
+            cursor = next_cursor(cursor.as_deref(), next)?;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        Ok(fills)
     }
 
     /// Retrieves a list of portfolio settlements from the Kalshi exchange.
     ///
-    /// This method fetches settlements in the user's portfolio, with options for pagination using limit and cursor.
-    /// A valid authentication token is required to access this information.
-    /// If the user is not logged in or the token is missing, it returns an error.
+    /// This method fetches settlements in the user's portfolio, with options for filtering by ticker
+    /// and settlement time, and pagination using limit and cursor. A valid authentication token is
+    /// required to access this information. If the user is not logged in or the token is missing,
+    /// it returns an error.
     ///
     /// # Arguments
     ///
-    /// * `limit` - An optional integer to limit the number of settlements returned.
+    /// * `limit` - An optional integer to limit the number of settlements returned (at most 200).
     /// * `cursor` - An optional string for pagination cursor.
+    /// * `ticker` - An optional string to filter settlements by market ticker.
+    /// * `min_ts` - An optional minimum timestamp for the settlement time.
+    /// * `max_ts` - An optional maximum timestamp for the settlement time.
     ///
     /// # Returns
     ///
@@ -282,33 +379,80 @@ impl<'a> Kalshi {
     ///
     /// ```
     /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
-    /// let settlements = kalshi_instance.get_settlements(None, None).await.unwrap();
+    /// let settlements = kalshi_instance.get_settlements(None, None, None, None, None).await.unwrap();
+    /// for settlement in settlements.1 {
+    ///     println!("{}: {:?}, revenue {}¢", settlement.ticker, settlement.market_result, settlement.revenue);
+    /// }
     /// ```
     pub async fn get_settlements(
         &self,
         limit: Option<i64>,
         cursor: Option<String>,
+        ticker: Option<String>,
+        min_ts: Option<i64>,
+        max_ts: Option<i64>,
     ) -> Result<(Option<String>, Vec<Settlement>), KalshiError> {
-        let mut params: Vec<(&str, String)> = Vec::with_capacity(6);
+        let mut params: Vec<(&str, String)> = Vec::with_capacity(5);
 
         add_param!(params, "limit", limit);
         add_param!(params, "cursor", cursor);
+        add_param!(params, "ticker", ticker);
+        add_param!(params, "min_ts", min_ts);
+        add_param!(params, "max_ts", max_ts);
 
-        let path = if params.is_empty() {
-            format!("{}/settlements", PORTFOLIO_PATH)
-        } else {
-            let query_string = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("{}/settlements?{}", PORTFOLIO_PATH, query_string)
-        };
-
-        let result: PortfolioSettlementResponse = self.signed_get(&path).await?;
+        let path = with_query(format!("{}/settlements", PORTFOLIO_PATH), &params)?;
+        let result: PortfolioSettlementResponse =
+            self.signed_get_with_cursor(&path, cursor.as_deref()).await?;
         Ok((result.cursor, result.settlements))
     }
 
+    /// Builds a per-ticker profit and loss report for fills and settlements in a time range.
+    ///
+    /// Every page of `/portfolio/fills` and `/portfolio/settlements` in the range is
+    /// fetched, filtered server-side by `min_ts`/`max_ts`, then joined by ticker through
+    /// [`PnlReport::from_fills_and_settlements`].
+    ///
+    /// # Arguments
+    ///
+    /// * `min_ts` - Start of the reporting period (Unix seconds, inclusive).
+    /// * `max_ts` - End of the reporting period (Unix seconds, inclusive).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PnlReport)`: Realized P&L, fees and net result per ticker plus period totals.
+    /// - `Err(KalshiError)`: An error if the user is not authenticated or if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let report = kalshi_instance.pnl_report(1704067200, 1706745600).await.unwrap();
+    /// println!("Net P&L: ${:.2}", report.total_net_pnl_dollars);
+    /// ```
+    ///
+    pub async fn pnl_report(&self, min_ts: i64, max_ts: i64) -> Result<PnlReport, KalshiError> {
+        let fills = self.get_all_fills(None, None, Some(min_ts), Some(max_ts)).await?;
+
+        let mut settlements = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let (next, page) = self
+                .get_settlements(Some(SETTLEMENTS_PAGE_LIMIT), cursor.clone(), None, Some(min_ts), Some(max_ts))
+                .await?;
+            // The bounds are checked again because the API doesn't document whether they're inclusive.
+            settlements.extend(page.into_iter().filter(|s| {
+                let ts = s.settled_time.timestamp();
+                ts >= min_ts && ts <= max_ts
+            }));
+            cursor = next_cursor(cursor.as_deref(), next)?;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(PnlReport::from_fills_and_settlements(&fills, &settlements))
+    }
+
     /// Retrieves the user's positions in events and markets from the Kalshi exchange.
     ///
     /// This method fetches the user's positions, providing options for filtering by settlement status,
@@ -368,7 +512,6 @@ impl<'a> Kalshi {
         let page_limit = limit.unwrap_or(1000);
 
         loop {
-            
             let mut params: Vec<(&str, String)> = Vec::with_capacity(6);
             add_param!(params, "limit", Some(page_limit));
             add_param!(params, "cursor", current_cursor);
@@ -376,31 +519,19 @@ impl<'a> Kalshi {
             add_param!(params, "ticker", ticker);
             add_param!(params, "event_ticker", event_ticker);
 
-            let query_string = if params.is_empty() {
-                String::new()
-            } else {
-                let qs = params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&");
-                format!("?{}", qs)
-            };
+            let path = with_query(format!("{}/positions", PORTFOLIO_PATH), &params)?;
+            let result: GetPositionsResponse =
+                self.signed_get_with_cursor(&path, current_cursor.as_deref()).await?;
 
-            let path = format!("{}/positions{}", PORTFOLIO_PATH, query_string);
-
-            let result: GetPositionsResponse = self.signed_get(&path).await?;
-            
             all_event_positions.extend(result.event_positions);
             all_market_positions.extend(result.market_positions);
 
-            current_cursor = result.cursor;
-            
-            // If there's no cursor or it's empty, we've collected all pages.
-            if current_cursor.is_none() || current_cursor.as_ref().map(|s| s.is_empty()).unwrap_or(true) {
+            // A missing or empty cursor means every page has been collected.
+            current_cursor = next_cursor(current_cursor.as_deref(), result.cursor)?;
+            if current_cursor.is_none() {
                 break;
             }
-            
+
             // If a specific limit was requested and we have enough market positions, we can stop.
             if let Some(l) = limit {
                 if all_market_positions.len() >= l as usize {
@@ -416,61 +547,138 @@ impl<'a> Kalshi {
         ))
     }
 
+    /// Retrieves every event and market position in the portfolio.
+    ///
+    /// All pages of `/portfolio/positions` are fetched (at the maximum page size) and
+    /// collected into a [`Positions`] aggregate with lookup helpers.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Positions)`: All market and event positions on successful retrieval.
+    /// - `Err(KalshiError)`: An error if the user is not authenticated or if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let positions = kalshi_instance.get_all_positions().await.unwrap();
+    /// println!("Net exposure: ${:.2}", positions.net_exposure());
+    /// if let Some(position) = positions.position_for("SOME-MARKET-2024") {
+    ///     println!("Holding {:?}", position.position_fp);
+    /// }
+    /// ```
+    ///
+    pub async fn get_all_positions(&self) -> Result<Positions, KalshiError> {
+        let (_, event, market) = self.get_positions(None, None, None, None, None).await?;
+        Ok(Positions { market, event })
+    }
+
     /// Submits an order to the Kalshi exchange.
     ///
-    /// This method allows placing an order in the market, requiring details such as action, count, side,
-    /// ticker, order type, and other optional parameters. A valid authentication token is
-    /// required for this operation. Note that for limit orders, either `no_price` or `yes_price` must be provided,
-    /// but not both.
+    /// Posts `req` to the signed `/portfolio/orders` endpoint. Every order is a limit
+    /// order, so the request is checked before anything is sent: it must name a count
+    /// (`count` or `count_fp`) and exactly one price, one of `yes_price`,
+    /// `yes_price_dollars`, `no_price` or `no_price_dollars`. Time in force, expiration,
+    /// `client_order_id` and the other optional fields are passed through as given.
     ///
     /// # Arguments
     ///
-    /// * `action` - The action (buy/sell) of the order.
-    /// * `client_order_id` - An optional client-side identifier for the order.
-    /// * `count` - The number of shares or contracts to trade.
-    /// * `side` - The side (Yes/No) of the order.
-    /// * `ticker` - The market ticker the order is placed in.
-    /// * `input_type` - The type of the order (e.g., market, limit).
-    /// * `buy_max_cost` - The maximum cost for a buy order. Optional.
-    /// * `expiration_ts` - The expiration timestamp for the order. Optional.
-    /// * `no_price` - The price for the 'No' option in a limit order. Optional.
-    /// * `sell_position_floor` - The minimum position size to maintain after selling. Optional.
-    /// * `yes_price` - The price for the 'Yes' option in a limit order. Optional.
+    /// * `req` - The order to place.
     ///
     /// # Returns
     ///
     /// - `Ok(Order)`: The created `Order` object on successful placement.
-    /// - `Err(KalshiError)`: An error if the user is not authenticated, if both `no_price` and `yes_price` are provided for limit orders,
-    ///   or if there is an issue with the request.
+    /// - `Err(KalshiError)`: A `UserInputError` if the request has no count or not exactly
+    ///   one price, an error if the user is not authenticated, or if there is an issue with
+    ///   the request.
     ///
     /// # Example
     ///
     /// ```
     /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
-    /// let action = Action::Buy;
-    /// let side = Side::Yes;
-    /// let order = kalshi_instance.create_order(
-    ///     action,
-    ///     None,
-    ///     10,
-    ///     side,
-    ///     "example_ticker",
-    ///     OrderType::Limit,
-    ///     None,
-    ///     None,
-    ///     None,
-    ///     None,
-    ///     Some(100)
-    /// ).await.unwrap();
+    /// let req: CreateOrderRequest = serde_json::from_value(serde_json::json!({
+    ///     "ticker": "SOME-MARKET-2024",
+    ///     "action": "buy",
+    ///     "side": "yes",
+    ///     "count": 10,
+    ///     "yes_price_dollars": "0.4500",
+    ///     "time_in_force": "good_till_canceled",
+    /// })).unwrap();
+    /// let order = kalshi_instance.create_order(req).await.unwrap();
     /// ```
     ///
-    
     pub async fn create_order(&self, req: CreateOrderRequest) -> Result<Order, KalshiError> {
+        validate_order_request(&req)?;
+        if self.dry_run {
+            log::info!(target: "kalshi::dry_run", "create order: {}", dry_run_json(&req));
+            return Ok(dry_run_created(&req));
+        }
         let path = format!("{}/orders", PORTFOLIO_PATH);
         let result: SingleOrderResponse = self.signed_post(&path, &req).await?;
         Ok(result.order)
     }
 
+    /// Places an order and cancels whatever is still resting after `fill_timeout`.
+    ///
+    /// The order is submitted with [`create_order`](Kalshi::create_order) and its status
+    /// is polled every 500ms. As soon as it is no longer resting (fully executed, or
+    /// canceled by the exchange) it is returned. If it is still resting when the
+    /// timeout elapses, the remaining quantity is canceled and the final order state
+    /// is returned. Check `fill_count_fp` for how much filled.
+    ///
+    /// If the cancel fails because the order executed in the meantime, the order is
+    /// re-fetched and returned instead of the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The order to place.
+    /// * `fill_timeout` - How long to let the order rest before pulling it.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Order)`: The final state of the order: executed or canceled.
+    /// - `Err(KalshiError)`: An error if placing, polling or cancelling the order fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let order = kalshi_instance
+    ///     .create_order_with_timeout(req, std::time::Duration::from_secs(30))
+    ///     .await
+    ///     .unwrap();
+    /// println!("filled {:?} of {:?}", order.fill_count_fp, order.initial_count_fp);
+    /// ```
+    ///
+    pub async fn create_order_with_timeout(
+        &self,
+        req: CreateOrderRequest,
+        fill_timeout: Duration,
+    ) -> Result<Order, KalshiError> {
+        let deadline = tokio::time::Instant::now() + fill_timeout;
+        let mut order = self.create_order(req).await?;
+
+        while order.status == OrderStatus::Resting {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return match self.cancel_order(&order.order_id).await {
+                    Ok((canceled, _, _)) => Ok(canceled),
+                    Err(e) => {
+                        let latest = self.get_single_order(&order.order_id).await?;
+                        if latest.status == OrderStatus::Resting {
+                            Err(e)
+                        } else {
+                            Ok(latest)
+                        }
+                    }
+                };
+            }
+            tokio::time::sleep(ORDER_POLL_INTERVAL.min(deadline - now)).await;
+            order = self.get_single_order(&order.order_id).await?;
+        }
+        Ok(order)
+    }
+
     // -----------------------------------------------------------------
     // BATCH-CREATE  (POST  /portfolio/orders/batched)
     // -----------------------------------------------------------------
@@ -486,6 +694,18 @@ impl<'a> Kalshi {
                 "Batch size exceeds 20; split the request".into(),
             ));
         }
+        batch.iter().try_for_each(validate_order_request)?;
+
+        if self.dry_run {
+            log::info!(target: "kalshi::dry_run", "batch create {} orders", batch.len());
+            return Ok(batch
+                .iter()
+                .map(|req| {
+                    log::info!(target: "kalshi::dry_run", "create order: {}", dry_run_json(req));
+                    Ok(dry_run_created(req))
+                })
+                .collect());
+        }
 
         let path = format!("{}/orders/batched", PORTFOLIO_PATH);
         let body = BatchCreateOrderPayload { orders: batch };
@@ -509,19 +729,64 @@ impl<'a> Kalshi {
     // -----------------------------------------------------------------
     // BATCH-CANCEL (DELETE /portfolio/orders/batched)
     // -----------------------------------------------------------------
+    /// Cancels up to 20 orders in a single request.
+    ///
+    /// Each order succeeds or fails on its own, so one bad or already-closed id doesn't
+    /// fail the rest of the batch. Results come back in the order of `ids`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The ids of the orders to cancel; at most 20.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<Result<(Order, i32, String), KalshiError>>)`: Per order, either the
+    ///   canceled order with the count it was reduced by (as an integer and in fixed-point
+    ///   form), or a `UserInputError` naming the order and why it couldn't be canceled.
+    /// - `Err(KalshiError)`: A `UserInputError` if more than 20 ids are given, or an error
+    ///   if the request as a whole fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let results = kalshi_instance.batch_cancel_order(vec![id_a, id_b]).await.unwrap();
+    /// for result in results {
+    ///     match result {
+    ///         Ok((order, reduced_by, _)) => println!("{} reduced by {}", order.order_id, reduced_by),
+    ///         Err(e) => eprintln!("{}", e),
+    ///     }
+    /// }
+    /// ```
+    ///
     pub async fn batch_cancel_order(
         &self,
         ids: Vec<String>,
     ) -> Result<Vec<Result<(Order, i32, String), KalshiError>>, KalshiError> {
+        let items = self.batch_cancel_items(ids).await?;
+        Ok(items.into_iter().map(|(_, result)| result).collect())
+    }
+
+    /// Like [`Kalshi::batch_cancel_order`], but keeps the `order_id` the server echoed
+    /// with each result so callers don't have to rely on response order.
+    async fn batch_cancel_items(
+        &self,
+        ids: Vec<String>,
+    ) -> Result<Vec<(Option<String>, Result<(Order, i32, String), KalshiError>)>, KalshiError> {
         if ids.is_empty() {
             return Ok(Vec::new());
         }
-        if ids.len() > 20 {
+        if ids.len() > CANCEL_BATCH_SIZE {
             return Err(KalshiError::UserInputError(
                 "Batch size exceeds 20; split the request".into(),
             ));
         }
 
+        if self.dry_run {
+            log::info!(target: "kalshi::dry_run", "batch cancel orders {}", ids.join(", "));
+            return Ok(ids.iter().map(|id| (Some(id.clone()), Ok(dry_run_cancel(id)))).collect());
+        }
+
         let path = format!("{}/orders/batched", PORTFOLIO_PATH);
         let body = BatchCancelOrderPayload { ids };
 
@@ -529,19 +794,121 @@ impl<'a> Kalshi {
 
         let mut out = Vec::with_capacity(response.orders.len());
         for item in response.orders {
-            match (item.order, item.reduced_by, item.reduced_by_fp, item.error) {
-                (Some(order), Some(reduced_by), Some(reduced_by_fp), None) => out.push(Ok((order, reduced_by, reduced_by_fp))),
-                (_, _, _, Some(err)) => out.push(Err(KalshiError::UserInputError(
-                    err.message.unwrap_or_else(|| "unknown error".into()),
+            // Current responses only carry `reduced_by_fp`; derive the integer count from it.
+            let reduced_by = item.reduced_by.or_else(|| {
+                let fp = item.reduced_by_fp.as_deref()?;
+                fp.trim().parse::<f64>().ok().map(|count| count as i32)
+            });
+            let result = match (item.order, reduced_by, item.reduced_by_fp, item.error) {
+                (Some(order), Some(reduced_by), Some(reduced_by_fp), None) => Ok((order, reduced_by, reduced_by_fp)),
+                (_, _, _, Some(err)) => Err(KalshiError::UserInputError(format!(
+                    "cancel order {}: {}",
+                    item.order_id.as_deref().unwrap_or("(unknown)"),
+                    err.message.as_deref().unwrap_or("unknown error"),
                 ))),
-                _ => out.push(Err(KalshiError::InternalError(
+                _ => Err(KalshiError::InternalError(
                     "malformed batch-cancel response".into(),
-                ))),
-            }
+                )),
+            };
+            out.push((item.order_id, result));
         }
         Ok(out)
     }
 
+    /// Cancels every resting order, optionally only those in one market.
+    ///
+    /// Intended as a kill switch. All resting orders are listed first, then cancelled
+    /// through the batch endpoint in groups of 20. Every batch is attempted even when an
+    /// earlier one fails: if a whole batch request errors, its orders are cancelled one
+    /// at a time instead. Results are matched to orders by `order_id`, not by position.
+    ///
+    /// An order can fill or be cancelled elsewhere between being listed and being
+    /// cancelled; when its cancel fails, the order is fetched again and, if it is no
+    /// longer resting, its current state (usually `Executed`) is reported as a success.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - Only cancel orders in this market; `None` cancels across all markets.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(CancelAllReport)`: The final state of every order that is no longer resting,
+    ///   in the order they were listed, plus every order that could not be cancelled and
+    ///   why. Check [`CancelAllReport::is_complete`] before assuming the book is empty.
+    /// - `Err(KalshiError)`: An error if the resting orders could not be listed; nothing
+    ///   has been cancelled in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an already authenticated instance of `Kalshi`
+    /// let report = kalshi_instance.cancel_all_orders(None).await.unwrap();
+    /// for order in &report.orders {
+    ///     println!("{} is now {}", order.order_id, order.status);
+    /// }
+    /// for failure in &report.failures {
+    ///     eprintln!("{} still resting: {}", failure.order_id, failure.error);
+    /// }
+    /// ```
+    ///
+    pub async fn cancel_all_orders(&self, ticker: Option<String>) -> Result<CancelAllReport, KalshiError> {
+        let mut ids = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let (next, orders) = self
+                .get_orders(
+                    ticker.clone(),
+                    None,
+                    None,
+                    None,
+                    Some(OrderStatus::Resting),
+                    Some(ORDERS_PAGE_LIMIT),
+                    cursor.clone(),
+                )
+                .await?;
+            ids.extend(orders.into_iter().map(|o| o.order_id));
+            cursor = next_cursor(cursor.as_deref(), next)?;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        let mut report = CancelAllReport::default();
+        for batch in ids.chunks(CANCEL_BATCH_SIZE) {
+            let mut results: HashMap<String, Result<(Order, i32, String), KalshiError>> =
+                match self.batch_cancel_items(batch.to_vec()).await {
+                    Ok(items) => items
+                        .into_iter()
+                        .filter_map(|(id, result)| Some((id?, result)))
+                        .collect(),
+                    Err(_) => {
+                        let mut results = HashMap::with_capacity(batch.len());
+                        for id in batch {
+                            results.insert(id.clone(), self.cancel_order(id).await);
+                        }
+                        results
+                    }
+                };
+
+            for id in batch {
+                let result = results.remove(id).unwrap_or_else(|| {
+                    Err(KalshiError::InternalError(format!(
+                        "batch-cancel response has no result for order {}",
+                        id
+                    )))
+                });
+                match result {
+                    Ok((order, _, _)) => report.orders.push(order),
+                    Err(error) => match self.get_single_order(id).await {
+                        Ok(order) if order.status != OrderStatus::Resting => report.orders.push(order),
+                        _ => report.failures.push(CancelFailure { order_id: id.clone(), error }),
+                    },
+                }
+            }
+        }
+        Ok(report)
+    }
+
     /// Retrieves the total value of all resting orders for the authenticated user.
     ///
     /// This endpoint is primarily intended for use by FCM members.
@@ -726,7 +1093,7 @@ impl<'a> Kalshi {
 
         let url = format!("{}{}", self.base_url, path);
         let final_url = reqwest::Url::parse_with_params(&url, &params)?;
-        let res: QueuePositionsResponse = self.client.get(final_url).send().await?.json().await?;
+        let res: QueuePositionsResponse = self.send_json(self.client.get(final_url)).await?;
         Ok(res.queue_positions)
     }
 
@@ -757,6 +1124,23 @@ impl<'a> Kalshi {
     /// ```
     ///
     pub async fn amend_order(&self, order_id: &str, req: AmendOrderRequest) -> Result<Order, KalshiError> {
+        if self.dry_run {
+            log::info!(target: "kalshi::dry_run", "amend order {}: {}", order_id, dry_run_json(&req));
+            return Ok(dry_run_order(DryRunOrder {
+                order_id: order_id.to_string(),
+                ticker: req.ticker.clone(),
+                action: req.action.to_string(),
+                side: req.side.to_string(),
+                count_fp: request_count_fp(req.count_fp.as_ref(), req.count),
+                yes_price_dollars: request_yes_price(
+                    req.yes_price_dollars.as_ref(),
+                    req.yes_price,
+                    req.no_price_dollars.as_ref(),
+                    req.no_price,
+                ),
+                client_order_id: req.updated_client_order_id.clone().or(req.client_order_id.clone()),
+            }));
+        }
         let path = format!("/portfolio/orders/{}/amend", order_id);
         let res: SingleOrderResponse = self.signed_post(&path, &req).await?;
         Ok(res.order)
@@ -917,6 +1301,171 @@ impl<'a> Kalshi {
     }
 }
 
+// -------- Cancel all --------
+
+/// Outcome of [`Kalshi::cancel_all_orders`].
+#[derive(Debug, Default)]
+pub struct CancelAllReport {
+    /// Final state of every order that is no longer resting, in the order listed.
+    pub orders: Vec<Order>,
+    /// Orders that could not be cancelled and may still be resting.
+    pub failures: Vec<CancelFailure>,
+}
+
+impl CancelAllReport {
+    /// Whether every listed order was cancelled (or had already left the book).
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// An order [`Kalshi::cancel_all_orders`] could not cancel.
+#[derive(Debug)]
+pub struct CancelFailure {
+    /// The order that could not be cancelled.
+    pub order_id: String,
+    /// Why the cancel failed.
+    pub error: KalshiError,
+}
+
+// -------- Positions --------
+
+/// Every market and event position in the portfolio. See [`Kalshi::get_all_positions`].
+#[derive(Debug, Clone)]
+pub struct Positions {
+    /// Per-market positions.
+    pub market: Vec<MarketPosition>,
+    /// Per-event positions.
+    pub event: Vec<EventPosition>,
+}
+
+impl Positions {
+    /// Sum of `market_exposure_dollars` across all market positions, in dollars.
+    ///
+    /// Exposure is the cost of the contracts currently held, so this is the total
+    /// capital at risk in open positions. Unparseable values count as zero.
+    pub fn net_exposure(&self) -> f64 {
+        self.market
+            .iter()
+            .filter_map(|p| p.market_exposure_dollars.to_f64())
+            .sum()
+    }
+
+    /// The position in the market with this ticker, if any.
+    pub fn position_for(&self, ticker: &str) -> Option<&MarketPosition> {
+        self.market.iter().find(|p| p.ticker == ticker)
+    }
+
+    /// The position in the event with this ticker, if any.
+    pub fn event_position_for(&self, event_ticker: &str) -> Option<&EventPosition> {
+        self.event.iter().find(|p| p.event_ticker == event_ticker)
+    }
+
+    /// Market positions that currently hold contracts (non-zero `position_fp`).
+    pub fn open_positions(&self) -> impl Iterator<Item = &MarketPosition> {
+        self.market
+            .iter()
+            .filter(|p| p.position_fp.to_f64().is_some_and(|n| n != 0.0))
+    }
+}
+
+impl EventPosition {
+    /// Capital at risk across the whole event, in dollars.
+    ///
+    /// For mutually exclusive events the exchange nets the legs, so this can be well
+    /// below the sum of the per-market exposures. Unparseable values count as zero.
+    pub fn net_exposure(&self) -> f64 {
+        self.event_exposure_dollars.to_f64().unwrap_or(0.0)
+    }
+}
+
+// -------- P&L reporting --------
+
+/// Realized profit and loss for a single market ticker, in dollars.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TickerPnl {
+    /// The market ticker.
+    pub ticker: String,
+    /// Premium received from sells minus premium paid for buys.
+    pub trading_cash_flow_dollars: f64,
+    /// Payout received when the market settled.
+    pub settlement_revenue_dollars: f64,
+    /// Trading fees from fills plus any settlement fees.
+    pub fees_dollars: f64,
+    /// `trading_cash_flow_dollars + settlement_revenue_dollars`.
+    pub realized_pnl_dollars: f64,
+    /// `realized_pnl_dollars - fees_dollars`.
+    pub net_pnl_dollars: f64,
+    /// Whether a settlement for this ticker fell inside the period.
+    pub settled: bool,
+    /// The settlement result, if the ticker settled. `Void` settlements refund the
+    /// premium paid, so they net out to roughly zero.
+    pub market_result: Option<SettlementMarketResult>,
+}
+
+/// Profit and loss over a period, broken down by ticker. See [`Kalshi::pnl_report`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PnlReport {
+    /// Per-ticker results, sorted by ticker.
+    pub tickers: Vec<TickerPnl>,
+    /// Sum of `realized_pnl_dollars` across all tickers.
+    pub total_realized_pnl_dollars: f64,
+    /// Sum of `fees_dollars` across all tickers.
+    pub total_fees_dollars: f64,
+    /// Sum of `net_pnl_dollars` across all tickers.
+    pub total_net_pnl_dollars: f64,
+}
+
+impl PnlReport {
+    /// Joins fills and settlements by ticker into a report.
+    ///
+    /// Cash flows are only complete when the inputs cover every fill of a position. A
+    /// ticker that has not settled (and was not sold back to flat) therefore shows the
+    /// net premium paid so far as a negative realized P&L; check `settled` to tell the
+    /// two apart. Settlement `revenue` is reported by the API in cents and converted.
+    /// Fills with a side or action this crate doesn't recognise only contribute fees.
+    pub fn from_fills_and_settlements(fills: &[Fill], settlements: &[Settlement]) -> PnlReport {
+        let mut by_ticker: BTreeMap<&str, TickerPnl> = BTreeMap::new();
+
+        for fill in fills {
+            let entry = by_ticker.entry(&fill.ticker).or_default();
+            let count = fill.count_fp.to_f64().unwrap_or(0.0);
+            let price = match fill.side {
+                Side::Yes => fill.yes_price_dollars.to_f64(),
+                Side::No => fill.no_price_dollars.to_f64(),
+                Side::Unknown(_) => None,
+            }
+            .unwrap_or(0.0);
+            match fill.action {
+                Action::Buy => entry.trading_cash_flow_dollars -= price * count,
+                Action::Sell => entry.trading_cash_flow_dollars += price * count,
+                Action::Unknown(_) => {}
+            }
+            entry.fees_dollars += fill.fee_cost.to_f64().unwrap_or(0.0);
+        }
+
+        for settlement in settlements {
+            let entry = by_ticker.entry(&settlement.ticker).or_default();
+            entry.settlement_revenue_dollars += settlement.revenue as f64 / 100.0;
+            entry.fees_dollars += settlement.fee_cost.to_f64().unwrap_or(0.0);
+            entry.settled = true;
+            entry.market_result = Some(settlement.market_result.clone());
+        }
+
+        let mut report = PnlReport::default();
+        for (ticker, mut pnl) in by_ticker {
+            pnl.ticker = ticker.to_string();
+            pnl.realized_pnl_dollars = pnl.trading_cash_flow_dollars + pnl.settlement_revenue_dollars;
+            pnl.net_pnl_dollars = pnl.realized_pnl_dollars - pnl.fees_dollars;
+            report.total_realized_pnl_dollars += pnl.realized_pnl_dollars;
+            report.total_fees_dollars += pnl.fees_dollars;
+            report.total_net_pnl_dollars += pnl.net_pnl_dollars;
+            report.tickers.push(pnl);
+        }
+        report
+    }
+}
+
 // PRIVATE STRUCTS
 // used in getbalance method
 #[derive(Debug, Serialize, Deserialize)]
@@ -1006,6 +1555,7 @@ struct BatchCreateOrderResponseItem {
 /// One element in the `orders` array that the batch-cancel endpoint returns.
 #[derive(Debug, Serialize, Deserialize)]
 struct BatchCancelOrderResponseItem {
+    order_id: Option<String>,
     order: Option<Order>,
     reduced_by: Option<i32>,
     reduced_by_fp: Option<String>,
@@ -1022,6 +1572,161 @@ struct BatchCancelOrdersResponse {
     orders: Vec<BatchCancelOrderResponseItem>,
 }
 
+// -------- Order validation --------
+
+/// Checks that `req` names a count and exactly one limit price before it is sent.
+fn validate_order_request(req: &CreateOrderRequest) -> Result<(), KalshiError> {
+    if req.count.is_none() && req.count_fp.is_none() {
+        return Err(KalshiError::UserInputError(format!(
+            "order for {} needs a count or count_fp",
+            req.ticker
+        )));
+    }
+    let prices = [
+        req.yes_price.is_some(),
+        req.yes_price_dollars.is_some(),
+        req.no_price.is_some(),
+        req.no_price_dollars.is_some(),
+    ];
+    match prices.iter().filter(|set| **set).count() {
+        1 => Ok(()),
+        0 => Err(KalshiError::UserInputError(format!(
+            "limit order for {} needs one of yes_price, yes_price_dollars, no_price or no_price_dollars",
+            req.ticker
+        ))),
+        _ => Err(KalshiError::UserInputError(format!(
+            "limit order for {} must set only one of yes_price, yes_price_dollars, no_price and no_price_dollars",
+            req.ticker
+        ))),
+    }
+}
+
+// -------- Dry run --------
+
+/// Fields of a synthetic order returned in dry-run mode.
+#[derive(Default)]
+struct DryRunOrder {
+    order_id: String,
+    ticker: String,
+    action: String,
+    side: String,
+    count_fp: Option<f64>,
+    yes_price_dollars: Option<f64>,
+    client_order_id: Option<String>,
+}
+
+/// Builds the synthetic order answered by write methods in dry-run mode.
+fn dry_run_order(o: DryRunOrder) -> Order {
+    let count = format!("{:.2}", o.count_fp.unwrap_or(0.0));
+    let yes = o.yes_price_dollars.unwrap_or(0.0);
+    let no = if o.yes_price_dollars.is_some() { 1.0 - yes } else { 0.0 };
+    serde_json::from_value(serde_json::json!({
+        "action": o.action,
+        "client_order_id": o.client_order_id.unwrap_or_default(),
+        "fill_count_fp": "0.00",
+        "initial_count_fp": count,
+        "maker_fees_dollars": "0.0000",
+        "maker_fill_cost_dollars": "0.0000",
+        "no_price_dollars": format!("{:.4}", no),
+        "order_id": o.order_id,
+        "remaining_count_fp": count,
+        "side": o.side,
+        "status": "dry_run",
+        "taker_fees_dollars": "0.0000",
+        "taker_fill_cost_dollars": "0.0000",
+        "ticker": o.ticker,
+        "type": "limit",
+        "user_id": "",
+        "yes_price_dollars": format!("{:.4}", yes),
+    }))
+    .expect("synthetic dry-run order matches the Order schema")
+}
+
+fn dry_run_created(req: &CreateOrderRequest) -> Order {
+    dry_run_order(DryRunOrder {
+        order_id: format!("dry-run-{}", uuid::Uuid::new_v4()),
+        ticker: req.ticker.clone(),
+        action: req.action.to_string(),
+        side: req.side.to_string(),
+        count_fp: request_count_fp(req.count_fp.as_ref(), req.count),
+        yes_price_dollars: request_yes_price(
+            req.yes_price_dollars.as_ref(),
+            req.yes_price,
+            req.no_price_dollars.as_ref(),
+            req.no_price,
+        ),
+        client_order_id: req.client_order_id.clone(),
+    })
+}
+
+fn dry_run_cancel(order_id: &str) -> (Order, i32, String) {
+    let order = dry_run_order(DryRunOrder { order_id: order_id.to_string(), ..Default::default() });
+    (order, 0, "0.00".to_string())
+}
+
+fn dry_run_json<T: Serialize>(req: &T) -> String {
+    serde_json::to_string(req).unwrap_or_default()
+}
+
+/// The contract count of a request, from `count_fp` or the legacy integer `count`.
+fn request_count_fp(count_fp: Option<&FixedPointCount>, count: Option<std::num::NonZeroU64>) -> Option<f64> {
+    count_fp.and_then(FixedPointCount::to_f64).or(count.map(|c| c.get() as f64))
+}
+
+/// The yes limit price of a request in dollars, from whichever price field is set.
+fn request_yes_price(
+    yes_dollars: Option<&FixedPointDollars>,
+    yes_cents: Option<std::num::NonZeroU64>,
+    no_dollars: Option<&FixedPointDollars>,
+    no_cents: Option<std::num::NonZeroU64>,
+) -> Option<f64> {
+    let no = no_dollars.and_then(FixedPointDollars::to_f64).or(no_cents.map(|c| c.get() as f64 / 100.0));
+    yes_dollars
+        .and_then(FixedPointDollars::to_f64)
+        .or(yes_cents.map(|c| c.get() as f64 / 100.0))
+        .or(no.map(|no| 1.0 - no))
+}
+
+// -------- Reconciliation --------
+
+/// Pairs each fill with the public trade it executed in, matched by `trade_id`.
+///
+/// Returns one entry per fill, in the order of `fills`. A trade is only matched when its
+/// ticker agrees with the fill's. Several fills may share one trade (for example the
+/// maker and taker legs of a self-cross), so a trade can appear in more than one entry.
+/// A `None` means the fill's trade isn't in `trades`, either because the tape window
+/// doesn't cover it or because the data disagrees. Duplicate trades, e.g. from
+/// overlapping pages, are harmless.
+///
+/// # Example
+///
+/// ```
+/// let (_, fills) = kalshi_instance.get_fills(Some(ticker.clone()), None, None, None, None, None).await.unwrap();
+/// let (_, trades) = kalshi_instance.get_trades(Some(1000), None, Some(ticker), None, None).await.unwrap();
+/// for (fill, trade) in match_fills_to_trades(&fills, &trades) {
+///     if trade.is_none() {
+///         println!("fill {} has no public trade", fill.fill_id);
+///     }
+/// }
+/// ```
+///
+pub fn match_fills_to_trades(fills: &[Fill], trades: &[Trade]) -> Vec<(Fill, Option<Trade>)> {
+    let mut by_id: HashMap<&str, Vec<&Trade>> = HashMap::new();
+    for trade in trades {
+        by_id.entry(trade.trade_id.as_str()).or_default().push(trade);
+    }
+    fills
+        .iter()
+        .map(|fill| {
+            let trade = by_id
+                .get(fill.trade_id.as_str())
+                .and_then(|candidates| candidates.iter().find(|t| t.ticker == fill.ticker))
+                .map(|t| (*t).clone());
+            (fill.clone(), trade)
+        })
+        .collect()
+}
+
 // -------- Private response/request wrappers --------
 
 #[derive(Debug, Deserialize)]
@@ -1096,7 +1801,138 @@ struct SubaccountTransfersListResponse {
 
 #[cfg(test)]
 mod test {
-    use crate::portfolio::MultipleOrderResponse;
+    use crate::kalshi_error::KalshiError;
+    use crate::portfolio::{
+        CANCEL_BATCH_SIZE, dry_run_order, match_fills_to_trades, validate_order_request, CreateOrderRequest, DryRunOrder, Fill, MultipleOrderResponse, OrderStatus,
+        PnlReport, Positions, Settlement, Trade,
+    };
+
+    fn order_json(order_id: &str, status: &str) -> serde_json::Value {
+        let order = dry_run_order(DryRunOrder { order_id: order_id.to_string(), ..Default::default() });
+        let mut order = serde_json::to_value(order).unwrap();
+        order["status"] = status.into();
+        order
+    }
+
+    #[test]
+    fn test_validate_order_request() {
+        let req = |extra: serde_json::Value| -> CreateOrderRequest {
+            let mut req = serde_json::json!({ "ticker": "SOME-MARKET", "action": "buy", "side": "yes" });
+            req.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            serde_json::from_value(req).unwrap()
+        };
+        let rejected = |extra| matches!(validate_order_request(&req(extra)), Err(KalshiError::UserInputError(_)));
+
+        assert!(validate_order_request(&req(serde_json::json!({ "count": 1, "yes_price": 45 }))).is_ok());
+        assert!(validate_order_request(&req(serde_json::json!({ "count_fp": "1.00", "no_price_dollars": "0.55" }))).is_ok());
+        assert!(rejected(serde_json::json!({ "yes_price": 45 })));
+        assert!(rejected(serde_json::json!({ "count": 1 })));
+        assert!(rejected(serde_json::json!({ "count": 1, "yes_price": 45, "no_price": 55 })));
+        assert!(rejected(serde_json::json!({ "count": 1, "yes_price": 45, "yes_price_dollars": "0.45" })));
+    }
+
+    #[tokio::test]
+    async fn test_batch_cancel_order_reports_each_order() {
+        let body = serde_json::json!({
+            "orders": [
+                { "order_id": "a", "order": order_json("a", "canceled"), "reduced_by_fp": "3.00" },
+                { "order_id": "b", "reduced_by_fp": "0.00", "error": { "message": "order not found" } },
+            ],
+        });
+        let client = crate::auth::test::mock_client(vec![(200, body.to_string())]).await;
+        let results = client.batch_cancel_order(vec!["a".to_string(), "b".to_string()]).await.unwrap();
+
+        assert!(matches!(&results[0], Ok((order, 3, _)) if order.order_id == "a"));
+        match &results[1] {
+            Err(KalshiError::UserInputError(message)) => assert_eq!(message, "cancel order b: order not found"),
+            other => panic!("expected a per-order error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_details() {
+        let body = r#"{"balance":12345,"portfolio_value":6789,"updated_ts":1700000000}"#;
+        let client = crate::auth::test::mock_client(vec![(200, body.to_string())]).await;
+        let balance = client.get_balance_details().await.unwrap();
+        assert_eq!((balance.balance, balance.portfolio_value, balance.updated_ts), (12345, 6789, 1700000000));
+    }
+
+    #[tokio::test]
+    async fn test_get_all_positions_rejects_repeated_cursor() {
+        let page = serde_json::json!({ "cursor": "c1", "event_positions": [], "market_positions": [] });
+        let client = crate::auth::test::mock_client(vec![
+            (200, page.to_string()),
+            (200, page.to_string()),
+        ])
+        .await;
+        match client.get_all_positions().await {
+            Err(KalshiError::InvalidCursor { cursor }) => assert_eq!(cursor, "c1"),
+            other => panic!("expected InvalidCursor, got {:?}", other.map(|p| p.market.len())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_reports_orders_filled_mid_cancel() {
+        let listed = serde_json::json!({
+            "orders": [order_json("a", "resting"), order_json("b", "resting")],
+            "cursor": "",
+        });
+        let cancelled = serde_json::json!({
+            "orders": [
+                { "order_id": "a", "order": order_json("a", "canceled"), "reduced_by_fp": "10.00" },
+                { "order_id": "b", "reduced_by_fp": "0.00", "error": { "message": "order not found" } },
+            ],
+        });
+        let filled = serde_json::json!({ "order": order_json("b", "executed") });
+        let client = crate::auth::test::mock_client(vec![
+            (200, listed.to_string()),
+            (200, cancelled.to_string()),
+            (200, filled.to_string()),
+        ])
+        .await;
+
+        let report = client.cancel_all_orders(None).await.unwrap();
+        let states: Vec<_> = report.orders.iter().map(|o| (o.order_id.as_str(), o.status.clone())).collect();
+        assert_eq!(states, vec![("a", OrderStatus::Canceled), ("b", OrderStatus::Executed)]);
+        assert!(report.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_attempts_every_batch() {
+        let ids: Vec<String> = (0..CANCEL_BATCH_SIZE + 1).map(|i| format!("o{}", i)).collect();
+        let listed = serde_json::json!({
+            "orders": ids.iter().map(|id| order_json(id, "resting")).collect::<Vec<_>>(),
+            "cursor": "",
+        });
+        // First batch: answered out of order, with "o1" missing from the response.
+        let mut first: Vec<_> = ids[..CANCEL_BATCH_SIZE]
+            .iter()
+            .filter(|id| id.as_str() != "o1")
+            .map(|id| serde_json::json!({ "order_id": id, "order": order_json(id, "canceled"), "reduced_by_fp": "10.00" }))
+            .collect();
+        first.reverse();
+        let still_resting = serde_json::json!({ "order": order_json("o1", "resting") });
+        let last = &ids[CANCEL_BATCH_SIZE];
+        let second = serde_json::json!({
+            "orders": [{ "order_id": last, "order": order_json(last, "canceled"), "reduced_by_fp": "10.00" }],
+        });
+        let client = crate::auth::test::mock_client(vec![
+            (200, listed.to_string()),
+            (200, serde_json::json!({ "orders": first }).to_string()),
+            (200, still_resting.to_string()),
+            (200, second.to_string()),
+        ])
+        .await;
+
+        let report = client.cancel_all_orders(None).await.unwrap();
+        let cancelled: Vec<_> = report.orders.iter().map(|o| o.order_id.as_str()).collect();
+        let expected: Vec<_> = ids.iter().map(String::as_str).filter(|id| *id != "o1").collect();
+        assert_eq!(cancelled, expected);
+        assert!(!report.is_complete());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].order_id, "o1");
+        assert!(matches!(report.failures[0].error, KalshiError::InternalError(_)));
+    }
 
     #[test]
     fn test_serialize_multiple_order_response() -> serde_json::Result<()> {
@@ -1106,4 +1942,187 @@ mod test {
         assert!(result.cursor.is_none());
         Ok(())
     }
+
+    fn fill(ticker: &str, action: &str, side: &str, count: &str, yes_price: &str, fee: &str) -> Fill {
+        let yes: f64 = yes_price.parse().unwrap();
+        serde_json::from_value(serde_json::json!({
+            "action": action,
+            "count_fp": count,
+            "fee_cost": fee,
+            "fill_id": "f",
+            "is_taker": true,
+            "market_ticker": ticker,
+            "no_price_dollars": format!("{:.4}", 1.0 - yes),
+            "order_id": "o",
+            "side": side,
+            "ticker": ticker,
+            "trade_id": "t",
+            "yes_price_dollars": yes_price
+        }))
+        .unwrap()
+    }
+
+    fn settlement(ticker: &str, result: &str, revenue: i64) -> Settlement {
+        serde_json::from_value(serde_json::json!({
+            "event_ticker": "EVENT",
+            "fee_cost": "0.0000",
+            "market_result": result,
+            "no_count_fp": "0.00",
+            "no_total_cost_dollars": "0.0000",
+            "revenue": revenue,
+            "settled_time": "2024-01-02T00:00:00Z",
+            "ticker": ticker,
+            "yes_count_fp": "10.00",
+            "yes_total_cost_dollars": "4.0000"
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_all_fills_follows_cursor() {
+        let page = |ticker: &str, cursor: &str| {
+            let fill = serde_json::to_value(fill(ticker, "buy", "yes", "1.00", "0.5000", "0.0100")).unwrap();
+            serde_json::json!({ "fills": [fill], "cursor": cursor }).to_string()
+        };
+        let client = crate::auth::test::mock_client(vec![(200, page("A", "next")), (200, page("B", ""))]).await;
+
+        let fills = client.get_all_fills(None, None, None, None).await.unwrap();
+        let tickers: Vec<_> = fills.iter().map(|f| f.ticker.as_str()).collect();
+        assert_eq!(tickers, ["A", "B"]);
+    }
+
+    #[test]
+    fn test_pnl_report_joins_by_ticker() {
+        let fills = vec![
+            fill("A", "buy", "yes", "10.00", "0.4000", "0.0700"),
+            fill("B", "buy", "no", "5.00", "0.3000", "0.0300"),
+            fill("B", "sell", "no", "5.00", "0.2000", "0.0300"),
+            fill("C", "buy", "yes", "2.00", "0.5000", "0.0100"),
+        ];
+        let settlements = vec![settlement("A", "yes", 1000), settlement("C", "void", 100)];
+        let report = PnlReport::from_fills_and_settlements(&fills, &settlements);
+
+        let tickers: Vec<_> = report.tickers.iter().map(|t| t.ticker.as_str()).collect();
+        assert_eq!(tickers, ["A", "B", "C"]);
+
+        let a = &report.tickers[0];
+        assert!(a.settled);
+        assert!((a.realized_pnl_dollars - 6.0).abs() < 1e-9);
+        assert!((a.net_pnl_dollars - 5.93).abs() < 1e-9);
+
+        // Bought no at 0.70, sold no at 0.80: +0.10 on 5 contracts.
+        let b = &report.tickers[1];
+        assert!(!b.settled);
+        assert!((b.realized_pnl_dollars - 0.5).abs() < 1e-9);
+
+        // Void settlement refunds the premium.
+        let c = &report.tickers[2];
+        assert!(c.realized_pnl_dollars.abs() < 1e-9);
+
+        let total: f64 = report.tickers.iter().map(|t| t.net_pnl_dollars).sum();
+        assert!((report.total_net_pnl_dollars - total).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_positions_helpers() {
+        let market = |ticker: &str, position: &str, exposure: &str| {
+            serde_json::from_value(serde_json::json!({
+                "fees_paid_dollars": "0.0000",
+                "last_updated_ts": "2024-01-01T00:00:00Z",
+                "market_exposure_dollars": exposure,
+                "position_fp": position,
+                "realized_pnl_dollars": "0.0000",
+                "resting_orders_count": 0,
+                "ticker": ticker,
+                "total_traded_dollars": "0.0000"
+            }))
+            .unwrap()
+        };
+        let positions = Positions {
+            market: vec![
+                market("A", "10.00", "4.0000"),
+                market("B", "-5.00", "2.5000"),
+                market("C", "0.00", "0.0000"),
+            ],
+            event: vec![serde_json::from_value(serde_json::json!({
+                "event_exposure_dollars": "3.2500",
+                "event_ticker": "EVENT",
+                "fees_paid_dollars": "0.0100",
+                "realized_pnl_dollars": "0.0000",
+                "total_cost_dollars": "6.5000",
+                "total_cost_shares_fp": "15.00"
+            }))
+            .unwrap()],
+        };
+        assert!((positions.net_exposure() - 6.5).abs() < 1e-9);
+        assert!((positions.event_position_for("EVENT").unwrap().net_exposure() - 3.25).abs() < 1e-9);
+        assert_eq!(positions.position_for("B").unwrap().ticker, "B");
+        assert!(positions.position_for("Z").is_none());
+        assert!(positions.event_position_for("OTHER").is_none());
+        let open: Vec<_> = positions.open_positions().map(|p| p.ticker.as_str()).collect();
+        assert_eq!(open, ["A", "B"]);
+    }
+
+    #[test]
+    fn test_match_fills_to_trades() {
+        let trade = |id: &str, ticker: &str| -> Trade {
+            let mut trade = crate::market::test::trade_with(id, "yes", "2024-01-01T00:00:00Z");
+            trade.ticker = ticker.to_string();
+            trade
+        };
+        let with_trade_id = |mut fill: Fill, id: &str| {
+            fill.trade_id = id.to_string();
+            fill
+        };
+        let fills = vec![
+            with_trade_id(fill("A", "buy", "yes", "5.00", "0.4000", "0.0100"), "t1"),
+            // The other leg of a self-cross shares the trade.
+            with_trade_id(fill("A", "sell", "yes", "5.00", "0.4000", "0.0100"), "t1"),
+            with_trade_id(fill("A", "buy", "yes", "1.00", "0.4000", "0.0100"), "missing"),
+            with_trade_id(fill("B", "buy", "yes", "1.00", "0.4000", "0.0100"), "t2"),
+        ];
+        let trades = vec![trade("t1", "A"), trade("t1", "A"), trade("t2", "OTHER"), trade("t3", "A")];
+
+        let matched: Vec<_> = match_fills_to_trades(&fills, &trades)
+            .into_iter()
+            .map(|(fill, trade)| (fill.trade_id, trade.map(|t| t.trade_id)))
+            .collect();
+        assert_eq!(
+            matched,
+            vec![
+                ("t1".to_string(), Some("t1".to_string())),
+                ("t1".to_string(), Some("t1".to_string())),
+                ("missing".to_string(), None),
+                ("t2".to_string(), None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_intercepts_order_writes() {
+        let mut client = crate::auth::test::offline_client();
+        client.set_dry_run(true);
+        let req: crate::CreateOrderRequest = serde_json::from_value(serde_json::json!({
+            "action": "buy",
+            "count": 10,
+            "side": "no",
+            "ticker": "SOME-MARKET",
+            "no_price": 40
+        }))
+        .unwrap();
+
+        let order = client.create_order(req.clone()).await.unwrap();
+        assert!(order.order_id.starts_with("dry-run-"));
+        assert_eq!(order.status, crate::OrderStatus::Unknown("dry_run".to_string()));
+        assert_eq!((order.ticker.as_str(), order.initial_count_fp.0.as_str()), ("SOME-MARKET", "10.00"));
+        assert_eq!((order.yes_price_dollars.0.as_str(), order.no_price_dollars.0.as_str()), ("0.6000", "0.4000"));
+
+        let (canceled, reduced_by, _) = client.cancel_order(&order.order_id).await.unwrap();
+        assert_eq!((canceled.order_id, reduced_by), (order.order_id, 0));
+
+        let batch = client.batch_create_order(vec![req.clone(), req]).await.unwrap();
+        assert_eq!(batch.len(), 2);
+        assert!(batch.iter().all(|o| o.as_ref().unwrap().order_id.starts_with("dry-run-")));
+        assert!(client.batch_create_order(vec![]).await.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,129 @@
+//! price.rs – a contract price in whole cents that can't be mistaken for dollars
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::generated::types::FixedPointDollars;
+
+/// The price of one contract in whole cents, from `0` to `100`.
+///
+/// A binary contract pays out $1, so its price in cents divided by 100 is both its
+/// price in dollars and the probability the market implies. Keeping prices in this
+/// type instead of a bare integer keeps `50` (cents) from being read as $50 or as a
+/// probability of 50.
+///
+/// The API reports prices as decimal dollar strings, which some markets quote in
+/// fractions of a cent; [`FixedPointDollars::to_price`] converts those that are whole
+/// cents. Serializes as the number of cents.
+///
+/// # Example
+///
+/// ```
+/// use kalshi::Price;
+///
+/// let price = Price::from_cents(54).unwrap();
+/// assert_eq!(price.as_dollars(), 0.54);
+/// assert_eq!(price.to_string(), "54¢");
+/// ```
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "i32", into = "i32")]
+pub struct Price(i32);
+
+impl Price {
+    /// A price of `cents`, or `None` unless it is between `0` and `100`.
+    pub const fn from_cents(cents: i32) -> Option<Price> {
+        if cents >= 0 && cents <= 100 {
+            Some(Price(cents))
+        } else {
+            None
+        }
+    }
+
+    /// The price matching `dollars`, or `None` unless it is a whole number of cents
+    /// between $0 and $1.
+    pub fn from_dollars(dollars: f64) -> Option<Price> {
+        let cents = dollars * 100.0;
+        if !cents.is_finite() || (cents - cents.round()).abs() > 1e-6 {
+            return None;
+        }
+        // Values beyond `i32` saturate and are rejected by `from_cents`.
+        Price::from_cents(cents.round() as i32)
+    }
+
+    /// The price in cents.
+    pub const fn as_cents(self) -> i32 {
+        self.0
+    }
+
+    /// The price in dollars.
+    pub fn as_dollars(self) -> f64 {
+        f64::from(self.0) / 100.0
+    }
+
+    /// The probability the price implies, from `0.0` to `1.0`.
+    pub fn as_probability(self) -> f64 {
+        self.as_dollars()
+    }
+
+    /// The price of the other side of the same contract, `100 - cents`.
+    pub const fn complement(self) -> Price {
+        Price(100 - self.0)
+    }
+}
+
+impl fmt::Display for Price {
+    /// Renders the price in cents, e.g. `54¢`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}¢", self.0)
+    }
+}
+
+impl TryFrom<i32> for Price {
+    type Error = String;
+
+    fn try_from(cents: i32) -> Result<Self, Self::Error> {
+        Price::from_cents(cents).ok_or_else(|| format!("price of {} cents is outside 0..=100", cents))
+    }
+}
+
+impl From<Price> for i32 {
+    fn from(price: Price) -> Self {
+        price.0
+    }
+}
+
+impl FixedPointDollars {
+    /// The dollar amount as a [`Price`], or `None` if it is malformed, outside $0–$1 or
+    /// not a whole number of cents.
+    pub fn to_price(&self) -> Option<Price> {
+        self.to_f64().and_then(Price::from_dollars)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_price_conversions() {
+        let price = Price::from_cents(37).unwrap();
+        assert_eq!(price.as_cents(), 37);
+        assert_eq!(price.as_dollars(), 0.37);
+        assert_eq!(price.as_probability(), 0.37);
+        assert_eq!(price.complement(), Price::from_cents(63).unwrap());
+        assert_eq!(price.to_string(), "37¢");
+
+        assert_eq!(Price::from_cents(-1), None);
+        assert_eq!(Price::from_cents(101), None);
+        assert_eq!(Price::from_dollars(0.37), Some(price));
+        assert_eq!(Price::from_dollars(0.375), None);
+        assert_eq!(Price::from_dollars(50.0), None);
+        assert_eq!(Price::from_dollars(f64::NAN), None);
+        assert_eq!(FixedPointDollars("0.3700".to_string()).to_price(), Some(price));
+        assert_eq!(FixedPointDollars("".to_string()).to_price(), None);
+
+        assert_eq!(serde_json::to_string(&price).unwrap(), "37");
+        assert_eq!(serde_json::from_str::<Price>("37").unwrap(), price);
+        assert!(serde_json::from_str::<Price>("150").is_err());
+    }
+}
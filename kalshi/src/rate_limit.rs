@@ -0,0 +1,253 @@
+//! rate_limit.rs – client-side token buckets for Kalshi's read and write limits
+//!
+//! Kalshi limits reads and writes per second separately, by account tier. When a
+//! limit is configured with [`Kalshi::set_rate_limit`], every request waits for a
+//! token from the matching bucket before it is sent, so bulk jobs slow down instead
+//! of being throttled by the exchange.
+use reqwest::Method;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::{Mutex, MutexGuard, Notify};
+use tokio::time::{Duration, Instant};
+
+use crate::Kalshi;
+
+impl Kalshi {
+    /// Limits how many requests per second this client sends.
+    ///
+    /// `GET` requests draw from the read bucket and every other method from the write
+    /// bucket, matching how Kalshi counts them. Each bucket holds one second's worth of
+    /// tokens, so a short burst up to the limit goes out immediately and anything beyond
+    /// waits for tokens to refill. Waiting requests of equal priority are served in the
+    /// order they arrived. A rate of `0` leaves that kind of request unlimited.
+    ///
+    /// Within each bucket, writes and requests from a [`with_priority`](Kalshi::with_priority)
+    /// client marked [`Priority::High`] are served before waiting reads, so a bulk scan
+    /// can't hold up an urgent cancel.
+    ///
+    /// Clones of this client made afterwards share the buckets, so the limit holds across
+    /// tasks. Calling this again starts fresh buckets.
+    ///
+    /// # Arguments
+    ///
+    /// * `reads_per_second` - Allowed `GET` requests per second.
+    /// * `writes_per_second` - Allowed `POST`, `PUT` and `DELETE` requests per second.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is a mutable instance of `Kalshi`
+    /// // Basic tier: 20 reads and 10 writes per second.
+    /// kalshi_instance.set_rate_limit(20, 10);
+    /// ```
+    ///
+    pub fn set_rate_limit(&mut self, reads_per_second: u32, writes_per_second: u32) {
+        self.rate_limiter = if reads_per_second == 0 && writes_per_second == 0 {
+            None
+        } else {
+            Some(std::sync::Arc::new(RateLimiter {
+                reads: TokenBucket::new(reads_per_second),
+                writes: TokenBucket::new(writes_per_second),
+            }))
+        };
+    }
+
+    /// Returns a copy of this client whose requests wait for rate limit tokens with
+    /// `priority`, regardless of method.
+    ///
+    /// Without this, writes are [`Priority::High`] and reads [`Priority::Low`]. The copy
+    /// shares the rate limit buckets, so a trading loop can use a high-priority copy for
+    /// the reads it needs urgently (such as the orderbook it quotes against) while a
+    /// background scanner keeps the default. Has no effect unless
+    /// [`set_rate_limit`](Kalshi::set_rate_limit) was called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi` with a rate limit set
+    /// use kalshi::Priority;
+    ///
+    /// let urgent = kalshi_instance.with_priority(Priority::High);
+    /// let book = urgent.get_orderbook("SOME-MARKET-2024", None).await.unwrap();
+    /// ```
+    ///
+    pub fn with_priority(&self, priority: Priority) -> Kalshi {
+        Kalshi {
+            priority: Some(priority),
+            ..self.clone()
+        }
+    }
+}
+
+/// The read and write buckets behind [`Kalshi::set_rate_limit`].
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    reads: TokenBucket,
+    writes: TokenBucket,
+}
+
+impl RateLimiter {
+    /// Waits until a request with `method` may be sent, letting `priority` jump the queue.
+    pub(crate) async fn acquire(&self, method: &Method, priority: Priority) {
+        if is_read(method) {
+            self.reads.acquire(priority).await
+        } else {
+            self.writes.acquire(priority).await
+        }
+    }
+}
+
+/// How urgently a request should get a rate limit token. See [`Kalshi::with_priority`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// Served before any waiting `Low` request. The default for writes.
+    High,
+    /// Served once no `High` request is waiting. The default for reads.
+    Low,
+}
+
+impl Priority {
+    /// The priority a request gets when the client doesn't set one.
+    pub(crate) fn for_method(method: &Method) -> Priority {
+        if is_read(method) {
+            Priority::Low
+        } else {
+            Priority::High
+        }
+    }
+}
+
+fn is_read(method: &Method) -> bool {
+    *method == Method::GET || *method == Method::HEAD
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    /// Tokens added per second, which is also the bucket's capacity. `0` means unlimited.
+    rate: f64,
+    /// Tokens available as of the instant; waiters queue on the lock.
+    state: Mutex<(f64, Instant)>,
+    /// `High` requests currently waiting; `Low` requests stand aside while it's non-zero.
+    urgent: AtomicUsize,
+    /// Signalled whenever a `High` request stops waiting.
+    urgent_done: Notify,
+}
+
+impl TokenBucket {
+    fn new(rate: u32) -> Self {
+        let rate = f64::from(rate);
+        TokenBucket {
+            rate,
+            state: Mutex::new((rate, Instant::now())),
+            urgent: AtomicUsize::new(0),
+            urgent_done: Notify::new(),
+        }
+    }
+
+    async fn acquire(&self, priority: Priority) {
+        if self.rate == 0.0 {
+            return;
+        }
+        match priority {
+            Priority::High => {
+                let _urgent = UrgentWaiter::new(self);
+                self.take(self.state.lock().await).await
+            }
+            Priority::Low => loop {
+                // Created before checking, so a `High` finishing in between still wakes us.
+                let done = self.urgent_done.notified();
+                if self.urgent.load(Ordering::SeqCst) == 0 {
+                    let state = self.state.lock().await;
+                    if self.urgent.load(Ordering::SeqCst) == 0 {
+                        return self.take(state).await;
+                    }
+                }
+                done.await;
+            },
+        }
+    }
+
+    /// Takes one token, sleeping until it has refilled if necessary.
+    async fn take(&self, mut state: MutexGuard<'_, (f64, Instant)>) {
+        // Holding the lock while sleeping keeps waiters in arrival order.
+        let (tokens, refilled) = *state;
+        let now = Instant::now();
+        let mut tokens = (tokens + now.duration_since(refilled).as_secs_f64() * self.rate).min(self.rate);
+        let mut now = now;
+        if tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - tokens) / self.rate);
+            tokio::time::sleep(wait).await;
+            now += wait;
+            tokens = 1.0;
+        }
+        *state = (tokens - 1.0, now);
+    }
+}
+
+/// Counts a `High` request as waiting for as long as it lives, even if its future is dropped.
+struct UrgentWaiter<'a>(&'a TokenBucket);
+
+impl<'a> UrgentWaiter<'a> {
+    fn new(bucket: &'a TokenBucket) -> Self {
+        bucket.urgent.fetch_add(1, Ordering::SeqCst);
+        UrgentWaiter(bucket)
+    }
+}
+
+impl Drop for UrgentWaiter<'_> {
+    fn drop(&mut self) {
+        self.0.urgent.fetch_sub(1, Ordering::SeqCst);
+        self.0.urgent_done.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_buckets_pace_reads_and_writes_separately() {
+        let limiter = RateLimiter { reads: TokenBucket::new(2), writes: TokenBucket::new(1) };
+        let start = Instant::now();
+
+        // The initial burst is the full second's worth.
+        limiter.acquire(&Method::GET, Priority::Low).await;
+        limiter.acquire(&Method::GET, Priority::Low).await;
+        limiter.acquire(&Method::POST, Priority::High).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire(&Method::GET, Priority::Low).await;
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+        limiter.acquire(&Method::DELETE, Priority::High).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+        let unlimited = TokenBucket::new(0);
+        for _ in 0..100 {
+            unlimited.acquire(Priority::Low).await;
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_high_priority_jumps_waiting_low_priority() {
+        let bucket = std::sync::Arc::new(TokenBucket::new(1));
+        bucket.acquire(Priority::High).await;
+
+        let order = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for (name, priority) in [("low-1", Priority::Low), ("low-2", Priority::Low), ("high", Priority::High)] {
+            let (bucket, order) = (bucket.clone(), order.clone());
+            tasks.push(tokio::spawn(async move {
+                bucket.acquire(priority).await;
+                order.lock().unwrap().push(name);
+            }));
+            // Let each task start waiting before the next one arrives.
+            tokio::task::yield_now().await;
+        }
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        // `low-1` was already waiting for the refill when `high` arrived; `low-2` wasn't.
+        assert_eq!(*order.lock().unwrap(), ["low-1", "high", "low-2"]);
+    }
+}
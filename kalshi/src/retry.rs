@@ -0,0 +1,191 @@
+//! retry.rs – automatic retries of `429` and `5xx` responses with exponential backoff
+//!
+//! Configured with [`Kalshi::set_retry_policy`]. Retries happen inside
+//! [`Kalshi::execute`], so every endpoint is covered; each attempt is re-signed and
+//! waits on the rate limiter like any other request.
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Method, StatusCode};
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::kalshi_error::KalshiError;
+use crate::Kalshi;
+
+/// How the client retries requests that failed with `429 Too Many Requests` or a `5xx`
+/// status. See [`Kalshi::set_retry_policy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; `0` disables retrying.
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for each one after it.
+    pub base_delay: Duration,
+    /// Longest backoff between two attempts. A `Retry-After` header is honored even when longer.
+    pub max_delay: Duration,
+    /// Also retry `POST`, `PUT` and `DELETE` requests. Off by default, because a write
+    /// whose response was lost may already have taken effect, and repeating it could
+    /// for example place an order twice.
+    pub retry_writes: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Three retries starting at 250 ms and capped at 10 s, reads only.
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+            retry_writes: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether requests with `method` are retried under this policy.
+    fn applies_to(&self, method: &Method) -> bool {
+        self.max_retries > 0 && (self.retry_writes || *method == Method::GET || *method == Method::HEAD)
+    }
+
+    /// The backoff before retry number `retry` (starting at 1): `base_delay` doubled
+    /// per earlier retry and capped at `max_delay`, then jittered down by up to half.
+    fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay);
+        exponential.mul_f64(1.0 - jitter() / 2.0)
+    }
+}
+
+impl Kalshi {
+    /// Retries requests that fail with `429 Too Many Requests` or a `5xx` status.
+    ///
+    /// Failed attempts are repeated up to `policy.max_retries` times with exponential
+    /// backoff and jitter. When the response carries a `Retry-After` header (in seconds),
+    /// that wait is used instead. `GET` requests such as [`get_market`](Kalshi::get_market)
+    /// and [`get_orderbook`](Kalshi::get_orderbook) are always covered; writes only when
+    /// [`RetryPolicy::retry_writes`] is set. If every attempt fails, the last response's
+    /// error is returned as usual. `None` turns retrying off, which is the default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is a mutable instance of `Kalshi`
+    /// use kalshi::RetryPolicy;
+    ///
+    /// kalshi_instance.set_retry_policy(Some(RetryPolicy { max_retries: 5, ..Default::default() }));
+    /// ```
+    ///
+    pub fn set_retry_policy(&mut self, policy: Option<RetryPolicy>) {
+        self.retry_policy = policy;
+    }
+
+    /// Sends `request`, repeating it as [`set_retry_policy`](Kalshi::set_retry_policy) allows.
+    pub(crate) async fn execute_with_retries(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, KalshiError> {
+        let policy = self.retry_policy.as_ref().filter(|p| p.applies_to(request.method()));
+        let mut retry = 0;
+        loop {
+            let next = match policy {
+                Some(policy) if retry < policy.max_retries => request.try_clone(),
+                _ => None,
+            };
+            let resp = self.execute_once(request).await?;
+            let (Some(policy), Some(mut next)) = (policy, next) else {
+                return Ok(resp);
+            };
+            if !is_transient(resp.status()) {
+                return Ok(resp);
+            }
+            retry += 1;
+            tokio::time::sleep(retry_after(&resp).unwrap_or_else(|| policy.backoff(retry))).await;
+            self.resign(&mut next)?;
+            request = next;
+        }
+    }
+
+    /// Replaces the signature headers of a signed request with fresh ones, so a retry
+    /// doesn't go out with a stale timestamp.
+    fn resign(&self, request: &mut reqwest::Request) -> Result<(), KalshiError> {
+        if !request.headers().contains_key("KALSHI-ACCESS-SIGNATURE") {
+            return Ok(());
+        }
+        // Signatures cover the path as if `base_url` ended in `/trade-api/v2`.
+        let base_path = reqwest::Url::parse(&self.base_url)?.path().trim_end_matches('/').to_string();
+        let path = request.url().path();
+        let path = path.strip_prefix(base_path.as_str()).unwrap_or(path);
+        let headers = self.auth_headers(request.method().as_str(), &format!("/trade-api/v2{}", path))?;
+        request.headers_mut().extend(headers);
+        Ok(())
+    }
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// The wait requested by a `Retry-After` header, given either in seconds or as an HTTP
+/// date. A date in the past asks for no wait.
+pub(crate) fn retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    let value = resp.headers().get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some((at.with_timezone(&chrono::Utc) - chrono::Utc::now()).to_std().unwrap_or_default())
+}
+
+/// A pseudo-random number in `[0, 1)`, from the randomly keyed std hasher.
+fn jitter() -> f64 {
+    let random = std::collections::hash_map::RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_within_bounds() {
+        let policy = RetryPolicy { base_delay: Duration::from_millis(100), ..Default::default() };
+        for retry in 1..=10 {
+            let full = Duration::from_millis(100 * 2u64.pow(retry - 1)).min(policy.max_delay);
+            let delay = policy.backoff(retry);
+            assert!(delay <= full && delay >= full / 2, "retry {}: {:?}", retry, delay);
+        }
+        assert!(policy.applies_to(&Method::GET));
+        assert!(!policy.applies_to(&Method::POST));
+        assert!(RetryPolicy { retry_writes: true, ..Default::default() }.applies_to(&Method::DELETE));
+    }
+
+    #[test]
+    fn test_retry_after_seconds_or_date() {
+        let with_header = |value: &str| {
+            let resp = http::Response::builder().status(429).header(RETRY_AFTER, value).body(Vec::<u8>::new());
+            retry_after(&reqwest::Response::from(resp.unwrap()))
+        };
+        assert_eq!(with_header("7"), Some(Duration::from_secs(7)));
+        assert_eq!(with_header("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+        let later = (chrono::Utc::now() + chrono::Duration::seconds(120)).to_rfc2822();
+        assert!(with_header(&later).is_some_and(|d| d > Duration::from_secs(100)));
+        assert_eq!(with_header("soon"), None);
+    }
+
+    #[tokio::test]
+    async fn test_retries_reads_but_not_writes() {
+        let status = r#"{"trading_active":true,"exchange_active":true}"#.to_string();
+        let mut client = crate::auth::test::mock_client(vec![
+            (503, "{}".to_string()),
+            (429, "{}".to_string()),
+            (200, status),
+            (503, "{}".to_string()),
+        ])
+        .await;
+        client.set_retry_policy(Some(RetryPolicy { base_delay: Duration::from_millis(1), ..Default::default() }));
+
+        assert!(client.get_exchange_status().await.unwrap().trading_active);
+        let write = client.signed_post::<_, serde_json::Value>("/portfolio/order_groups/create", &()).await;
+        assert!(write.unwrap_err().is_retryable());
+    }
+}
@@ -63,7 +63,7 @@ struct TagsResponse {
 // -------- Public models --------
 
 /// Represents available filters for sports markets.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SportsFilters {
     /// List of available sports.
     pub sports: Vec<String>,
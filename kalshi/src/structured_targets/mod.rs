@@ -67,7 +67,7 @@ struct StructuredTargetResponse {
 // -------- Public models --------
 
 /// Represents a structured target market.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StructuredTarget {
     /// The unique identifier for the structured target.
     pub id: String,
@@ -0,0 +1,120 @@
+//! telemetry.rs – client metrics recorded through the `metrics` crate facade
+//! (enabled with the `metrics` feature)
+//!
+//! Nothing is exported here; install any `metrics` recorder (e.g. a Prometheus
+//! exporter) in the application and these series show up under it:
+//!
+//! - `kalshi_requests_total{method, status}`: every HTTP request sent, by response status
+//!   (`"error"` when no response was received).
+//! - `kalshi_request_duration_seconds{method}`: time until the response headers arrived.
+//! - `kalshi_rate_limited_total`: responses with status `429 Too Many Requests`.
+//! - `kalshi_errors_total{kind}`: failed API calls, by [`KalshiError::kind`].
+//! - `kalshi_ws_connections_total{channel, outcome}`: WebSocket connection attempts,
+//!   `"ok"` or `"error"`; `channel` is `"raw"` for [`KalshiWebSocket::send_raw`](crate::KalshiWebSocket::send_raw).
+//! - `kalshi_ws_reconnects_total{channel}`: connection attempts after the first one of a
+//!   subscription.
+//! - `kalshi_ws_subscriptions_total{channel}`: subscriptions acknowledged by the server.
+//! - `kalshi_ws_messages_total{channel, type}`: WebSocket messages received, by message type.
+use std::time::Duration;
+
+use crate::kalshi_error::KalshiError;
+
+/// Records one sent request and how long it took.
+pub(crate) fn record_response(
+    method: &reqwest::Method,
+    result: &Result<reqwest::Response, reqwest::Error>,
+    elapsed: Duration,
+) {
+    let status = match result {
+        Ok(resp) => resp.status().as_u16().to_string(),
+        Err(_) => "error".to_string(),
+    };
+    if status == "429" {
+        metrics::counter!("kalshi_rate_limited_total").increment(1);
+    }
+    metrics::counter!("kalshi_requests_total", "method" => method.to_string(), "status" => status)
+        .increment(1);
+    metrics::histogram!("kalshi_request_duration_seconds", "method" => method.to_string())
+        .record(elapsed.as_secs_f64());
+}
+
+/// Counts a failed API call by its error variant.
+pub(crate) fn record_error(err: &KalshiError) {
+    metrics::counter!("kalshi_errors_total", "kind" => err.kind()).increment(1);
+}
+
+/// Records one WebSocket connection attempt for `channel`.
+pub(crate) fn record_ws_connect(channel: &'static str, reconnect: bool, ok: bool) {
+    if reconnect {
+        metrics::counter!("kalshi_ws_reconnects_total", "channel" => channel).increment(1);
+    }
+    let outcome = if ok { "ok" } else { "error" };
+    metrics::counter!("kalshi_ws_connections_total", "channel" => channel, "outcome" => outcome).increment(1);
+}
+
+/// Counts a subscription the server acknowledged on `channel`.
+pub(crate) fn record_ws_subscribed(channel: &'static str) {
+    metrics::counter!("kalshi_ws_subscriptions_total", "channel" => channel).increment(1);
+}
+
+/// Counts a message of type `kind` received on a `channel` connection.
+pub(crate) fn record_ws_message(channel: &'static str, kind: &str) {
+    metrics::counter!("kalshi_ws_messages_total", "channel" => channel, "type" => kind.to_string()).increment(1);
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use metrics::{Counter, CounterFn, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    type Counts = Arc<Mutex<BTreeMap<String, u64>>>;
+
+    /// A recorder keeping counter totals, keyed as `name{label=value,...}`.
+    #[derive(Default)]
+    pub(crate) struct RecordingSink {
+        counts: Counts,
+    }
+
+    impl RecordingSink {
+        /// The total of the counter written as `key`, 0 if it was never incremented.
+        pub(crate) fn count(&self, key: &str) -> u64 {
+            self.counts.lock().unwrap().get(key).copied().unwrap_or(0)
+        }
+    }
+
+    struct RecordedCounter {
+        key: String,
+        counts: Counts,
+    }
+
+    impl CounterFn for RecordedCounter {
+        fn increment(&self, value: u64) {
+            *self.counts.lock().unwrap().entry(self.key.clone()).or_default() += value;
+        }
+
+        fn absolute(&self, value: u64) {
+            self.counts.lock().unwrap().insert(self.key.clone(), value);
+        }
+    }
+
+    impl Recorder for RecordingSink {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let labels: Vec<_> = key.labels().map(|l| format!("{}={}", l.key(), l.value())).collect();
+            let key = format!("{}{{{}}}", key.name(), labels.join(","));
+            Counter::from_arc(Arc::new(RecordedCounter { key, counts: self.counts.clone() }))
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::kalshi_error::KalshiError;
 use crate::TradingEnvironment;
 // MACROS
 
@@ -19,3 +20,135 @@ pub fn build_base_url(trading_env: TradingEnvironment) -> &'static str {
         TradingEnvironment::DemoMode => "https://demo-api.kalshi.co/trade-api/v2",
     }
 }
+
+// Fixed-point helpers
+//
+// The API transmits prices and contract counts as fixed-point decimal strings
+// (e.g. `"0.5600"` dollars or `"10.00"` contracts). These accessors parse them
+// so helpers elsewhere in the crate don't each reimplement the conversion.
+
+impl crate::generated::types::FixedPointDollars {
+    /// Parses the dollar amount, returning `None` if the string is empty or malformed.
+    pub fn to_f64(&self) -> Option<f64> {
+        self.0.trim().parse().ok()
+    }
+}
+
+impl crate::generated::types::FixedPointCount {
+    /// Parses the contract count, returning `None` if the string is empty or malformed.
+    pub fn to_f64(&self) -> Option<f64> {
+        self.0.trim().parse().ok()
+    }
+}
+
+// Pagination helpers
+
+/// Validates the cursor a page came back with before a paginator follows it.
+///
+/// An empty or missing cursor ends the scan. A cursor identical to the one just sent
+/// would request the same page forever, so it is reported as `InvalidCursor` instead.
+pub(crate) fn next_cursor(
+    sent: Option<&str>,
+    received: Option<String>,
+) -> Result<Option<String>, KalshiError> {
+    match received {
+        Some(cursor) if cursor.is_empty() => Ok(None),
+        Some(cursor) if sent == Some(cursor.as_str()) => Err(KalshiError::InvalidCursor { cursor }),
+        other => Ok(other),
+    }
+}
+
+/// Deserializes each element of a list response on its own, setting aside the ones
+/// that fail instead of failing them all.
+pub(crate) fn parse_lenient<T: serde::de::DeserializeOwned>(
+    values: Vec<serde_json::Value>,
+) -> (Vec<T>, Vec<crate::SkippedItem>) {
+    let mut items = Vec::with_capacity(values.len());
+    let mut skipped = Vec::new();
+    for (index, raw) in values.into_iter().enumerate() {
+        match T::deserialize(&raw) {
+            Ok(item) => items.push(item),
+            Err(e) => skipped.push(crate::SkippedItem { index, error: e.to_string(), raw }),
+        }
+    }
+    (items, skipped)
+}
+
+/// Appends `params` to `path` as a URL-encoded query string, so opaque values such as
+/// cursors can't spill into other parameters.
+pub(crate) fn with_query(path: String, params: &[(&str, String)]) -> Result<String, KalshiError> {
+    if params.is_empty() {
+        Ok(path)
+    } else {
+        Ok(format!("{}?{}", path, serde_urlencoded::to_string(params)?))
+    }
+}
+
+// Enum helpers
+
+/// Deserializes a string enum whose [`FromStr`](std::str::FromStr) impl never fails.
+///
+/// Used by every enum in [`crate::enums`] so that a value the exchange adds after
+/// this crate was released lands in the enum's `Unknown` variant rather than
+/// failing the whole response.
+pub(crate) fn deserialize_enum_or_unknown<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr<Err = std::convert::Infallible>,
+{
+    let raw = <String as serde::Deserialize>::deserialize(deserializer)?;
+    let Ok(value) = raw.parse::<T>();
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{next_cursor, with_query};
+    use crate::KalshiError;
+
+    /// Cursors built from characters that are meaningful in URLs, paths and JSON.
+    fn malformed_cursors() -> Vec<String> {
+        let alphabet = ['a', 'Z', '9', '&', '=', '?', '#', '%', '+', ' ', '/', '"', '\\', '\n', 'é', '\0'];
+        let mut cursors = vec![String::new(), "%".to_string(), "%zz".to_string(), "a".repeat(4096)];
+        // A fixed-seed LCG keeps the generated inputs reproducible.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for len in 1..64 {
+            let cursor = (0..len)
+                .map(|_| {
+                    state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                    alphabet[(state >> 60) as usize]
+                })
+                .collect();
+            cursors.push(cursor);
+        }
+        cursors
+    }
+
+    #[test]
+    fn test_with_query_round_trips_malformed_cursors() {
+        for cursor in malformed_cursors() {
+            let params = vec![("limit", "1000".to_string()), ("cursor", cursor.clone())];
+            let path = with_query("/portfolio/fills".to_string(), &params).unwrap();
+            let url = url::Url::parse(&format!("https://example.com{}", path)).unwrap();
+            let parsed: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+            assert_eq!(
+                parsed,
+                vec![("limit".to_string(), "1000".to_string()), ("cursor".to_string(), cursor)]
+            );
+        }
+        assert_eq!(with_query("/portfolio/fills".to_string(), &[]).unwrap(), "/portfolio/fills");
+    }
+
+    #[test]
+    fn test_next_cursor_never_repeats() {
+        assert_eq!(next_cursor(None, None).unwrap(), None);
+        assert_eq!(next_cursor(Some("a"), Some(String::new())).unwrap(), None);
+        for cursor in malformed_cursors().into_iter().filter(|c| !c.is_empty()) {
+            assert_eq!(next_cursor(None, Some(cursor.clone())).unwrap(), Some(cursor.clone()));
+            match next_cursor(Some(&cursor), Some(cursor.clone())) {
+                Err(KalshiError::InvalidCursor { cursor: c }) => assert_eq!(c, cursor),
+                other => panic!("expected InvalidCursor, got {:?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,1722 @@
+use super::Kalshi;
+use crate::enums::OrderSide;
+use crate::kalshi_error::*;
+use crate::market::{complement, Orderbook, Snapshot, Trade};
+use futures_util::future;
+use futures_util::stream::{self, Stream, StreamExt};
+use futures_util::SinkExt;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::generated::types::{FixedPointCount, FixedPointDollars, PriceLevelDollarsCountFp};
+
+/// Path of the WebSocket endpoint on the API host; also the path that gets signed.
+const WS_PATH: &str = "/trade-api/ws/v2";
+
+/// Wait before the first reconnect attempt; doubled after every consecutive failure.
+const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest wait between reconnect attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// How long [`KalshiWebSocket::snapshot_once`] waits for the snapshot to arrive.
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Most market tickers sent in one `subscribe` or `update_subscription` command.
+///
+/// The exchange refuses commands carrying too many tickers, so longer lists are split
+/// into several commands, each creating or updating its own server-side subscription.
+const TICKERS_PER_COMMAND: usize = 100;
+
+/// Default time between pings on an otherwise idle connection.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default wait for the answer to a ping before the connection is considered dead.
+const PONG_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How far behind the newest trade [`Kalshi::trades_stream`] remembers trade ids.
+///
+/// Trades replayed from REST and received live only overlap between the subscription
+/// being acknowledged and the backfill's first page, which takes well under a minute.
+const TRADE_DEDUP_WINDOW_SECS: i64 = 60;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+impl Kalshi {
+    /// Creates a WebSocket client that authenticates with this client's credentials.
+    ///
+    /// The WebSocket lives on the same host as the REST API, at `/trade-api/ws/v2`.
+    /// Creating the client doesn't connect; each subscription opens its own connection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let ws = kalshi_instance.websocket();
+    /// ```
+    ///
+    pub fn websocket(&self) -> KalshiWebSocket {
+        KalshiWebSocket {
+            url: websocket_url(&self.base_url),
+            kalshi: self.clone(),
+            heartbeat_interval: HEARTBEAT_INTERVAL,
+            pong_timeout: PONG_TIMEOUT,
+        }
+    }
+
+    /// Streams every trade in a market from `min_ts` onwards: first the history, then
+    /// live trades as they happen.
+    ///
+    /// The live `trade` channel is subscribed first. Once the exchange acknowledges the
+    /// subscription, the trades since `min_ts` are replayed oldest first through
+    /// [`get_all_trades`](Kalshi::get_all_trades), and then the live trades follow. Any
+    /// trade both replayed and received live is yielded once, matched by `trade_id`, so
+    /// the tape has no gap and no duplicates at the handoff. After a reconnect the same
+    /// happens again from the newest trade already yielded, filling in whatever traded
+    /// while the connection was down.
+    ///
+    /// A failed backfill is yielded as `Err` and retried with backoff before any more
+    /// live trades are yielded. Connection errors are yielded as `Err` as well; the
+    /// stream keeps going until it is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - The market to stream trades for.
+    /// * `min_ts` - Replay trades from this Unix timestamp (seconds, inclusive).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let mut tape = Box::pin(kalshi_instance.trades_stream("SOME-MARKET-2024", 1700000000));
+    /// while let Some(trade) = tape.next().await {
+    ///     let trade = trade.unwrap();
+    ///     println!("{} {} @ {}", trade.created_time, trade.count_fp.0, trade.yes_price_dollars.0);
+    /// }
+    /// ```
+    ///
+    pub fn trades_stream(&self, ticker: &str, min_ts: i64) -> impl Stream<Item = Result<Trade, KalshiError>> + '_ {
+        let live = self.websocket().subscribe("trade", vec![ticker.to_string()], |event| match event {
+            ChannelEvent::Connected => None,
+            ChannelEvent::Subscribed => Some(Ok(TapeEvent::Subscribed)),
+            ChannelEvent::Message(message) => parse_trade_message(message).map(|t| Ok(TapeEvent::Trade(t))),
+            ChannelEvent::Error(e) => Some(Err(e)),
+        });
+        let state = (live, TradeTape::default(), ticker.to_string());
+        stream::unfold(state, move |(mut live, mut tape, ticker)| async move {
+            if tape.backfill_pending {
+                if tape.backfill_failures > 0 {
+                    tokio::time::sleep(reconnect_delay(tape.backfill_failures)).await;
+                }
+                let from = tape.newest.map_or(min_ts, |newest| newest.max(min_ts));
+                let items = match self.get_all_trades(&ticker, Some(from), None, None).await {
+                    Ok(trades) => {
+                        tape.backfill_pending = false;
+                        tape.backfill_failures = 0;
+                        trades.into_iter().filter(|t| tape.admit(t)).map(Ok).collect()
+                    }
+                    Err(e) => {
+                        tape.backfill_failures += 1;
+                        vec![Err(e)]
+                    }
+                };
+                return Some((items, (live, tape, ticker)));
+            }
+            let items = match live.next().await? {
+                Ok(TapeEvent::Subscribed) => {
+                    tape.backfill_pending = true;
+                    Vec::new()
+                }
+                Ok(TapeEvent::Trade(trade)) if tape.admit(&trade) => vec![Ok(trade)],
+                Ok(TapeEvent::Trade(_)) => Vec::new(),
+                Err(e) => vec![Err(e)],
+            };
+            Some((items, (live, tape, ticker)))
+        })
+        .flat_map(stream::iter)
+    }
+}
+
+/// A client for Kalshi's WebSocket channels. Create one with [`Kalshi::websocket`].
+///
+/// Every subscription method returns a stream backed by its own connection. Ticker
+/// lists longer than 100 are subscribed in groups of 100, one command each, and the
+/// updates of all groups arrive on the one stream.
+///
+/// If the connection drops, or a message is missed (a gap in the channel's sequence
+/// numbers), the stream reconnects and subscribes again, backing off exponentially up
+/// to 30 seconds while the server is unreachable. Failed connection attempts are
+/// yielded as `Err` and the stream keeps retrying; drop it to stop.
+///
+/// Each connection is pinged every [`heartbeat_interval`](KalshiWebSocket::heartbeat_interval)
+/// (10 seconds by default). A connection that doesn't answer a ping within the
+/// [`pong_timeout`](KalshiWebSocket::pong_timeout) is treated as dead: an `Err` is
+/// yielded and the stream reconnects, so a quiet subscription can't silently stall.
+#[derive(Debug, Clone)]
+pub struct KalshiWebSocket {
+    kalshi: Kalshi,
+    url: String,
+    heartbeat_interval: Duration,
+    pong_timeout: Duration,
+}
+
+impl KalshiWebSocket {
+    /// Sets how often subscriptions created afterwards ping the server. Defaults to 10 seconds.
+    pub fn heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+
+    /// Sets how long subscriptions created afterwards wait for the answer to a ping
+    /// before reconnecting. Defaults to 10 seconds.
+    pub fn pong_timeout(mut self, timeout: Duration) -> Self {
+        self.pong_timeout = timeout;
+        self
+    }
+
+    /// Streams live orderbooks for `tickers` from the `orderbook_delta` channel.
+    ///
+    /// For each market the server first sends a full snapshot, then one delta per
+    /// change. A local [`Orderbook`] is kept per ticker and every update carries the
+    /// book with the change applied, so consumers never have to apply deltas
+    /// themselves. After a reconnect each market starts over with a fresh snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - The market tickers to subscribe to. More can be added or removed
+    ///   later through the returned [`Subscription`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Subscription`] streaming `Result<OrderbookUpdate, KalshiError>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let ws = kalshi_instance.websocket();
+    /// let mut updates = ws.subscribe_orderbook(vec!["SOME-MARKET-2024".to_string()]);
+    /// while let Some(update) = updates.next().await {
+    ///     let update = update.unwrap();
+    ///     println!("{}: {:?}", update.ticker, update.book.yes_mid());
+    /// }
+    /// ```
+    ///
+    pub fn subscribe_orderbook(&self, tickers: Vec<String>) -> Subscription<'_, OrderbookUpdate> {
+        let mut books: HashMap<String, LocalBook> = HashMap::new();
+        self.subscribe("orderbook_delta", tickers, move |event| match event {
+            ChannelEvent::Connected => {
+                books.clear();
+                None
+            }
+            ChannelEvent::Subscribed => None,
+            ChannelEvent::Message(message) => apply_orderbook_message(&mut books, message).map(Ok),
+            ChannelEvent::Error(e) => Some(Err(e)),
+        })
+    }
+
+    /// Fetches the current orderbook of one market over the WebSocket.
+    ///
+    /// Opens a connection, subscribes to the market's `orderbook_delta` channel, waits
+    /// for the full snapshot the server sends first, then unsubscribes and closes the
+    /// connection. No deltas are applied. Cheaper than [`Kalshi::get_orderbook`] when
+    /// many books are fetched at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - The market ticker.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Orderbook)`: The market's book as of the snapshot.
+    /// - `Err(KalshiError)`: A [`KalshiError::WebSocketRejected`] if the exchange refused the
+    ///   subscription (for example an unknown ticker), or a [`KalshiError::WebSocket`] if
+    ///   the connection failed or no snapshot arrived within 10 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// let book = kalshi_instance.websocket().snapshot_once("SOME-MARKET-2024").await.unwrap();
+    /// println!("{:?}", book.yes_mid());
+    /// ```
+    ///
+    pub async fn snapshot_once(&self, ticker: &str) -> Result<Orderbook, KalshiError> {
+        let (_changes, receiver) = mpsc::unbounded_channel();
+        let mut state = Connection::new("orderbook_delta", vec![ticker.to_string()], receiver);
+        self.connect(&mut state).await?;
+
+        let book = tokio::time::timeout(SNAPSHOT_TIMEOUT, state.first_snapshot(ticker))
+            .await
+            .map_err(|_| {
+                KalshiError::WebSocket(format!("no orderbook snapshot for {} within {:?}", ticker, SNAPSHOT_TIMEOUT))
+            })??;
+
+        // The book is already in hand, so failing to unsubscribe cleanly isn't an error.
+        if !state.sids.is_empty() {
+            let sids = state.sids.clone();
+            let _ = state.send("unsubscribe", serde_json::json!({ "sids": sids })).await;
+        }
+        if let Some(mut socket) = state.socket.take() {
+            let _ = socket.close(None).await;
+        }
+        Ok(book)
+    }
+
+    /// Streams live quotes for `tickers` from the `ticker` channel.
+    ///
+    /// The server pushes an update whenever a market's last price, best bid or ask,
+    /// volume or open interest changes. Each update's [`Snapshot`] has `yes_price` set
+    /// to the last traded price; the no quotes are the complements of the yes quotes.
+    ///
+    /// Tickers can be added or removed on the live connection with
+    /// [`Subscription::add_tickers`] and [`Subscription::remove_tickers`]. If the
+    /// exchange rejects a subscription (for example an unknown ticker), its error is
+    /// yielded as [`KalshiError::WebSocketRejected`] with the server's code and message, and the
+    /// tickers of the rejected command are dropped so reconnects don't request them again.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - The market tickers to subscribe to.
+    ///
+    /// # Returns
+    ///
+    /// A [`Subscription`] streaming `Result<TickerUpdate, KalshiError>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let ws = kalshi_instance.websocket();
+    /// let mut quotes = ws.subscribe_ticker(vec!["SOME-MARKET-2024".to_string()]);
+    /// quotes.add_tickers(vec!["OTHER-MARKET-2024".to_string()]);
+    /// while let Some(quote) = quotes.next().await {
+    ///     let quote = quote.unwrap();
+    ///     println!("{} {} / {}", quote.ticker, quote.snapshot.yes_bid, quote.snapshot.yes_ask);
+    /// }
+    /// ```
+    ///
+    pub fn subscribe_ticker(&self, tickers: Vec<String>) -> Subscription<'_, TickerUpdate> {
+        self.subscribe("ticker", tickers, |event| match event {
+            ChannelEvent::Connected | ChannelEvent::Subscribed => None,
+            ChannelEvent::Message(message) => parse_ticker_message(message).map(Ok),
+            ChannelEvent::Error(e) => Some(Err(e)),
+        })
+    }
+
+    /// Streams public trades in `tickers` from the `trade` channel.
+    ///
+    /// Every execution in a subscribed market is yielded as a [`Trade`], in the same
+    /// shape [`Kalshi::get_trades`] returns. Only trades made while connected are
+    /// delivered; use [`Kalshi::trades_stream`] to start with the history and to fill in
+    /// trades missed during a reconnect.
+    ///
+    /// # Arguments
+    ///
+    /// * `tickers` - The market tickers to subscribe to.
+    ///
+    /// # Returns
+    ///
+    /// A [`Subscription`] streaming `Result<Trade, KalshiError>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let ws = kalshi_instance.websocket();
+    /// let mut trades = ws.subscribe_trades(vec!["SOME-MARKET-2024".to_string()]);
+    /// while let Some(trade) = trades.next().await {
+    ///     let trade = trade.unwrap();
+    ///     println!("{} {:?} {}", trade.ticker, trade.taker_side, trade.count_fp.0);
+    /// }
+    /// ```
+    ///
+    pub fn subscribe_trades(&self, tickers: Vec<String>) -> Subscription<'_, Trade> {
+        self.subscribe("trade", tickers, |event| match event {
+            ChannelEvent::Connected | ChannelEvent::Subscribed => None,
+            ChannelEvent::Message(message) => parse_trade_message(message).map(Ok),
+            ChannelEvent::Error(e) => Some(Err(e)),
+        })
+    }
+
+    /// Sends an arbitrary command, such as a `subscribe` to a channel this crate has no
+    /// typed method for, and streams the messages that follow as raw JSON.
+    ///
+    /// The command is sent as is on a connection of its own, and again after every
+    /// reconnect. An `id` is added if it has none. Acknowledgements are not yielded (see
+    /// [`Subscription::raw_messages`] for every message); an `error` answer is yielded
+    /// as [`KalshiError::WebSocketRejected`]. Sequence gaps reconnect like they do for
+    /// the typed channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command, a JSON object with at least a `cmd` field.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Subscription)`: A [`Subscription`] streaming `Result<serde_json::Value, KalshiError>`.
+    /// - `Err(KalshiError)`: A `UserInputError` if `command` isn't an object with a `cmd`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let ws = kalshi_instance.websocket();
+    /// let mut messages = ws.send_raw(serde_json::json!({
+    ///     "cmd": "subscribe",
+    ///     "params": { "channels": ["market_lifecycle_v2"] },
+    /// })).unwrap();
+    /// while let Some(message) = messages.next().await {
+    ///     println!("{}", message.unwrap());
+    /// }
+    /// ```
+    ///
+    pub fn send_raw(&self, command: serde_json::Value) -> Result<Subscription<'_, serde_json::Value>, KalshiError> {
+        if !command.get("cmd").is_some_and(serde_json::Value::is_string) {
+            return Err(KalshiError::UserInputError(format!(
+                "a raw command must be a JSON object with a \"cmd\", got {}",
+                command
+            )));
+        }
+        Ok(self.subscribe_with("raw", Vec::new(), Some(command), |event| match event {
+            ChannelEvent::Connected | ChannelEvent::Subscribed => None,
+            ChannelEvent::Message(message) => Some(Ok(message.raw)),
+            ChannelEvent::Error(e) => Some(Err(e)),
+        }))
+    }
+
+    /// Subscribes to `channel` and turns its events into items with `handle`.
+    fn subscribe<T, F>(&self, channel: &'static str, tickers: Vec<String>, handle: F) -> Subscription<'static, T>
+    where
+        T: Send + Sync + 'static,
+        F: FnMut(ChannelEvent) -> Option<Result<T, KalshiError>> + Send + Sync + 'static,
+    {
+        self.subscribe_with(channel, tickers, None, handle)
+    }
+
+    /// Like [`subscribe`](KalshiWebSocket::subscribe), sending `command` instead of the
+    /// channel's `subscribe` when it is set.
+    fn subscribe_with<T, F>(
+        &self,
+        channel: &'static str,
+        tickers: Vec<String>,
+        command: Option<serde_json::Value>,
+        mut handle: F,
+    ) -> Subscription<'static, T>
+    where
+        T: Send + Sync + 'static,
+        F: FnMut(ChannelEvent) -> Option<Result<T, KalshiError>> + Send + Sync + 'static,
+    {
+        let (changes, receiver) = mpsc::unbounded_channel();
+        let mut state = Connection::new(channel, tickers, receiver);
+        state.command = command;
+        let raw = state.raw.clone();
+        let updates = self
+            .channel_events(state)
+            .filter_map(move |event| future::ready(handle(event)));
+        Subscription { updates: Box::pin(updates), changes, raw }
+    }
+
+    /// Connects, subscribes and yields everything received on `channel`, reconnecting
+    /// and resubscribing whenever the connection drops, stops answering pings or a
+    /// sequence gap shows up.
+    fn channel_events(&self, state: Connection) -> impl Stream<Item = ChannelEvent> + Send + Sync + 'static {
+        // Owned by the stream, so a subscription can outlive this `KalshiWebSocket`.
+        let ws = Arc::new(self.clone());
+        stream::unfold(state, move |mut state| {
+            let ws = ws.clone();
+            async move {
+                loop {
+                    let Some(socket) = state.socket.as_mut() else {
+                        if state.failures > 0 {
+                            tokio::time::sleep(reconnect_delay(state.failures)).await;
+                        }
+                        return Some(match ws.connect(&mut state).await {
+                            Ok(()) => (ChannelEvent::Connected, state),
+                            Err(e) => {
+                                state.failures += 1;
+                                (ChannelEvent::Error(e), state)
+                            }
+                        });
+                    };
+
+                    let heartbeat = match state.ping_sent {
+                        Some(sent) => sent + ws.pong_timeout,
+                        None => state.next_ping,
+                    };
+                    let received = tokio::select! {
+                        received = socket.next() => received,
+                        _ = tokio::time::sleep_until(heartbeat) => {
+                            if state.ping_sent.is_some() {
+                                state.socket = None;
+                                state.failures += 1;
+                                let e = KalshiError::WebSocket(format!("no pong within {:?}", ws.pong_timeout));
+                                return Some((ChannelEvent::Error(e), state));
+                            }
+                            if let Err(e) = state.ping().await {
+                                state.socket = None;
+                                state.failures += 1;
+                                return Some((ChannelEvent::Error(e), state));
+                            }
+                            continue;
+                        }
+                        Some(change) = state.changes.recv() => {
+                            state.apply(&change);
+                            if state.sids.is_empty() {
+                                // Not acknowledged yet; sent once the `subscribed` message arrives.
+                                state.pending.push(change);
+                            } else if let Err(e) = state.send_change(&change).await {
+                                state.socket = None;
+                                return Some((ChannelEvent::Error(e), state));
+                            }
+                            continue;
+                        }
+                    };
+                    let text = match received {
+                        Some(Ok(Message::Text(text))) => text,
+                        Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                            state.socket = None;
+                            state.failures += 1;
+                            continue;
+                        }
+                        Some(Ok(Message::Pong(_))) => {
+                            state.ping_sent = None;
+                            state.next_ping = Instant::now() + ws.heartbeat_interval;
+                            continue;
+                        }
+                        // Server pings are queued for an answer by tungstenite, which sends
+                        // it with the next read or write.
+                        Some(Ok(_)) => continue,
+                    };
+                    let Ok(value) = serde_json::from_str::<serde_json::Value>(text.as_str()) else {
+                        continue;
+                    };
+                    state.tap(&value);
+                    let Some(message) = ChannelMessage::parse(value) else {
+                        continue;
+                    };
+                    #[cfg(feature = "metrics")]
+                    crate::telemetry::record_ws_message(state.channel, &message.kind);
+                    match message.kind.as_str() {
+                        "subscribed" => {
+                            #[cfg(feature = "metrics")]
+                            crate::telemetry::record_ws_subscribed(state.channel);
+                            state.acknowledge(message.id);
+                            let first = state.sids.is_empty();
+                            state.sids.extend(message.msg.get("sid").and_then(|sid| sid.as_u64()));
+                            if !first {
+                                // Another group of a long ticker list; already reported.
+                                continue;
+                            }
+                            for change in std::mem::take(&mut state.pending) {
+                                if let Err(e) = state.send_change(&change).await {
+                                    state.socket = None;
+                                    return Some((ChannelEvent::Error(e), state));
+                                }
+                            }
+                            return Some((ChannelEvent::Subscribed, state));
+                        }
+                        "ok" | "unsubscribed" => {
+                            state.acknowledge(message.id);
+                            continue;
+                        }
+                        "error" => {
+                            state.reject(message.id);
+                            return Some((ChannelEvent::Error(rejection(&message)), state));
+                        }
+                        _ => {}
+                    }
+                    if let (Some(sid), Some(seq)) = (message.sid, message.seq) {
+                        if state.seqs.insert(sid, seq).is_some_and(|last| seq != last + 1) {
+                            // A message was missed; only a fresh subscription can repair the state.
+                            state.socket = None;
+                            state.failures += 1;
+                            continue;
+                        }
+                    }
+                    state.failures = 0;
+                    return Some((ChannelEvent::Message(message), state));
+                }
+            }
+        })
+    }
+
+    /// Opens an authenticated connection and subscribes to the state's channel and tickers,
+    /// recording the attempt when the `metrics` feature is enabled.
+    async fn connect(&self, state: &mut Connection) -> Result<(), KalshiError> {
+        let result = self.open(state).await;
+        #[cfg(feature = "metrics")]
+        {
+            crate::telemetry::record_ws_connect(state.channel, state.connected_before, result.is_ok());
+            state.connected_before = true;
+        }
+        result
+    }
+
+    /// The work of [`connect`](KalshiWebSocket::connect).
+    async fn open(&self, state: &mut Connection) -> Result<(), KalshiError> {
+        let mut request = self.url.as_str().into_client_request().map_err(websocket_error)?;
+        request.headers_mut().extend(self.kalshi.auth_headers("GET", WS_PATH)?);
+
+        let (socket, _) = tokio_tungstenite::connect_async(request).await.map_err(|e| match e {
+            tungstenite::Error::Http(response) if response.status() == http::StatusCode::UNAUTHORIZED => {
+                KalshiError::AuthFailed { key_id: self.kalshi.key_id.clone() }
+            }
+            e => websocket_error(e),
+        })?;
+        state.socket = Some(socket);
+        state.sids.clear();
+        state.seqs.clear();
+        // Changes queued for the old connection are already reflected in `tickers`.
+        state.pending.clear();
+        state.in_flight.clear();
+        state.ping_sent = None;
+        state.next_ping = Instant::now() + self.heartbeat_interval;
+
+        if let Some(command) = state.command.clone() {
+            if let Err(e) = state.send_raw(command).await {
+                state.socket = None;
+                return Err(e);
+            }
+            return Ok(());
+        }
+        if state.tickers.is_empty() && !state.all_markets {
+            // Every requested ticker was rejected; an empty list would mean every market.
+            return Ok(());
+        }
+        let groups: Vec<Vec<String>> = if state.tickers.is_empty() {
+            vec![Vec::new()]
+        } else {
+            state.tickers.chunks(TICKERS_PER_COMMAND).map(<[String]>::to_vec).collect()
+        };
+        for group in groups {
+            let mut params = serde_json::json!({ "channels": [state.channel] });
+            if !group.is_empty() {
+                params["market_tickers"] = group.clone().into();
+            }
+            match state.send("subscribe", params).await {
+                Ok(id) => {
+                    state.in_flight.insert(id, group);
+                }
+                Err(e) => {
+                    state.socket = None;
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A live WebSocket subscription, created by the `subscribe_*` methods of [`KalshiWebSocket`].
+///
+/// Poll it as a [`Stream`] to receive updates. The set of markets can be changed while
+/// it runs with [`add_tickers`](Subscription::add_tickers) and
+/// [`remove_tickers`](Subscription::remove_tickers), without reconnecting. Changes are
+/// also remembered across reconnects. Dropping the subscription closes the connection.
+pub struct Subscription<'a, T> {
+    updates: Pin<Box<dyn Stream<Item = Result<T, KalshiError>> + Send + Sync + 'a>>,
+    changes: mpsc::UnboundedSender<SubscriptionChange>,
+    raw: RawTap,
+}
+
+impl<T> Subscription<'_, T> {
+    /// Starts receiving updates for `tickers` as well.
+    pub fn add_tickers(&self, tickers: Vec<String>) {
+        // The receiver lives in `updates`, so it can't be gone while `self` exists.
+        let _ = self.changes.send(SubscriptionChange { add: true, tickers });
+    }
+
+    /// Stops receiving updates for `tickers`.
+    pub fn remove_tickers(&self, tickers: Vec<String>) {
+        let _ = self.changes.send(SubscriptionChange { add: false, tickers });
+    }
+
+    /// Streams every JSON message the server sends on this subscription's connection,
+    /// unparsed, in place of the typed updates.
+    ///
+    /// Includes what the typed stream skips, such as acknowledgements and fields or
+    /// message types this crate doesn't know yet. Typed updates produced while this
+    /// stream is polled are dropped; errors are still yielded as `Err`. Reconnects,
+    /// ticker changes and heartbeats carry on as usual, and the typed stream can be
+    /// polled again once this one is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // Assuming `kalshi_instance` is an instance of `Kalshi`
+    /// use futures_util::StreamExt;
+    ///
+    /// let ws = kalshi_instance.websocket();
+    /// let mut quotes = ws.subscribe_ticker(vec!["SOME-MARKET-2024".to_string()]);
+    /// let mut raw = quotes.raw_messages();
+    /// while let Some(message) = raw.next().await {
+    ///     println!("{}", message.unwrap());
+    /// }
+    /// ```
+    ///
+    pub fn raw_messages(&mut self) -> Pin<Box<dyn Stream<Item = Result<serde_json::Value, KalshiError>> + Send + '_>> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        *self.raw.lock().unwrap_or_else(PoisonError::into_inner) = Some(sender);
+        // An error (`Some`) or the end (`None`) of the updates, held back until the
+        // messages received before it are out.
+        let held: Option<Option<KalshiError>> = None;
+        Box::pin(stream::unfold((self, receiver, held), |(subscription, mut receiver, mut held)| async move {
+            loop {
+                if let Ok(value) = receiver.try_recv() {
+                    return Some((Ok(value), (subscription, receiver, held)));
+                }
+                match held.take() {
+                    Some(Some(e)) => return Some((Err(e), (subscription, receiver, None))),
+                    Some(None) => return None,
+                    None => {}
+                }
+                // Polling the updates drives the connection, which feeds `receiver`.
+                tokio::select! {
+                    biased;
+                    Some(value) = receiver.recv() => return Some((Ok(value), (subscription, receiver, held))),
+                    update = subscription.updates.next() => match update {
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => held = Some(Some(e)),
+                        None => held = Some(None),
+                    },
+                }
+            }
+        }))
+    }
+}
+
+impl<T> Stream for Subscription<'_, T> {
+    type Item = Result<T, KalshiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.updates.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for Subscription<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription").finish_non_exhaustive()
+    }
+}
+
+/// The WebSocket URL on the same host as the REST `base_url`.
+fn websocket_url(base_url: &str) -> String {
+    let host = base_url.trim_end_matches('/').trim_end_matches("/trade-api/v2");
+    let host = match host.split_once("://") {
+        Some(("https", rest)) => format!("wss://{}", rest),
+        Some(("http", rest)) => format!("ws://{}", rest),
+        _ => host.to_string(),
+    };
+    format!("{}{}", host, WS_PATH)
+}
+
+fn reconnect_delay(failures: u32) -> Duration {
+    RECONNECT_DELAY
+        .saturating_mul(2u32.saturating_pow(failures.saturating_sub(1)))
+        .min(MAX_RECONNECT_DELAY)
+}
+
+fn websocket_error(e: tungstenite::Error) -> KalshiError {
+    KalshiError::WebSocket(e.to_string())
+}
+
+/// The error for an `error` message answering one of the client's commands.
+fn rejection(message: &ChannelMessage) -> KalshiError {
+    let code = message.msg.get("code").and_then(|c| c.as_i64());
+    let reason = message.msg.get("msg").and_then(|m| m.as_str()).unwrap_or("unknown error");
+    KalshiError::WebSocketRejected { code, message: reason.to_string() }
+}
+
+// -------- Connection plumbing --------
+
+/// Where a connection copies every message it receives while
+/// [`Subscription::raw_messages`] is in use.
+type RawTap = Arc<Mutex<Option<mpsc::UnboundedSender<serde_json::Value>>>>;
+
+/// State carried between polls of [`KalshiWebSocket::channel_events`].
+struct Connection {
+    channel: &'static str,
+    /// Subscribed without a ticker list, which the server takes as every market.
+    all_markets: bool,
+    /// The markets currently wanted, including changes made since subscribing. Tickers
+    /// the server rejected are taken out again, so reconnects don't resend them.
+    tickers: Vec<String>,
+    changes: mpsc::UnboundedReceiver<SubscriptionChange>,
+    socket: Option<Socket>,
+    /// Subscription ids acknowledged on the current connection.
+    sids: Vec<u64>,
+    /// Changes made before the current subscription was acknowledged.
+    pending: Vec<SubscriptionChange>,
+    /// Tickers added by each command the server hasn't answered yet, by command id.
+    in_flight: HashMap<u64, Vec<String>>,
+    /// Last sequence number seen per subscription id.
+    seqs: HashMap<u64, u64>,
+    next_id: u64,
+    /// Consecutive failed or dropped connections, driving the reconnect backoff.
+    failures: u32,
+    /// When to ping next if no ping is outstanding.
+    next_ping: Instant,
+    /// When the outstanding ping was sent; cleared by its pong.
+    ping_sent: Option<Instant>,
+    /// A raw command sent on connecting instead of the channel's `subscribe`.
+    command: Option<serde_json::Value>,
+    raw: RawTap,
+    /// Whether a connection was attempted before, making the next one a reconnect.
+    #[cfg(feature = "metrics")]
+    connected_before: bool,
+}
+
+impl Connection {
+    fn new(channel: &'static str, tickers: Vec<String>, changes: mpsc::UnboundedReceiver<SubscriptionChange>) -> Self {
+        Connection {
+            channel,
+            all_markets: tickers.is_empty(),
+            tickers,
+            changes,
+            socket: None,
+            sids: Vec::new(),
+            pending: Vec::new(),
+            in_flight: HashMap::new(),
+            seqs: HashMap::new(),
+            next_id: 1,
+            failures: 0,
+            next_ping: Instant::now(),
+            ping_sent: None,
+            command: None,
+            raw: RawTap::default(),
+            #[cfg(feature = "metrics")]
+            connected_before: false,
+        }
+    }
+
+    /// Records `change` in the wanted ticker list.
+    fn apply(&mut self, change: &SubscriptionChange) {
+        self.tickers.retain(|t| !change.tickers.contains(t));
+        if change.add {
+            self.tickers.extend(change.tickers.iter().cloned());
+        }
+    }
+
+    /// Forgets the tickers added by command `id` once the server has accepted it.
+    fn acknowledge(&mut self, id: Option<u64>) {
+        if let Some(id) = id {
+            self.in_flight.remove(&id);
+        }
+    }
+
+    /// Takes the tickers added by the rejected command `id` back out of the wanted list.
+    fn reject(&mut self, id: Option<u64>) {
+        if let Some(rejected) = id.and_then(|id| self.in_flight.remove(&id)) {
+            self.tickers.retain(|t| !rejected.contains(t));
+        }
+    }
+
+    /// Sends `change` to the server as `update_subscription` commands of at most
+    /// [`TICKERS_PER_COMMAND`] tickers each.
+    async fn send_change(&mut self, change: &SubscriptionChange) -> Result<(), KalshiError> {
+        for group in change.tickers.chunks(TICKERS_PER_COMMAND) {
+            let params = serde_json::json!({
+                "sids": self.sids,
+                "market_tickers": group,
+                "action": if change.add { "add_markets" } else { "delete_markets" },
+            });
+            let id = self.send("update_subscription", params).await?;
+            if change.add {
+                self.in_flight.insert(id, group.to_vec());
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the connection until the orderbook snapshot for `ticker` arrives.
+    async fn first_snapshot(&mut self, ticker: &str) -> Result<Orderbook, KalshiError> {
+        let mut books = HashMap::new();
+        loop {
+            let socket = self.socket.as_mut().ok_or_else(|| KalshiError::WebSocket("not connected".to_string()))?;
+            let text = match socket.next().await {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(Message::Close(_))) | None => {
+                    return Err(KalshiError::WebSocket("connection closed before the snapshot arrived".to_string()))
+                }
+                Some(Err(e)) => return Err(websocket_error(e)),
+                Some(Ok(_)) => continue,
+            };
+            let Ok(message) = serde_json::from_str::<ChannelMessage>(text.as_str()) else {
+                continue;
+            };
+            match message.kind.as_str() {
+                "subscribed" => self.sids.extend(message.msg.get("sid").and_then(|sid| sid.as_u64())),
+                "error" => return Err(rejection(&message)),
+                "orderbook_snapshot" => {
+                    if let Some(update) = apply_orderbook_message(&mut books, message) {
+                        if update.ticker == ticker {
+                            return Ok(update.book);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Copies `message` to [`Subscription::raw_messages`], if it is being polled.
+    fn tap(&self, message: &serde_json::Value) {
+        let mut raw = self.raw.lock().unwrap_or_else(PoisonError::into_inner);
+        if raw.as_ref().is_some_and(|sender| sender.send(message.clone()).is_err()) {
+            *raw = None;
+        }
+    }
+
+    /// Pings the server; the connection is dead if no pong follows in time.
+    async fn ping(&mut self) -> Result<(), KalshiError> {
+        let socket = self.socket.as_mut().ok_or_else(|| KalshiError::WebSocket("not connected".to_string()))?;
+        socket.send(Message::Ping(Vec::new().into())).await.map_err(websocket_error)?;
+        self.ping_sent = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Sends a command, returning the id its answer will carry.
+    async fn send(&mut self, cmd: &str, params: serde_json::Value) -> Result<u64, KalshiError> {
+        let socket = self.socket.as_mut().ok_or_else(|| KalshiError::WebSocket("not connected".to_string()))?;
+        let id = self.next_id;
+        let command = serde_json::json!({ "id": id, "cmd": cmd, "params": params });
+        self.next_id += 1;
+        socket
+            .send(Message::Text(command.to_string().into()))
+            .await
+            .map_err(websocket_error)?;
+        Ok(id)
+    }
+
+    /// Sends a caller-built command, giving it the next id unless it has one.
+    async fn send_raw(&mut self, mut command: serde_json::Value) -> Result<(), KalshiError> {
+        let socket = self.socket.as_mut().ok_or_else(|| KalshiError::WebSocket("not connected".to_string()))?;
+        match command.get("id").and_then(serde_json::Value::as_u64) {
+            Some(id) => self.next_id = self.next_id.max(id + 1),
+            None => {
+                command["id"] = self.next_id.into();
+                self.next_id += 1;
+            }
+        }
+        socket
+            .send(Message::Text(command.to_string().into()))
+            .await
+            .map_err(websocket_error)
+    }
+}
+
+/// Tickers added to or removed from a live [`Subscription`].
+struct SubscriptionChange {
+    add: bool,
+    tickers: Vec<String>,
+}
+
+enum ChannelEvent {
+    /// A new connection was established; state built from the old one is stale.
+    Connected,
+    /// The server acknowledged the subscription; messages from here on are delivered.
+    Subscribed,
+    Message(ChannelMessage),
+    Error(KalshiError),
+}
+
+/// The envelope every channel message arrives in.
+#[derive(Debug, Deserialize)]
+struct ChannelMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    /// Id of the command this message answers, if it is an answer.
+    id: Option<u64>,
+    sid: Option<u64>,
+    seq: Option<u64>,
+    #[serde(default)]
+    msg: serde_json::Value,
+    /// The whole message as received.
+    #[serde(skip)]
+    raw: serde_json::Value,
+}
+
+impl ChannelMessage {
+    /// Reads the envelope of `value`, keeping `value` itself as `raw`.
+    fn parse(value: serde_json::Value) -> Option<Self> {
+        let mut message: ChannelMessage = serde_json::from_value(value.clone()).ok()?;
+        message.raw = value;
+        Some(message)
+    }
+}
+
+// -------- Orderbook channel --------
+
+/// One change to a market's book, yielded by [`KalshiWebSocket::subscribe_orderbook`].
+#[derive(Debug, Clone)]
+pub struct OrderbookUpdate {
+    /// The market the update belongs to.
+    pub ticker: String,
+    /// What changed.
+    pub change: OrderbookChange,
+    /// The full book after the change.
+    pub book: Orderbook,
+}
+
+/// The kind of change an [`OrderbookUpdate`] carries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderbookChange {
+    /// The book was replaced by a full snapshot.
+    Snapshot,
+    /// The quantity resting at one price level changed.
+    Delta {
+        /// The side of the book the level is on.
+        side: OrderSide,
+        /// Bid price of the level in dollars, on its own side.
+        price_dollars: f64,
+        /// Contracts added (positive) or removed (negative) at that price.
+        delta: f64,
+    },
+}
+
+/// Bids per side, keyed on hundredths of a cent, in contracts.
+#[derive(Debug, Default)]
+struct LocalBook {
+    yes: BTreeMap<i64, f64>,
+    no: BTreeMap<i64, f64>,
+}
+
+impl LocalBook {
+    fn side_mut(&mut self, side: &OrderSide) -> Option<&mut BTreeMap<i64, f64>> {
+        match side {
+            OrderSide::Yes => Some(&mut self.yes),
+            OrderSide::No => Some(&mut self.no),
+            OrderSide::Unknown(_) => None,
+        }
+    }
+
+    fn to_orderbook(&self) -> Orderbook {
+        let levels = |side: &BTreeMap<i64, f64>| {
+            side.iter()
+                .map(|(key, quantity)| {
+                    PriceLevelDollarsCountFp([format!("{:.4}", *key as f64 / 10_000.0), format!("{:.2}", quantity)])
+                })
+                .collect()
+        };
+        Orderbook { yes_dollars: levels(&self.yes), no_dollars: levels(&self.no) }
+    }
+}
+
+/// Applies an `orderbook_snapshot` or `orderbook_delta` message to `books`.
+///
+/// Returns `None` for other message types, for messages that don't parse, and for
+/// deltas on a market whose snapshot hasn't arrived yet.
+fn apply_orderbook_message(books: &mut HashMap<String, LocalBook>, message: ChannelMessage) -> Option<OrderbookUpdate> {
+    let msg = &message.msg;
+    let ticker = msg.get("market_ticker")?.as_str()?.to_string();
+    let change = match message.kind.as_str() {
+        "orderbook_snapshot" => {
+            let side = |name: &str| -> BTreeMap<i64, f64> {
+                let mut levels = BTreeMap::new();
+                for level in dollar_levels(msg, name).unwrap_or_default() {
+                    if let (Some(price), Some(quantity)) = (level.first(), level.get(1)) {
+                        if quantity > &0.0 {
+                            *levels.entry(price_key(*price)).or_default() += quantity;
+                        }
+                    }
+                }
+                levels
+            };
+            books.insert(ticker.clone(), LocalBook { yes: side("yes"), no: side("no") });
+            OrderbookChange::Snapshot
+        }
+        "orderbook_delta" => {
+            let side: OrderSide = msg.get("side")?.as_str()?.parse().ok()?;
+            let price_dollars = msg
+                .get("price_dollars")
+                .and_then(number)
+                .or_else(|| msg.get("price").and_then(number).map(|cents| cents / 100.0))?;
+            let delta = msg.get("delta_fp").and_then(number).or_else(|| msg.get("delta").and_then(number))?;
+
+            let levels = books.get_mut(&ticker)?.side_mut(&side)?;
+            let key = price_key(price_dollars);
+            let quantity = levels.get(&key).copied().unwrap_or(0.0) + delta;
+            if quantity > 1e-9 {
+                levels.insert(key, quantity);
+            } else {
+                levels.remove(&key);
+            }
+            OrderbookChange::Delta { side, price_dollars: key as f64 / 10_000.0, delta }
+        }
+        _ => return None,
+    };
+    let book = books.get(&ticker)?.to_orderbook();
+    Some(OrderbookUpdate { ticker, change, book })
+}
+
+/// The `[price_dollars, quantity]` levels of one side of a snapshot.
+///
+/// Reads `<side>_dollars_fp` or `<side>_dollars`, falling back to the legacy
+/// `<side>` field priced in cents.
+fn dollar_levels(msg: &serde_json::Value, side: &str) -> Option<Vec<Vec<f64>>> {
+    let parse = |value: &serde_json::Value, scale: f64| -> Option<Vec<Vec<f64>>> {
+        let levels = value.as_array()?.iter().filter_map(|level| {
+            let level = level.as_array()?;
+            Some(vec![number(level.first()?)? * scale, number(level.get(1)?)?])
+        });
+        Some(levels.collect())
+    };
+    msg.get(format!("{}_dollars_fp", side))
+        .or_else(|| msg.get(format!("{}_dollars", side)))
+        .and_then(|levels| parse(levels, 1.0))
+        .or_else(|| msg.get(side).and_then(|levels| parse(levels, 0.01)))
+}
+
+/// A JSON number, or a string holding one.
+fn number(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        other => other.as_f64(),
+    }
+}
+
+/// `price` in dollars as hundredths of a cent, so float noise can't split a level.
+fn price_key(price: f64) -> i64 {
+    (price * 10_000.0).round() as i64
+}
+
+// -------- Ticker channel --------
+
+/// A market's latest quotes, yielded by [`KalshiWebSocket::subscribe_ticker`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickerUpdate {
+    /// The market the quotes belong to.
+    pub ticker: String,
+    /// The quotes, with `yes_price` set to the last traded price.
+    pub snapshot: Snapshot,
+}
+
+/// Parses a `ticker` channel message; `None` for other message types.
+fn parse_ticker_message(message: ChannelMessage) -> Option<TickerUpdate> {
+    if message.kind != "ticker" {
+        return None;
+    }
+    let msg = &message.msg;
+    let ticker = msg.get("market_ticker")?.as_str()?.to_string();
+    let dollars = |name: &str| {
+        msg.get(format!("{}_dollars", name))
+            .and_then(number)
+            .or_else(|| msg.get(name).and_then(number).map(|cents| cents / 100.0))
+            .unwrap_or(0.0)
+    };
+    let count = |name: &str| {
+        msg.get(format!("{}_fp", name))
+            .and_then(number)
+            .or_else(|| msg.get(name).and_then(number))
+            .map_or(0, |n| n.round() as i64)
+    };
+    let (yes_bid, yes_ask) = (dollars("yes_bid"), dollars("yes_ask"));
+    // A missing quote stays 0 rather than becoming a 1.00 complement.
+    let no_quote = |yes: f64| if yes > 0.0 { complement(yes) } else { 0.0 };
+    let snapshot = Snapshot {
+        ts: msg.get("ts").and_then(|ts| ts.as_i64()).unwrap_or_default(),
+        yes_price: dollars("price"),
+        yes_bid,
+        yes_ask,
+        no_bid: no_quote(yes_ask),
+        no_ask: no_quote(yes_bid),
+        volume: count("volume"),
+        open_interest: count("open_interest"),
+    };
+    Some(TickerUpdate { ticker, snapshot })
+}
+
+// -------- Trade channel --------
+
+/// Parses a `trade` channel message into the REST [`Trade`] shape; `None` for other
+/// message types.
+fn parse_trade_message(message: ChannelMessage) -> Option<Trade> {
+    if message.kind != "trade" {
+        return None;
+    }
+    let msg = &message.msg;
+    let dollars = |name: &str| {
+        msg.get(format!("{}_dollars", name))
+            .and_then(number)
+            .or_else(|| msg.get(name).and_then(number).map(|cents| cents / 100.0))
+            .map(|price| FixedPointDollars(format!("{:.4}", price)))
+    };
+    let count = msg.get("count_fp").and_then(number).or_else(|| msg.get("count").and_then(number))?;
+    Some(Trade {
+        trade_id: msg.get("trade_id")?.as_str()?.to_string(),
+        ticker: msg.get("market_ticker")?.as_str()?.to_string(),
+        yes_price_dollars: dollars("yes_price")?,
+        no_price_dollars: dollars("no_price")?,
+        count_fp: FixedPointCount(format!("{:.2}", count)),
+        taker_side: msg.get("taker_side")?.as_str()?.parse().ok()?,
+        created_time: chrono::DateTime::from_timestamp(msg.get("ts")?.as_i64()?, 0)?,
+    })
+}
+
+/// What [`Kalshi::trades_stream`] receives from its live subscription.
+enum TapeEvent {
+    Subscribed,
+    Trade(Trade),
+}
+
+/// Handoff state of [`Kalshi::trades_stream`].
+#[derive(Default)]
+struct TradeTape {
+    /// Ids of the trades yielded within [`TRADE_DEDUP_WINDOW_SECS`] of the newest one.
+    seen: HashSet<String>,
+    /// The same trades as `(created_ts, trade_id)`, oldest first, for pruning `seen`.
+    recent: VecDeque<(i64, String)>,
+    /// Creation time of the newest trade yielded.
+    newest: Option<i64>,
+    /// Set when a subscription is acknowledged, until the history has been replayed.
+    backfill_pending: bool,
+    /// Consecutive failed backfills, driving the retry backoff.
+    backfill_failures: u32,
+}
+
+impl TradeTape {
+    /// Records `trade`, returning `false` if it was already yielded.
+    fn admit(&mut self, trade: &Trade) -> bool {
+        if !self.seen.insert(trade.trade_id.clone()) {
+            return false;
+        }
+        let ts = trade.created_time.timestamp();
+        let newest = self.newest.map_or(ts, |newest| newest.max(ts));
+        self.newest = Some(newest);
+        self.recent.push_back((ts, trade.trade_id.clone()));
+        while self.recent.front().is_some_and(|(ts, _)| *ts < newest - TRADE_DEDUP_WINDOW_SECS) {
+            if let Some((_, id)) = self.recent.pop_front() {
+                self.seen.remove(&id);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    /// One step of a scripted mock connection.
+    enum Step {
+        /// Sends a message to the client.
+        Send(serde_json::Value),
+        /// Reads a command from the client and checks it contains the given text.
+        Expect(&'static str),
+        /// Reads a command from the client and checks it contains the first text but not
+        /// the second.
+        ExpectWithout(&'static str, &'static str),
+        /// Answers the client's next REST request, which must contain the given text,
+        /// with the JSON body.
+        Respond(&'static str, serde_json::Value),
+        /// Stops reading for a while, so pings go unanswered.
+        Stall(Duration),
+    }
+
+    /// Reads the client's next command, skipping its pings.
+    async fn next_command(socket: &mut WebSocketStream<TcpStream>) -> String {
+        loop {
+            match socket.next().await.unwrap().unwrap() {
+                Message::Text(command) => return command.to_string(),
+                _ => continue,
+            }
+        }
+    }
+
+    /// Serves one scripted WebSocket connection per entry of `sessions`.
+    async fn mock_server(sessions: Vec<Vec<Step>>) -> Kalshi {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            for steps in sessions {
+                let (stream, _) = listener.accept().await.unwrap();
+                // The callback's signature is fixed by tungstenite.
+                #[allow(clippy::result_large_err)]
+                let check_auth = |request: &Request, response: Response| {
+                    assert_eq!(request.uri().path(), WS_PATH);
+                    assert!(request.headers().contains_key("KALSHI-ACCESS-SIGNATURE"));
+                    Ok(response)
+                };
+                let mut socket = tokio_tungstenite::accept_hdr_async(stream, check_auth).await.unwrap();
+                for step in steps {
+                    match step {
+                        Step::Send(message) => {
+                            socket.send(Message::Text(message.to_string().into())).await.unwrap()
+                        }
+                        Step::Expect(text) => {
+                            let command = next_command(&mut socket).await;
+                            assert!(command.contains(text), "{} does not contain {}", command, text);
+                        }
+                        Step::ExpectWithout(text, absent) => {
+                            let command = next_command(&mut socket).await;
+                            assert!(command.contains(text), "{} does not contain {}", command, text);
+                            assert!(!command.contains(absent), "{} contains {}", command, absent);
+                        }
+                        Step::Respond(text, body) => {
+                            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+                            let (mut http, _) = listener.accept().await.unwrap();
+                            let mut request = Vec::new();
+                            let mut buf = [0u8; 1024];
+                            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                                let n = http.read(&mut buf).await.unwrap();
+                                if n == 0 {
+                                    break;
+                                }
+                                request.extend_from_slice(&buf[..n]);
+                            }
+                            let request = String::from_utf8_lossy(&request);
+                            assert!(request.contains(text), "{} does not contain {}", request, text);
+                            let body = body.to_string();
+                            let response = format!(
+                                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                                body.len(),
+                                body
+                            );
+                            http.write_all(response.as_bytes()).await.unwrap();
+                        }
+                        Step::Stall(wait) => tokio::time::sleep(wait).await,
+                    }
+                }
+                socket.close(None).await.ok();
+            }
+        });
+
+        let mut client = crate::auth::test::offline_client();
+        client.base_url = format!("http://{}/trade-api/v2", addr);
+        client.client = reqwest::Client::builder().no_proxy().build().unwrap();
+        client
+    }
+
+    fn snapshot(seq: u64, yes: &[(&str, &str)], no: &[(&str, &str)]) -> Step {
+        Step::Send(serde_json::json!({
+            "type": "orderbook_snapshot",
+            "sid": 1,
+            "seq": seq,
+            "msg": { "market_ticker": "SOME-MARKET", "yes_dollars_fp": yes, "no_dollars_fp": no },
+        }))
+    }
+
+    fn delta(seq: u64, side: &str, price: &str, delta: &str) -> Step {
+        Step::Send(serde_json::json!({
+            "type": "orderbook_delta",
+            "sid": 1,
+            "seq": seq,
+            "msg": { "market_ticker": "SOME-MARKET", "side": side, "price_dollars": price, "delta_fp": delta },
+        }))
+    }
+
+    fn subscribed() -> Step {
+        Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "trade", "sid": 1 } }))
+    }
+
+    fn live_trade(seq: u64, id: &str, ts: i64) -> Step {
+        Step::Send(serde_json::json!({
+            "type": "trade",
+            "sid": 1,
+            "seq": seq,
+            "msg": {
+                "trade_id": id,
+                "market_ticker": "SOME-MARKET",
+                "yes_price": 45,
+                "no_price": 55,
+                "count_fp": "10.00",
+                "taker_side": "yes",
+                "ts": ts,
+            },
+        }))
+    }
+
+    /// A `/markets/trades` page holding `trades` as `(trade_id, ts)`, newest first.
+    fn trades_page(trades: &[(&str, i64)]) -> serde_json::Value {
+        let trades: Vec<_> = trades
+            .iter()
+            .map(|(id, ts)| {
+                let created_time = chrono::DateTime::from_timestamp(*ts, 0).unwrap().to_rfc3339();
+                serde_json::to_value(crate::market::test::trade_with(id, "yes", &created_time)).unwrap()
+            })
+            .collect();
+        serde_json::json!({ "trades": trades, "cursor": "" })
+    }
+
+    #[tokio::test]
+    async fn test_trades_stream_hands_off_without_gaps_or_duplicates() {
+        let t = 1700000000;
+        let client = mock_server(vec![
+            vec![
+                Step::Expect(r#""channels":["trade"]"#),
+                subscribed(),
+                // "t2" happened after the subscription but before the backfill's request.
+                live_trade(1, "t2", t + 20),
+                live_trade(2, "t3", t + 30),
+                Step::Respond("min_ts=1700000000", trades_page(&[("t2", t + 20), ("t1", t + 10)])),
+            ],
+            // "t4" happened while disconnected; the backfill resumes from "t3".
+            vec![
+                Step::Expect(r#""channels":["trade"]"#),
+                subscribed(),
+                live_trade(1, "t4", t + 40),
+                live_trade(2, "t5", t + 50),
+                Step::Respond("min_ts=1700000030", trades_page(&[("t4", t + 40), ("t3", t + 30)])),
+            ],
+        ])
+        .await;
+
+        let trades: Vec<Trade> = client
+            .trades_stream("SOME-MARKET", t)
+            .filter_map(|trade| future::ready(trade.ok()))
+            .take(5)
+            .collect()
+            .await;
+        let ids: Vec<_> = trades.iter().map(|t| t.trade_id.as_str()).collect();
+        assert_eq!(ids, ["t1", "t2", "t3", "t4", "t5"]);
+
+        // Live trades take the same shape as replayed ones.
+        let live = &trades[2];
+        assert_eq!((live.ticker.as_str(), live.yes_price_dollars.0.as_str()), ("SOME-MARKET", "0.4500"));
+        assert_eq!((live.count_fp.0.as_str(), live.created_time.timestamp()), ("10.00", t + 30));
+    }
+
+    #[test]
+    fn test_websocket_url() {
+        assert_eq!(
+            websocket_url("https://api.elections.kalshi.com/trade-api/v2"),
+            "wss://api.elections.kalshi.com/trade-api/ws/v2"
+        );
+        assert_eq!(websocket_url("http://127.0.0.1:8080"), "ws://127.0.0.1:8080/trade-api/ws/v2");
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_orderbook_applies_deltas_and_resubscribes() {
+        let client = mock_server(vec![
+            vec![
+                Step::Expect("orderbook_delta"),
+                snapshot(1, &[("0.4000", "10.00")], &[("0.5500", "3.00")]),
+                delta(2, "yes", "0.4200", "5.00"),
+                delta(3, "no", "0.5500", "-3.00"),
+                // Sequence 4 is missing: the client must resubscribe.
+                delta(5, "yes", "0.4000", "-10.00"),
+            ],
+            vec![Step::Expect("orderbook_delta"), snapshot(1, &[("0.3000", "1.00")], &[])],
+        ])
+        .await;
+        let ws = client.websocket();
+        let updates: Vec<OrderbookUpdate> = ws
+            .subscribe_orderbook(vec!["SOME-MARKET".to_string()])
+            .take(4)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(updates[0].change, OrderbookChange::Snapshot);
+        assert_eq!(updates[1].book.best_yes_bid(), Some((0.42, 5.0)));
+        assert_eq!(
+            updates[2].change,
+            OrderbookChange::Delta { side: OrderSide::No, price_dollars: 0.55, delta: -3.0 }
+        );
+        assert!(updates[2].book.no_dollars.is_empty());
+        assert_eq!(updates[2].book.yes_dollars.len(), 2);
+        assert_eq!(updates[3].change, OrderbookChange::Snapshot);
+        assert_eq!(updates[3].book.best_yes_bid(), Some((0.3, 1.0)));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_ticker_updates_live_subscription() {
+        let client = mock_server(vec![vec![
+            Step::Expect(r#""channels":["ticker"]"#),
+            Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "ticker", "sid": 7 } })),
+            Step::Send(serde_json::json!({
+                "type": "ticker",
+                "sid": 7,
+                "msg": {
+                    "market_ticker": "SOME-MARKET",
+                    "price_dollars": "0.4500",
+                    "yes_bid_dollars": "0.4400",
+                    "yes_ask_dollars": "0.4600",
+                    "volume_fp": "1200.00",
+                    "open_interest": 300,
+                    "ts": 1700000000,
+                },
+            })),
+            Step::Expect(r#""action":"add_markets""#),
+            Step::Send(serde_json::json!({
+                "id": 2,
+                "type": "error",
+                "msg": { "code": 6, "msg": "Market not found: BAD-MARKET" },
+            })),
+        ]])
+        .await;
+        let ws = client.websocket();
+        let mut quotes = ws.subscribe_ticker(vec!["SOME-MARKET".to_string()]);
+
+        let quote = quotes.next().await.unwrap().unwrap();
+        assert_eq!(quote.ticker, "SOME-MARKET");
+        assert_eq!(
+            quote.snapshot,
+            Snapshot {
+                ts: 1700000000,
+                yes_price: 0.45,
+                yes_bid: 0.44,
+                yes_ask: 0.46,
+                no_bid: 0.54,
+                no_ask: 0.56,
+                volume: 1200,
+                open_interest: 300,
+            }
+        );
+
+        quotes.add_tickers(vec!["BAD-MARKET".to_string()]);
+        match quotes.next().await.unwrap() {
+            Err(KalshiError::WebSocketRejected { code, message }) => {
+                assert_eq!((code, message.as_str()), (Some(6), "Market not found: BAD-MARKET"))
+            }
+            other => panic!("expected a rejection, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejected_ticker_is_not_resubscribed() {
+        let quote = |sid: u64| {
+            Step::Send(serde_json::json!({
+                "type": "ticker",
+                "sid": sid,
+                "msg": { "market_ticker": "SOME-MARKET", "price_dollars": "0.4500", "ts": 1700000000 },
+            }))
+        };
+        let client = mock_server(vec![
+            vec![
+                Step::Expect(r#""market_tickers":["SOME-MARKET"]"#),
+                Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "ticker", "sid": 1 } })),
+                quote(1),
+                Step::Expect(r#""action":"add_markets""#),
+                Step::Send(serde_json::json!({
+                    "id": 2,
+                    "type": "error",
+                    "msg": { "code": 6, "msg": "Market not found: BAD-MARKET" },
+                })),
+            ],
+            // The connection dropped: only the accepted ticker is subscribed again.
+            vec![
+                Step::ExpectWithout(r#""market_tickers":["SOME-MARKET"]"#, "BAD-MARKET"),
+                Step::Send(serde_json::json!({ "id": 3, "type": "subscribed", "msg": { "channel": "ticker", "sid": 2 } })),
+                quote(2),
+            ],
+        ])
+        .await;
+        let ws = client.websocket();
+        let mut quotes = ws.subscribe_ticker(vec!["SOME-MARKET".to_string()]);
+
+        assert!(quotes.next().await.unwrap().is_ok());
+        quotes.add_tickers(vec!["BAD-MARKET".to_string()]);
+        assert!(quotes.next().await.unwrap().is_err());
+        assert_eq!(quotes.next().await.unwrap().unwrap().ticker, "SOME-MARKET");
+    }
+
+    #[tokio::test]
+    async fn test_unanswered_ping_reconnects() {
+        let client = mock_server(vec![
+            vec![
+                Step::Expect("subscribe"),
+                Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "ticker", "sid": 1 } })),
+                Step::Stall(Duration::from_millis(500)),
+            ],
+            vec![
+                Step::Expect("subscribe"),
+                Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "ticker", "sid": 1 } })),
+                Step::Send(serde_json::json!({
+                    "type": "ticker",
+                    "sid": 1,
+                    "msg": { "market_ticker": "SOME-MARKET", "price_dollars": "0.4500", "ts": 1700000000 },
+                })),
+            ],
+        ])
+        .await;
+        let ws = client
+            .websocket()
+            .heartbeat_interval(Duration::from_millis(20))
+            .pong_timeout(Duration::from_millis(50));
+        let mut quotes = ws.subscribe_ticker(vec!["SOME-MARKET".to_string()]);
+
+        match quotes.next().await.unwrap() {
+            Err(KalshiError::WebSocket(message)) => assert!(message.contains("no pong"), "{}", message),
+            other => panic!("expected a dead connection, got {:?}", other.map(|q| q.ticker)),
+        }
+        assert_eq!(quotes.next().await.unwrap().unwrap().ticker, "SOME-MARKET");
+    }
+
+    #[tokio::test]
+    async fn test_long_ticker_list_is_subscribed_in_groups() {
+        let tickers: Vec<String> = (0..500).map(|i| format!("M-{:03}", i)).collect();
+        let groups = [
+            ("\"M-000\"", "\"M-100\""),
+            ("\"M-100\"", "\"M-200\""),
+            ("\"M-200\"", "\"M-300\""),
+            ("\"M-300\"", "\"M-400\""),
+            ("\"M-400\"", "\"M-399\""),
+        ];
+        let mut steps: Vec<Step> = groups.iter().map(|&(first, next)| Step::ExpectWithout(first, next)).collect();
+        for sid in 1..=5u64 {
+            steps.push(Step::Send(serde_json::json!({ "id": sid, "type": "subscribed", "msg": { "channel": "ticker", "sid": sid } })));
+        }
+        for sid in 1..=5u64 {
+            steps.push(Step::Send(serde_json::json!({
+                "type": "ticker",
+                "sid": sid,
+                "msg": { "market_ticker": format!("M-{:03}", sid * 100 - 1), "price_dollars": "0.4500", "ts": 1700000000 },
+            })));
+        }
+        let client = mock_server(vec![steps]).await;
+        let ws = client.websocket();
+        let quotes = ws.subscribe_ticker(tickers);
+
+        let received: Vec<String> = quotes.take(5).map(|q| q.unwrap().ticker).collect().await;
+        assert_eq!(received, vec!["M-099", "M-199", "M-299", "M-399", "M-499"]);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_once() {
+        let client = mock_server(vec![
+            vec![
+                Step::Expect(r#""market_tickers":["SOME-MARKET"]"#),
+                Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "orderbook_delta", "sid": 7 } })),
+                snapshot(1, &[("0.4400", "10.00")], &[("0.5400", "5.00")]),
+                delta(2, "yes", "0.4400", "-10.00"),
+                Step::Expect(r#""cmd":"unsubscribe","id":2,"params":{"sids":[7]}"#),
+            ],
+            vec![
+                Step::Expect(r#""market_tickers":["BAD-MARKET"]"#),
+                Step::Send(serde_json::json!({ "id": 1, "type": "error", "msg": { "code": 6, "msg": "Market not found" } })),
+            ],
+        ])
+        .await;
+        let ws = client.websocket();
+
+        let book = ws.snapshot_once("SOME-MARKET").await.unwrap();
+        assert_eq!(book.yes_mid(), Some(0.45));
+        assert!(matches!(
+            ws.snapshot_once("BAD-MARKET").await,
+            Err(KalshiError::WebSocketRejected { code: Some(6), .. })
+        ));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_websocket_telemetry() {
+        let ticker = |seq: u64| {
+            Step::Send(serde_json::json!({
+                "type": "ticker",
+                "sid": 7,
+                "seq": seq,
+                "msg": { "market_ticker": "SOME-MARKET", "yes_bid_dollars": "0.4400" },
+            }))
+        };
+        let ack = || Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "ticker", "sid": 7 } }));
+        let sink = crate::telemetry::test::RecordingSink::default();
+        // The recorder is thread-local, so the mock server runs on this thread too.
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        metrics::with_local_recorder(&sink, || {
+            runtime.block_on(async {
+                let client = mock_server(vec![
+                    vec![Step::Expect(r#""channels":["ticker"]"#), ack(), ticker(1)],
+                    vec![Step::Expect(r#""channels":["ticker"]"#), ack(), ticker(1)],
+                ])
+                .await;
+                let ws = client.websocket();
+                let updates: Vec<_> = ws.subscribe_ticker(vec!["SOME-MARKET".to_string()]).take(2).collect().await;
+                assert!(updates.iter().all(Result::is_ok));
+            })
+        });
+
+        assert_eq!(sink.count("kalshi_ws_connections_total{channel=ticker,outcome=ok}"), 2);
+        assert_eq!(sink.count("kalshi_ws_reconnects_total{channel=ticker}"), 1);
+        assert_eq!(sink.count("kalshi_ws_subscriptions_total{channel=ticker}"), 2);
+        assert_eq!(sink.count("kalshi_ws_messages_total{channel=ticker,type=subscribed}"), 2);
+        assert_eq!(sink.count("kalshi_ws_messages_total{channel=ticker,type=ticker}"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_streams_untyped_channel() {
+        let lifecycle = |seq: u64, event: &str| {
+            Step::Send(serde_json::json!({
+                "type": "market_lifecycle_v2",
+                "sid": 3,
+                "seq": seq,
+                "msg": { "market_ticker": "SOME-MARKET", "event_type": event },
+            }))
+        };
+        let client = mock_server(vec![
+            vec![
+                Step::Expect(r#""cmd":"subscribe","id":1,"params":{"channels":["market_lifecycle_v2"]}"#),
+                Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "market_lifecycle_v2", "sid": 3 } })),
+                lifecycle(1, "activated"),
+            ],
+            // Sent again after reconnecting.
+            vec![
+                Step::Expect(r#""params":{"channels":["market_lifecycle_v2"]}"#),
+                lifecycle(1, "deactivated"),
+            ],
+        ])
+        .await;
+        let ws = client.websocket();
+
+        let command = serde_json::json!({ "cmd": "subscribe", "params": { "channels": ["market_lifecycle_v2"] } });
+        let messages: Vec<serde_json::Value> = ws
+            .send_raw(command)
+            .unwrap()
+            .filter_map(|message| future::ready(message.ok()))
+            .take(2)
+            .collect()
+            .await;
+        let events: Vec<_> = messages.iter().map(|m| m["msg"]["event_type"].as_str().unwrap()).collect();
+        assert_eq!(events, ["activated", "deactivated"]);
+        assert_eq!(messages[0]["type"], "market_lifecycle_v2");
+
+        assert!(matches!(ws.send_raw(serde_json::json!(["subscribe"])), Err(KalshiError::UserInputError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_raw_messages_include_what_typed_updates_skip() {
+        let client = mock_server(vec![vec![
+            Step::Expect(r#""channels":["ticker"]"#),
+            Step::Send(serde_json::json!({ "id": 1, "type": "subscribed", "msg": { "channel": "ticker", "sid": 7 } })),
+            Step::Send(serde_json::json!({
+                "type": "ticker",
+                "sid": 7,
+                "seq": 1,
+                "msg": { "market_ticker": "SOME-MARKET", "yes_bid_dollars": "0.4400", "new_field": 1 },
+            })),
+            Step::Send(serde_json::json!({ "type": "something_new", "sid": 7, "seq": 2, "msg": {} })),
+        ]])
+        .await;
+        let ws = client.websocket();
+        let mut quotes = ws.subscribe_ticker(vec!["SOME-MARKET".to_string()]);
+
+        let messages: Vec<serde_json::Value> = quotes.raw_messages().take(3).map(Result::unwrap).collect().await;
+        let kinds: Vec<_> = messages.iter().map(|m| m["type"].as_str().unwrap()).collect();
+        assert_eq!(kinds, ["subscribed", "ticker", "something_new"]);
+        assert_eq!(messages[1]["msg"]["new_field"], 1);
+    }
+}
@@ -1,4 +1,7 @@
-use kalshi::TradingEnvironment;
+use kalshi::{
+    Kalshi, KalshiError, KalshiWebSocket, OrderbookUpdate, Subscription, TickerUpdate, Trade,
+    TradingEnvironment,
+};
 
 #[test]
 fn test_trading_environment_equality() {
@@ -22,4 +25,29 @@ fn test_trading_environment_debug() {
     
     assert_eq!(demo_str, "DemoMode");
     assert_eq!(prod_str, "ProdMode");
-} 
\ No newline at end of file
+} 
+
+fn assert_send_sync<T: Send + Sync>() {}
+fn assert_send<T: Send>(_: &T) {}
+
+#[test]
+fn test_client_is_send_sync() {
+    assert_send_sync::<Kalshi>();
+    assert_send_sync::<KalshiError>();
+}
+
+#[test]
+fn test_websocket_is_send_sync() {
+    assert_send_sync::<KalshiWebSocket>();
+    assert_send_sync::<Subscription<'static, OrderbookUpdate>>();
+    assert_send_sync::<Subscription<'static, TickerUpdate>>();
+    assert_send_sync::<Subscription<'static, Trade>>();
+}
+
+// Never run: only checks that the futures and streams handed to `tokio::spawn` are `Send`.
+#[allow(dead_code)]
+fn client_futures_are_send(kalshi: &Kalshi) {
+    assert_send(&kalshi.get_market("SOME-MARKET-2024"));
+    assert_send(&kalshi.get_balance());
+    assert_send(&kalshi.exchange_status_stream(std::time::Duration::from_secs(1)));
+}
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use kalshi::{Kalshi, TradingEnvironment, KalshiError};
 use std::env;
 use std::sync::Once;
@@ -34,7 +36,7 @@ impl TestAuth {
         let pem_path = env::var("KALSHI_DEMO_PEM_PATH").ok()?;
         let environment = match env::var("KALSHI_TEST_ENV").unwrap_or_else(|_| "demo".to_string()).as_str() {
             "prod" => TradingEnvironment::ProdMode,
-            "demo" | _ => TradingEnvironment::DemoMode,
+            _ => TradingEnvironment::DemoMode,
         };
         
         Some(TestAuth {
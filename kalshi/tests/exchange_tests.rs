@@ -3,6 +3,7 @@ mod common;
 use common::setup_auth_test;
 
 #[tokio::test]
+#[allow(clippy::bool_comparison, clippy::nonminimal_bool)]
 async fn test_exchange_status_structure() {
     let kalshi = setup_auth_test().await.unwrap();
     
@@ -1,3 +1,5 @@
+#![allow(clippy::duplicate_mod)]
+
 pub mod common;
 pub mod auth_tests;
 pub mod market_tests;
@@ -43,7 +43,7 @@ async fn test_get_settlements() {
     let kalshi = setup_auth_test().await.unwrap();
     
     // Test getting settlements
-    let result = kalshi.get_settlements(None, None).await;
+    let result = kalshi.get_settlements(None, None, None, None, None).await;
     assert!(result.is_ok(), "Failed to get settlements: {:?}", result.err());
     
     let (_cursor, _settlements) = result.unwrap();